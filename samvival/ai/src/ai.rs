@@ -2,55 +2,481 @@ use common::prelude::*;
 use game::prelude::*;
 use noisy_float::types::r32;
 use game::logic;
-use game::logic::{faction,combat,movement};
-use game::entities::{PositionData, CharacterData, AllegianceData};
+use game::logic::{faction,combat,movement,harvest,awareness,morale};
+use game::logic::combat::{AttackBreakdown, StrikeTargetBreakdown};
+use game::entities::{PositionData, CharacterData, AllegianceData, ActionData, AttackRef, AIData, Consideration, ReactionTypeRef, VisibilityData};
+use std::collections::HashMap;
+use planner::{self, FactionPlan};
+
+/// upper bound on the number of actions a single creature will take in one turn, in case some action fails to use up
+/// any action points and would otherwise be chosen again forever
+const MAX_ACTIONS_PER_TURN : usize = 8;
+
+/// the things an AI controlled creature can choose between doing on its turn
+#[derive(Clone, Debug, PartialEq)]
+pub enum AIAction {
+    /// move to the given hex (if not already there) and attack the target with the given attack
+    Attack { target : Entity, attack_ref : AttackRef, from : AxialCoord },
+    /// move towards the nearest enemy seen or remembered, nothing being in reach to attack yet
+    Approach { to : AxialCoord },
+    /// fall back to a hex fewer enemies can reach
+    Retreat { to : AxialCoord },
+    /// head out looking for enemies, none being known of
+    Search { to : AxialCoord },
+    /// run as far from any known enemy as possible, having lost the nerve to fight
+    Flee { to : AxialCoord },
+    /// gather from a harvestable in reach
+    Harvest { harvestable : Entity, from : AxialCoord },
+    /// switch to a different reaction for the rest of the turn, ending it
+    React(ReactionTypeRef),
+    /// do nothing more this turn
+    Wait,
+}
 
 pub fn take_ai_actions(world: &mut World, faction : Entity) {
-    let world_view = world.view();
-    for (cref, cur_data) in world_view.entities_with_data::<CharacterData>() {
-        let allegiance = world_view.data::<AllegianceData>(*cref);
-        if allegiance.faction == faction && cur_data.is_alive() {
-            // these are enemies, now we get to decide what they want to do
-            ai_action(&cref, &cur_data, world, world_view);
+    awareness::update_awareness(world, faction);
+
+    let plan = planner::plan_for_faction(world, faction);
+
+    // anyone who couldn't get where they wanted to go, most likely because someone else was in the way, gets another
+    // chance once everyone else has moved
+    let mut deferred = Vec::new();
+    for character in &plan.order {
+        if ! take_character_actions(world, *character, &plan) {
+            deferred.push(*character);
         }
     }
+    for character in deferred {
+        take_character_actions(world, character, &plan);
+    }
 }
 
+/// has the character act until it has nothing better to do than wait, returning false if it never managed to do
+/// anything it wanted to
+fn take_character_actions(world : &mut World, character : Entity, plan : &FactionPlan) -> bool {
+    for i in 0 .. MAX_ACTIONS_PER_TURN {
+        if ! world.view().character(character).is_alive() || morale::has_surrendered(world.view(), character) {
+            return true;
+        }
+        let action = best_action(world, character, plan);
+        trace!("AI {:?} chose {:?}", character, action);
+        if action == AIAction::Wait {
+            return true;
+        }
+        if ! perform_action(world, character, &action) {
+            return i > 0 || is_turn_ending(&action);
+        }
+    }
+    true
+}
 
-fn ai_action(ai_ref: &Entity, cdata: &CharacterData, world: &mut World, world_view: &WorldView) {
+/// scores every candidate action against the character's considerations and picks the highest. Waiting scores 0, so
+/// anything chosen over it is expected to actually improve the character's position
+pub fn best_action(world : &World, character : Entity, plan : &FactionPlan) -> AIAction {
+    scored_actions(world, character, plan).into_iter()
+        .max_by_key(|(_, score)| r32(*score))
+        .map(|(action, _)| action)
+        .unwrap_or(AIAction::Wait)
+}
 
-    let ai = world_view.character(*ai_ref);
+pub fn scored_actions(world : &World, character : Entity, plan : &FactionPlan) -> Vec<(AIAction, f32)> {
+    let view = world.view();
+    let ai_data = view.data_opt::<AIData>(character).cloned().unwrap_or_default();
+    let ai = view.character(character);
+    let current_hex = ai.position.hex;
+    let faction = ai.allegiance.faction;
 
-    if ai.movement.move_speed == Sext::of(0) {
+    // only what the faction can see can be attacked, but it will still steer around or towards anyone it remembers seeing
+    let enemies = awareness::visible_enemies(view, faction);
+    let known_enemies = awareness::known_enemy_positions(view, faction);
 
+    let mut reachable = if ai.movement.move_speed == Sext::of(0) {
+        HashMap::new()
     } else {
-        let closest_enemy = world_view.entities_with_data::<CharacterData>()
-            .filter(|&(_, c)| c.is_alive())
-            .filter(|&(cref, _)| faction::is_enemy(world_view, *ai_ref, *cref))
-            .min_by_key(|t| world_view.data::<PositionData>(*t.0).hex.distance(&ai.position.hex));
-
-        if let Some(closest) = closest_enemy {
-            let enemy_ref: &Entity = closest.0;
-            let enemy_data = world_view.character(*closest.0);
-
-            if enemy_data.position.distance(&ai.position) >= r32(1.5) {
-                if let Some(path) = movement::path_any_v(world_view, *ai_ref, ai.position.hex, &enemy_data.position.hex.neighbors_vec(), enemy_data.position.hex) {
-                    movement::handle_move(world, *ai_ref, path.0.as_slice())
-                } else {
-                    println!("No move towards closest enemy, stalling");
+        movement::hexes_reachable_by_character_this_turn_default(view, character)
+    };
+    reachable.insert(current_hex, 0.0);
+
+    let threats = enemy_threats(view, &known_enemies);
+    let threat_at = |hex : &AxialCoord| threat_at_hex(&threats, hex);
+    let current_threat = threat_at(&current_hex);
+    let health_missing = 1.0 - ai.health.cur_fract() as f32;
+
+    // those fighting from range should keep behind the faction's melee fighters rather than out in front of them
+    let formation_penalty = |hex : &AxialCoord| -> f32 {
+        match plan.front_line_distance {
+            Some(front) if planner::is_ranged(view, character) => {
+                let distance = known_enemies.iter().map(|(_, e)| hex.distance(e).raw()).fold(front, f32::min);
+                (front - distance).max(0.0) * ai_data.weight(Consideration::Formation)
+            },
+            _ => 0.0
+        }
+    };
+
+    let mut res = vec![(AIAction::Wait, 0.0)];
+
+    // a character whose nerve has broken thinks of nothing but getting away
+    if morale::is_fleeing(view, character) {
+        let distances = distances_from(view, &known_enemies.iter().map(|(_, hex)| *hex).collect_vec(), FLEE_FIELD_RADIUS);
+        let distance_at = |hex : &AxialCoord| distances.get(hex).cloned().unwrap_or(FLEE_FIELD_RADIUS + 1);
+        let current_distance = distance_at(&current_hex);
+        for hex in reachable.keys() {
+            let gained = distance_at(hex) - current_distance;
+            if gained > 0 {
+                res.push((AIAction::Flee { to : *hex }, gained as f32));
+            }
+        }
+        return res;
+    }
+
+    // attacks, from whichever reachable hex is safest to attack from
+    let ap = ai.action_points.cur_value();
+    for enemy in &enemies {
+        let enemy_hex = view.data::<PositionData>(*enemy).hex;
+        for attack_ref in combat::possible_attack_refs(view, character) {
+            if let Some(attack) = attack_ref.resolve(view, character) {
+                let attack_from = reachable.iter()
+                    .map(|(hex, cost)| (*hex, ap - movement::ap_cost_for_move_cost(view, character, Sext::of_rounded_up(*cost)) as i32))
+                    .filter(|(hex, ap_after)| *ap_after >= attack.ap_cost as i32 && combat::within_range(view, character, *enemy, &attack, Some(*hex), Some(enemy_hex)))
+                    .min_by_key(|(hex, ap_after)| r32(threat_at(hex) * ai_data.weight(Consideration::Threat) + formation_penalty(hex) - *ap_after as f32));
+
+                if let Some((from, ap_after)) = attack_from {
+                    let breakdown = combat::compute_attack_breakdown(world, view, character, *enemy, &attack_ref, Some(from), Some(ap_after));
+                    let focus_bonus = if plan.assigned_target(character) == Some(*enemy) { ai_data.weight(Consideration::FocusFire) } else { 0.0 };
+                    let score = score_attack(view, character, *enemy, &breakdown, from != current_hex, &ai_data) + focus_bonus -
+                        threat_at(&from) * ai_data.weight(Consideration::Threat) - formation_penalty(&from);
+                    res.push((AIAction::Attack { target : *enemy, attack_ref : attack_ref.clone(), from }, score));
                 }
             }
+        }
+    }
+    let any_attacks = res.len() > 1;
 
-            if enemy_data.position.distance(&ai.position) < r32(1.5) {
-                let all_possible_attacks = combat::possible_attack_refs(world_view, *ai_ref);
-                if let Some(attack) = all_possible_attacks.first() {
-                    combat::handle_attack(world, *ai_ref, *enemy_ref, attack);
+    if ! any_attacks {
+        // close in on the assigned target, or failing that the nearest enemy, if there's nothing to hit yet
+        let assigned_hex = plan.assigned_target(character).and_then(|t| known_enemies.iter().find(|(e,_)| *e == t)).map(|(_, hex)| *hex);
+        if let Some(nearest) = assigned_hex.or_else(|| known_enemies.iter().map(|(_, hex)| *hex).min_by_key(|h| h.distance(&current_hex))) {
+            let current_distance = nearest.distance(&current_hex).raw();
+            for hex in reachable.keys() {
+                let closed_by = current_distance - nearest.distance(hex).raw();
+                if closed_by > 0.0 {
+                    let score = closed_by * ai_data.weight(Consideration::Approach) - threat_at(hex) * ai_data.weight(Consideration::Threat) - formation_penalty(hex);
+                    res.push((AIAction::Approach { to : *hex }, score));
                 }
             }
-        } else {
-            if let Some(path) = logic::movement::path(world_view, *ai_ref, ai.position.hex, ai.position.hex.neighbor(0)) {
-                movement::handle_move(world, *ai_ref, path.0.as_slice());
+        }
+    }
+
+    // with no idea where anyone is, go and look
+    let search_weight = ai_data.weight(Consideration::Search);
+    if known_enemies.is_empty() && search_weight > 0.0 {
+        if let Some(target) = search_target(view, faction, current_hex, &reachable) {
+            let current_distance = target.distance(&current_hex).raw();
+            for hex in reachable.keys() {
+                let closed_by = current_distance - target.distance(hex).raw();
+                if closed_by > 0.0 {
+                    res.push((AIAction::Search { to : *hex }, closed_by * search_weight));
+                }
+            }
+        }
+    }
+
+    // pull back from danger, more eagerly the more hurt the character is
+    if current_threat > 0.0 {
+        for hex in reachable.keys() {
+            let threat_avoided = current_threat - threat_at(hex);
+            if threat_avoided > 0.0 {
+                let score = threat_avoided * health_missing * ai_data.weight(Consideration::SelfPreservation);
+                res.push((AIAction::Retreat { to : *hex }, score));
+            }
+        }
+    }
+
+    // gather from whatever is close by, but only when nobody is around to take advantage of it
+    let forage_weight = ai_data.weight(Consideration::Forage);
+    if forage_weight > 0.0 && current_threat <= 0.0 {
+        for from in current_hex.neighbors_vec().into_iter().chain(Some(current_hex)) {
+            for harvestable in harvest::harvestables_sorted_by_desirability_at(view, character, from) {
+                if harvest::can_harvest(view, character, harvestable).0 {
+                    res.push((AIAction::Harvest { harvestable, from }, forage_weight));
+                }
+            }
+        }
+    }
+
+    // reactions, which end the turn, so they're only taken when there's nothing better left to do
+    let reaction_weight = ai_data.weight(Consideration::Reaction);
+    if reaction_weight > 0.0 && current_threat > 0.0 {
+        for reaction in &[ReactionTypeRef::Counterattack, ReactionTypeRef::Dodge, ReactionTypeRef::Defend] {
+            if *reaction != ai.action.active_reaction && (reaction.resolve().condition)().matches(view, character) {
+                let suitability = match reaction {
+                    // retribution is best when the character is healthy enough to trade blows
+                    ReactionTypeRef::Counterattack => 1.0 - health_missing,
+                    ReactionTypeRef::Dodge => 0.5 + health_missing,
+                    _ => 0.25,
+                };
+                res.push((AIAction::React(*reaction), suitability * reaction_weight * current_threat.min(1.0)));
+            }
+        }
+    }
+
+    res
+}
+
+fn score_attack(view : &WorldView, character : Entity, target : Entity, breakdown : &AttackBreakdown, moving : bool, ai_data : &AIData) -> f32 {
+    let mut damage_dealt = 0.0;
+    for strike in &breakdown.strikes {
+        for target_breakdown in &strike.per_target_breakdowns {
+            let damage = expected_damage(target_breakdown);
+            // attacks covering an area may well catch friends as well as enemies
+            if faction::is_enemy(view, character, target_breakdown.target) {
+                damage_dealt += damage;
+            } else {
+                damage_dealt -= damage;
             }
         }
     }
-}
\ No newline at end of file
+
+    // counters are computed relative to where the character is now, if they're moving somewhere new first those are
+    // only a rough indication of what they'll actually face
+    let damage_taken : f32 = breakdown.counters.iter()
+        .flat_map(|c| c.per_target_breakdowns.iter())
+        .filter(|tb| tb.target == character)
+        .map(|tb| expected_damage(tb))
+        .sum();
+
+    let target_health = view.character(target).health.cur_value().max(1) as f32;
+    let kill_chance = (damage_dealt / target_health).min(1.0).max(0.0).powi(2);
+
+    // all else being equal, prefer not to move
+    let movement_penalty = if moving { 0.01 } else { 0.0 };
+
+    damage_dealt * ai_data.weight(Consideration::DamageDealt) +
+        kill_chance * ai_data.weight(Consideration::KillChance) -
+        damage_taken * ai_data.weight(Consideration::DamageTaken) -
+        movement_penalty
+}
+
+/// the average damage a single strike against a single target is expected to do, accounting for the chance to miss
+pub fn expected_damage(target_breakdown : &StrikeTargetBreakdown) -> f32 {
+    let dice_avg : f32 = target_breakdown.damage_dice_total().map(|d| d.avg_roll()).sum();
+    let damage_on_hit = (dice_avg + target_breakdown.damage_bonus_total() as f32 - target_breakdown.damage_absorption_total() as f32).max(0.0);
+    hit_chance(target_breakdown.to_hit_total() - target_breakdown.to_miss_total()) * damage_on_hit
+}
+
+/// the chance that a strike with the given net modifier hits, mirroring the 3d6 roll against 10 made in handle_strike
+pub fn hit_chance(to_hit_modifiers : i32) -> f32 {
+    let mut hits = 0;
+    for a in 1 ..= 6 {
+        for b in 1 ..= 6 {
+            for c in 1 ..= 6 {
+                if a + b + c + to_hit_modifiers >= 10 {
+                    hits += 1;
+                }
+            }
+        }
+    }
+    hits as f32 / 216.0
+}
+
+/// where each enemy is and how far it can reach to attack next turn, along with roughly how much damage it could do
+struct EnemyThreat {
+    position : AxialCoord,
+    reach : f32,
+    damage : f32,
+}
+
+fn enemy_threats(view : &WorldView, enemies : &Vec<(Entity, AxialCoord)>) -> Vec<EnemyThreat> {
+    enemies.iter().filter_map(|(enemy, position)| {
+        let attacks = combat::possible_attacks(view, *enemy);
+        let max_range = attacks.iter().map(|a| a.range).max()?;
+        let ap = view.character(*enemy).action_points.max_value();
+        let damage = attacks.iter()
+            .map(|a| (a.damage_dice.avg_roll() + a.damage_bonus as f32) * (ap / a.ap_cost.max(1) as i32) as f32)
+            .fold(0.0, f32::max);
+        let moves = movement::default_movement_type(view, *enemy)
+            .map(|mt| movement::max_moves_per_turn(view, *enemy, mt).as_f32())
+            .unwrap_or(0.0);
+        Some(EnemyThreat { position : *position, reach : moves + max_range as f32, damage })
+    }).collect()
+}
+
+/// the total damage enemies could be expected to bring to bear on the given hex on their next turn
+fn threat_at_hex(threats : &Vec<EnemyThreat>, hex : &AxialCoord) -> f32 {
+    threats.iter().filter(|t| t.position.distance(hex).raw() <= t.reach).map(|t| t.damage).sum()
+}
+
+/// how far out from known enemies the flight distance field is computed, anywhere further is as good as any other
+const FLEE_FIELD_RADIUS : i32 = 20;
+
+/// a distance field over the map, the number of steps from each hex to the nearest of the sources, so that fleeing can
+/// follow it downhill away from all threats at once rather than just away from whichever one is closest
+fn distances_from(view : &WorldView, sources : &Vec<AxialCoord>, max_distance : i32) -> HashMap<AxialCoord, i32> {
+    let mut distances = HashMap::new();
+    let mut frontier = Vec::new();
+    for source in sources {
+        distances.insert(*source, 0);
+        frontier.push(*source);
+    }
+
+    for distance in 1 ..= max_distance {
+        let mut next_frontier = Vec::new();
+        for hex in frontier {
+            for neighbor in hex.neighbors_vec() {
+                if ! distances.contains_key(&neighbor) && view.entity_by_key(&neighbor).is_some() {
+                    distances.insert(neighbor, distance);
+                    next_frontier.push(neighbor);
+                }
+            }
+        }
+        frontier = next_frontier;
+    }
+    distances
+}
+
+/// somewhere to search for enemies: the nearest hex the faction has seen before but can't see now, or failing that the
+/// furthest the character can get this turn, to bring new ground into view
+fn search_target(view : &WorldView, faction : Entity, current_hex : AxialCoord, reachable : &HashMap<AxialCoord, f64>) -> Option<AxialCoord> {
+    let stable_order = |h : &AxialCoord| r32(h.q as f32 * 0.001 + h.r as f32 * 0.0013);
+    let visibility = view.world_data::<VisibilityData>().visibility_for(faction);
+    visibility.revealed_hexes.iter()
+        .filter(|h| ! visibility.visible_hexes.contains(h))
+        .min_by_key(|h| h.distance(&current_hex) + stable_order(h))
+        .cloned()
+        .or_else(|| reachable.keys().max_by_key(|h| h.distance(&current_hex) + stable_order(h)).cloned())
+}
+
+fn is_turn_ending(action : &AIAction) -> bool {
+    match action {
+        AIAction::React(_) | AIAction::Wait => true,
+        _ => false
+    }
+}
+
+/// carries out the given action, returning whether the character should go on to consider doing anything else this turn
+fn perform_action(world : &mut World, character : Entity, action : &AIAction) -> bool {
+    let view = world.view();
+    let ap_before = view.character(character).action_points.cur_value();
+    match action {
+        AIAction::Attack { target, attack_ref, from } => {
+            if ! move_to(world, character, *from) {
+                return false;
+            }
+            let view = world.view();
+            if let Some(attack) = attack_ref.resolve(view, character) {
+                if combat::can_attack(view, character, *target, &attack, None, None) {
+                    combat::handle_attack(world, character, *target, attack_ref);
+                }
+            }
+        },
+        AIAction::Approach { to } | AIAction::Retreat { to } | AIAction::Search { to } | AIAction::Flee { to } => {
+            move_to(world, character, *to);
+        },
+        AIAction::Harvest { harvestable, from } => {
+            logic::harvest::harvest(world, character, *from, *harvestable, true, None);
+        },
+        AIAction::React(reaction) => {
+            world.modify_with_desc(character, ActionData::active_reaction.set_to(*reaction), "reaction selected");
+            world.add_event(GameEvent::SelectedReactionChanged { entity : character, reaction_type : *reaction });
+            return false;
+        },
+        AIAction::Wait => return false,
+    }
+    // if nothing was spent there's no reason to think trying again would go any differently
+    world.view().character(character).action_points.cur_value() < ap_before
+}
+
+fn move_to(world : &mut World, character : Entity, to : AxialCoord) -> bool {
+    let view = world.view();
+    if view.data::<PositionData>(character).hex == to {
+        true
+    } else if let Some(path) = movement::path_to(view, character, to) {
+        movement::handle_move(world, character, path.0.as_slice());
+        true
+    } else {
+        trace!("No path to chosen hex, stalling");
+        false
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use spectral::prelude::*;
+    use common::Color;
+    use game::archetypes::character_archetypes;
+    use game::entities::{FactionData, Visibility, VisibilityData};
+    use game::events::CoreEvent;
+    use game::logic::test::testbed::{self, in_testbed};
+    use game::core::DicePool;
+    use std::collections::HashSet;
+
+    #[test]
+    pub fn test_hit_chance_matches_the_dice() {
+        // 3d6 comes up 10 or more five times in eight
+        assert_that(&hit_chance(0)).is_equal_to(&(135.0 / 216.0));
+        // even the lowest roll of 3 hits with a +7, and nothing short of 19 would hit with a -9
+        assert_that(&hit_chance(7)).is_equal_to(&1.0);
+        assert_that(&hit_chance(-9)).is_equal_to(&0.0);
+        assert_that(&(hit_chance(2) > hit_chance(1))).is_true();
+    }
+
+    #[test]
+    pub fn test_expected_damage_accounts_for_misses_and_absorption() {
+        let mut breakdown = StrikeTargetBreakdown::default();
+        breakdown.damage_dice_components.push((DicePool::of(1, 6), "weapon"));
+        breakdown.damage_bonus_components.add(2, "strength");
+        breakdown.to_hit_components.add(7, "can't miss");
+        // a 1d6 averages 3.5, plus the bonus
+        assert_that(&expected_damage(&breakdown)).is_equal_to(&5.5);
+
+        breakdown.damage_absorption_components.add(10, "armor");
+        assert_that(&expected_damage(&breakdown)).is_equal_to(&0.0);
+
+        let mut evenly_matched = StrikeTargetBreakdown::default();
+        evenly_matched.damage_dice_components.push((DicePool::of(1, 6), "weapon"));
+        assert_that(&expected_damage(&evenly_matched)).is_equal_to(&(3.5 * hit_chance(0)));
+    }
+
+    #[test]
+    pub fn test_attacks_an_enemy_in_reach() {
+        in_testbed(|world, testbed| {
+            let view = world.view();
+            let hunters = EntityBuilder::new().with(FactionData { name : strf("Hunters"), color : Color::new(1.0, 1.0, 1.0, 1.0), player_faction : false }).create(world);
+            let prey = EntityBuilder::new().with(FactionData { name : strf("Prey"), color : Color::new(0.0, 0.0, 0.0, 1.0), player_faction : false }).create(world);
+
+            let hunter = testbed::human(hunters, AxialCoord::new(0, 0)).create(world);
+            let target = character_archetypes().with_name("mud monster").clone()
+                .with(AllegianceData { faction : prey })
+                .with(PositionData { hex : AxialCoord::new(1, 0) })
+                .create(world);
+
+            let visible_hexes : HashSet<AxialCoord> = (-3 ..= 3).flat_map(|q| (-3 ..= 3).map(move |r| AxialCoord::new(q, r)))
+                .filter(|h| h.distance(&AxialCoord::new(0, 0)) <= r32(3.0))
+                .collect();
+            world.modify_world(VisibilityData::visibility_by_faction.set_key_to(hunters, Visibility { revealed_hexes : visible_hexes.clone(), visible_hexes }), None);
+            world.add_event(CoreEvent::TimePassed);
+            awareness::update_awareness(world, hunters);
+            world.add_event(CoreEvent::TimePassed);
+
+            let plan = planner::plan_for_faction(world, hunters);
+            match best_action(world, hunter, &plan) {
+                // already in reach, so there's no reason to go anywhere first
+                AIAction::Attack { target : attacked, from, .. } => {
+                    assert_that(&attacked).is_equal_to(&target);
+                    assert_that(&from).is_equal_to(&AxialCoord::new(0, 0));
+                },
+                other => panic!("expected an attack, chose {:?}", other)
+            }
+
+            // and with nobody left to fight there's nothing to attack
+            world.modify(target, PositionData::hex.set_to(AxialCoord::new(8, 0)));
+            world.add_event(CoreEvent::TimePassed);
+            awareness::update_awareness(world, hunters);
+            world.add_event(CoreEvent::TimePassed);
+            let plan = planner::plan_for_faction(world, hunters);
+            let any_attacks = scored_actions(world, hunter, &plan).iter().any(|(a, _)| if let AIAction::Attack { .. } = a { true } else { false });
+            assert_that(&any_attacks).is_false();
+        })
+    }
+}
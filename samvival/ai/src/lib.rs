@@ -32,4 +32,5 @@ extern crate noise;
 extern crate num;
 extern crate cgmath;
 
-pub mod ai;
\ No newline at end of file
+pub mod ai;
+pub mod planner;
\ No newline at end of file
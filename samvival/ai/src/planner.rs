@@ -0,0 +1,147 @@
+use common::prelude::*;
+use game::prelude::*;
+use noisy_float::types::r32;
+use game::logic::{combat,awareness,morale};
+use game::entities::{PositionData, CharacterData, AllegianceData, MoraleState};
+use std::collections::HashMap;
+use ai::expected_damage;
+
+
+/// The faction wide picture each creature's own choices are made within: who each of them should be going after, so
+/// that damage is concentrated on enemies that can actually be brought down, what order they should act in so the ones
+/// in front get out of the way of those behind, and where the front line is, so those attacking from range can keep
+/// behind it
+#[derive(Clone, Debug, Default)]
+pub struct FactionPlan {
+    pub assigned_targets : HashMap<Entity, Entity>,
+    pub order : Vec<Entity>,
+    /// how close the faction's nearest melee fighter is to an enemy, if it has any
+    pub front_line_distance : Option<f32>,
+}
+
+impl FactionPlan {
+    pub fn assigned_target(&self, character : Entity) -> Option<Entity> {
+        self.assigned_targets.get(&character).cloned()
+    }
+}
+
+pub fn plan_for_faction(world : &World, faction : Entity) -> FactionPlan {
+    let view = world.view();
+    let members : Vec<Entity> = view.entities_with_data::<CharacterData>()
+        .filter(|(cref, cur_data)| view.data::<AllegianceData>(**cref).faction == faction && cur_data.is_alive())
+        .map(|(cref, _)| *cref)
+        .collect();
+    // those who've lost their nerve act on their own, if at all
+    let fighters : Vec<Entity> = members.iter().cloned().filter(|c| morale::morale_state(view, *c) == MoraleState::Steady).collect();
+
+    let enemies = awareness::visible_enemies(view, faction);
+    let known_enemies = awareness::known_enemy_positions(view, faction);
+    let distance_to_enemies = |character : Entity| {
+        let hex = view.data::<PositionData>(character).hex;
+        known_enemies.iter().map(|(_, e)| hex.distance(e).raw()).fold(None, |acc : Option<f32>, d| Some(acc.map(|a| a.min(d)).unwrap_or(d)))
+    };
+
+    // melee fighters go first, those closest to the enemy leading, so they don't stand in the way of the ones behind them,
+    // then those fighting from range, who can make use of the space opened up
+    let mut order = members.clone();
+    order.sort_by_key(|c| (is_ranged(view, *c), r32(distance_to_enemies(*c).unwrap_or(1000.0))));
+
+    let front_line_distance = fighters.iter()
+        .filter(|c| ! is_ranged(view, **c))
+        .filter_map(|c| distance_to_enemies(*c))
+        .fold(None, |acc : Option<f32>, d| Some(acc.map(|a| a.min(d)).unwrap_or(d)));
+
+    FactionPlan {
+        assigned_targets : assign_targets(world, &fighters, &enemies),
+        order,
+        front_line_distance,
+    }
+}
+
+/// Assigns attackers to targets, going after the enemies that will take the least to bring down first and giving each
+/// just enough attackers to be expected to finish them before moving on to the next. Anyone left over once every enemy
+/// has enough attackers to be killed piles onto whichever target they'd do the most damage to
+fn assign_targets(world : &World, members : &Vec<Entity>, enemies : &Vec<Entity>) -> HashMap<Entity, Entity> {
+    let view = world.view();
+    let mut assignments = HashMap::new();
+
+    let damage_against = |attacker : Entity, target : Entity| -> f32 {
+        match combat::primary_attack_ref(view, attacker) {
+            Some(attack_ref) => combat::compute_attack_breakdown(world, view, attacker, target, &attack_ref, None, Some(view.character(attacker).action_points.max_value())).strikes.iter()
+                .flat_map(|s| s.per_target_breakdowns.iter())
+                .filter(|tb| tb.target == target)
+                .map(|tb| expected_damage(tb))
+                .sum(),
+            None => 0.0
+        }
+    };
+
+    let mut targets = enemies.clone();
+    targets.sort_by_key(|e| view.character(*e).health.cur_value());
+
+    let mut unassigned = members.clone();
+    for target in targets {
+        let target_hex = view.data::<PositionData>(target).hex;
+        let health_remaining = view.character(target).health.cur_value() as f32;
+        let mut expected = 0.0;
+
+        unassigned.sort_by_key(|c| view.data::<PositionData>(*c).hex.distance(&target_hex));
+        while expected < health_remaining && ! unassigned.is_empty() {
+            let attacker = unassigned.remove(0);
+            expected += damage_against(attacker, target);
+            assignments.insert(attacker, target);
+        }
+    }
+
+    for attacker in unassigned {
+        if let Some(target) = enemies.iter().max_by_key(|e| r32(damage_against(attacker, **e))) {
+            assignments.insert(attacker, *target);
+        }
+    }
+
+    assignments
+}
+
+pub fn is_ranged(view : &WorldView, character : Entity) -> bool {
+    combat::primary_attack(view, character).map(|a| a.range > 1).unwrap_or(false)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use spectral::prelude::*;
+    use common::Color;
+    use game::entities::{FactionData, Visibility, VisibilityData};
+    use game::events::CoreEvent;
+    use game::logic::test::testbed::{self, in_testbed};
+    use std::collections::HashSet;
+
+    #[test]
+    pub fn test_fighters_focus_on_a_target_they_cant_yet_bring_down_closest_first() {
+        in_testbed(|world, testbed| {
+            let view = world.view();
+            let pack = EntityBuilder::new().with(FactionData { name : strf("Pack"), color : Color::new(1.0, 1.0, 1.0, 1.0), player_faction : false }).create(world);
+            let loners = EntityBuilder::new().with(FactionData { name : strf("Loners"), color : Color::new(0.0, 0.0, 0.0, 1.0), player_faction : false }).create(world);
+
+            let far = testbed::human(pack, AxialCoord::new(3, 0)).create(world);
+            let near = testbed::human(pack, AxialCoord::new(1, 0)).create(world);
+            // far too tough for either of them to be expected to bring down alone
+            let brute = testbed::human(loners, AxialCoord::new(0, 0))
+                .with(CharacterData { health : Reduceable::new(500), action_points : Reduceable::new(8), ..Default::default() })
+                .create(world);
+
+            let visible_hexes : HashSet<AxialCoord> = (-4 ..= 4).flat_map(|q| (-4 ..= 4).map(move |r| AxialCoord::new(q, r))).collect();
+            world.modify_world(VisibilityData::visibility_by_faction.set_key_to(pack, Visibility { revealed_hexes : visible_hexes.clone(), visible_hexes }), None);
+            world.add_event(CoreEvent::TimePassed);
+            awareness::update_awareness(world, pack);
+            world.add_event(CoreEvent::TimePassed);
+
+            let plan = plan_for_faction(world, pack);
+            assert_that(&plan.assigned_target(near)).is_equal_to(&Some(brute));
+            assert_that(&plan.assigned_target(far)).is_equal_to(&Some(brute));
+            // the one in front acts first, so it isn't in the way of the one behind it
+            assert_that(&plan.order).is_equal_to(&vec![near, far]);
+            assert_that(&plan.front_line_distance).is_equal_to(&Some(1.0));
+        })
+    }
+}
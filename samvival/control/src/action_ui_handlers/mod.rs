@@ -4,6 +4,7 @@ pub use self::move_and_attack_handler::*;
 pub mod move_handler;
 pub mod player_action_handler;
 pub mod harvest_handler;
+pub mod pick_up_handler;
 
 
 use common::Color;
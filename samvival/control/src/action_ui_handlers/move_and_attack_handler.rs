@@ -49,7 +49,8 @@ impl PlayerActionHandler for MoveAndAttackHandler {
             if let Some(targeted) = tile_opt.and_then(|t| t.occupied_by) {
                 let target_data = view.character(targeted);
 
-                if target_data.allegiance.faction != game_state.player_faction {
+                // neutrals can be attacked, though they won't stay neutral for long, allies can't
+                if ! logic::faction::is_ally(view, cur_sel, targeted) {
                     if let Some((path, cost)) = logic::combat::path_to_attack(view, cur_sel, targeted, attack_ref, game_state.mouse_cart_vec()) {
                         if path.is_empty() {
                             println!("no movement needed, attacking");
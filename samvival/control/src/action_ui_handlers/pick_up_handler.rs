@@ -0,0 +1,107 @@
+use common::prelude::*;
+
+use gui::Key;
+use action_ui_handlers::player_action_handler::PlayerActionHandler;
+use gui::MouseButton;
+use gui::PlayerActionType;
+use gui::GUI;
+use gui::GameState;
+use graphics::GraphicsResources;
+use graphics::prelude::*;
+
+use game::prelude::*;
+
+use game::logic::loot;
+use game::entities::InventoryData;
+use game::entities::tile::TileData;
+use std::collections::HashSet;
+use action_ui_handlers::draw_hex_boundary;
+use action_ui_handlers::draw_movement_path;
+
+pub(crate) struct PickUpHandler {
+    last_overlay_key: (GameEventClock, Entity),
+    last_overlay: DrawList,
+}
+
+impl PickUpHandler {
+    pub fn new() -> Box<PlayerActionHandler> {
+        box PickUpHandler {
+            last_overlay: DrawList::none(),
+            last_overlay_key: (0, Entity::sentinel()),
+        }
+    }
+
+    /// all hexes the character's faction can currently see that have something lying on the ground
+    fn hexes_with_items(world_view: &WorldView, selected: Entity) -> HashSet<AxialCoord> {
+        let visibility = logic::visibility::faction_visibility_for_character(world_view, selected);
+        world_view.entities_with_data::<InventoryData>()
+            .filter_map(|(ent, inv)| world_view.data_opt::<TileData>(*ent).filter(|_| !inv.items.is_empty()).map(|t| t.position))
+            .filter(|hex| visibility.visible_hexes.contains(hex))
+            .collect()
+    }
+}
+
+impl PlayerActionHandler for PickUpHandler {
+    fn handle_click(&mut self, world: &mut World, game_state: &GameState, player_action: &PlayerActionType, button: MouseButton) -> bool {
+        if let (Some(selected), PlayerActionType::PickUp) = (game_state.selected_character, player_action) {
+            let view = world.view();
+            let target_hex: AxialCoord = game_state.hovered_hex_coord;
+            if loot::ground_items_at(view, target_hex).is_empty() {
+                return false;
+            }
+
+            let pos: AxialCoord = view.character(selected).position.hex;
+            if pos.distance(&target_hex) > 1.0 {
+                if let Some((path, _)) = logic::movement::path_adjacent_to_hex(view, selected, target_hex) {
+                    info!("Path found to out-of-range items, moving");
+                    logic::movement::handle_move(world, selected, &path);
+                }
+            }
+
+            let (can_pick_up, reason) = loot::can_pick_up(world.view(), selected, target_hex);
+            if can_pick_up {
+                loot::pick_up(world, selected, target_hex);
+                return true;
+            } else {
+                info!("Cannot pick up: {}", reason);
+            }
+        }
+        false
+    }
+
+    fn handle_key_release(&mut self, world: &mut World, game_state: &GameState, player_action: &PlayerActionType, key: Key) -> bool {
+        false
+    }
+
+    fn draw(&mut self, world_view: &WorldView, game_state: &GameState, player_action: &PlayerActionType) -> DrawList {
+        if let (Some(selected), PlayerActionType::PickUp) = (game_state.selected_character, player_action) {
+            let key_state = (game_state.display_event_clock, selected);
+            let mut draw_list = if self.last_overlay_key == key_state {
+                self.last_overlay.clone()
+            } else {
+                let item_hexes = PickUpHandler::hexes_with_items(world_view, selected);
+                let draw_list = draw_hex_boundary(world_view, logic::visibility::faction_visibility_for_character(world_view, selected),
+                                                  &item_hexes, ::common::Color::new(0.7, 0.6, 0.2, 0.8));
+
+                self.last_overlay_key = key_state;
+                self.last_overlay = draw_list.clone();
+                draw_list
+            };
+
+            let target_hex = game_state.hovered_hex_coord;
+            if !loot::ground_items_at(world_view, target_hex).is_empty() && logic::movement::position_of(world_view, selected).distance(&target_hex) > 1.0 {
+                if let Some((path, _)) = logic::movement::path_adjacent_to_hex(world_view, selected, target_hex) {
+                    draw_list.extend(draw_movement_path(world_view, selected, path));
+                }
+            }
+
+            draw_list
+        } else {
+            DrawList::none()
+        }
+    }
+
+    fn update_widgets(&mut self, gui: &mut GUI, grsrc: &mut GraphicsResources, world: &World, world_view: &WorldView, game_state: &GameState, player_action: &PlayerActionType) {}
+
+    fn hide_widgets(&mut self, gui: &mut GUI) {}
+}
@@ -15,6 +15,8 @@ use common::prelude::strf;
 use game::World;
 use game::universe::WorldRef;
 use game::scenario::test_scenarios::FirstEverScenario;
+use game::scenario::data_scenario;
+use game::scenario::data_scenario::DataScenario;
 use graphics::GraphicsResources;
 
 #[derive(Default)]
@@ -22,6 +24,8 @@ pub struct MainMenu {
     pub menu: Widget,
     pub load_button: Button,
     pub new_button: Button,
+    /// one button for each scenario found in the assets folder, alongside the scenario it starts
+    pub scenario_buttons: Vec<(Button, DataScenario)>,
     pub exit_button: Button
 }
 
@@ -40,10 +44,24 @@ impl MainMenu {
             .x(Positioning::centered())
             .apply(gui);
 
+        let mut scenario_buttons : Vec<(Button, DataScenario)> = Vec::new();
+        for scenario in data_scenario::available_scenarios() {
+            let button = {
+                let above = scenario_buttons.last().map(|(b, _)| b).unwrap_or(&new_button);
+                Button::new(scenario.definition.name.as_str())
+                    .parent(&menu)
+                    .font_size(FontSize::Large)
+                    .below(above, 10.px())
+                    .x(Positioning::centered())
+                    .apply(gui)
+            };
+            scenario_buttons.push((button, scenario));
+        }
+
         let mut load_button = Button::new("Continue")
             .parent(&menu)
             .font_size(FontSize::ExtraLarge)
-            .below(&new_button, 10.px())
+            .below(scenario_buttons.last().map(|(b, _)| b).unwrap_or(&new_button), 10.px())
             .x(Positioning::centered())
             .apply(gui);
         if open_save_file(false).is_none() {
@@ -61,6 +79,7 @@ impl MainMenu {
             menu,
             load_button,
             new_button,
+            scenario_buttons,
             exit_button
         }
     }
@@ -82,6 +101,8 @@ impl GameMode for MainMenu {
                     event_bus.push_event(GameModeEvent::InitScenario(box FirstEverScenario{}));
                 } else if button == &self.exit_button.id() {
                     event_bus.push_event(GameModeEvent::Exit);
+                } else if let Some((_, scenario)) = self.scenario_buttons.iter().find(|(b, _)| button == &b.id()) {
+                    event_bus.push_event(GameModeEvent::InitScenario(box scenario.clone()));
                 }
             }
         }
@@ -258,24 +258,6 @@ impl TacticalMode {
             }
         }
 
-        let mut living_enemy = false;
-        let mut living_ally = false;
-        for (cref,char_data) in world_view.entities_with_data::<CharacterData>() {
-            if char_data.is_alive() {
-                let allegiance = world_view.data::<AllegianceData>(*cref);
-                if allegiance.faction != self.player_faction {
-                    living_enemy = true;
-                } else {
-                    living_ally = true;
-                }
-            }
-        }
-        if !living_enemy {
-            self.victory_time = self.victory_time.or(Some(world.next_time -1));
-        } else if !living_ally {
-            self.defeat_time = self.defeat_time.or(Some(world.next_time -1));
-        }
-
         self.skipped_characters.clear();
         if self.selected_character.is_none() {
             println!("End turn complete, selecting a character");
@@ -373,8 +355,12 @@ impl GameMode for TacticalMode {
         });
 
         game::components::SpawningComponent::register(world);
+        game::components::RenewalComponent::register(world);
 
         VisibilityComputor::register(world);
+        logic::awareness::register(world);
+        logic::objectives::register(world);
+        logic::triggers::register(world);
     }
 
     fn update(&mut self, universe: &mut Universe, _: f64, event_bus: &mut EventBus<GameModeEvent>) {
@@ -382,6 +368,14 @@ impl GameMode for TacticalMode {
         let dt = Instant::now().duration_since(self.last_update_time).subsec_nanos() as f64 / 1e9f64;
         self.last_update_time = Instant::now();
         self.camera.position = self.camera.position + self.camera.move_delta * (dt as f32) * self.camera.move_speed;
+
+        if self.victory_time.is_none() && self.defeat_time.is_none() {
+            match logic::objectives::outcome(world.view(), self.player_faction) {
+                Some(Outcome::Victory) => self.victory_time = Some(world.next_time - 1),
+                Some(Outcome::Defeat) => self.defeat_time = Some(world.next_time - 1),
+                None => ()
+            }
+        }
     }
 
     fn update_gui(&mut self, universe: &mut Universe, gsrc : &mut GraphicsResources, ui: &mut GUI, frame_id: Option<Wid>, event_bus: &mut EventBus<GameModeEvent>) {
@@ -441,7 +435,7 @@ impl GameMode for TacticalMode {
         // draw list for the map tiles
         let terrain_draw_list = self.terrain_renderer.render_tiles(world_view, self.player_faction, self.display_event_clock, culling_rect);
         // draw list for items on the map
-        let item_draw_list = self.item_renderer.render_items(world_view, g.resources, culling_rect);
+        let item_draw_list = self.item_renderer.render_items(world_view, g.resources, self.player_faction, culling_rect);
         // draw list for the units and built-in unit UI elements
         let unit_draw_list = self.unit_renderer.render_units(world_view, self.display_event_clock, self.player_faction, self.selected_character);
 
@@ -9,6 +9,7 @@ use game::entities::CharacterStore;
 use game::entities::GraphicsData;
 use game::entities::StrikeResult;
 use game::entities::PositionData;
+use game::entities::Skill;
 use game::Entity;
 use game::entity::EntityData;
 use game::GameEvent;
@@ -47,6 +48,9 @@ pub fn animation_elements_for_new_event(world_view: &WorldView, wrapper: &GameEv
             GameEvent::DamageTaken { entity, damage_taken, .. } => {
                 animate_damage(world_view, entity, damage_taken)
             }
+            GameEvent::SkillLevelUp { entity, skill, level } => {
+                animate_level_up(world_view, entity, skill, level)
+            }
             _ => vec![]
         }
     } else if wrapper.is_ended() {
@@ -263,6 +267,31 @@ fn animate_damage(world_view: &WorldView, entity: Entity, damage_done: u32) -> V
     vec![box animation_group]
 }
 
+fn animate_level_up(world_view: &WorldView, entity: Entity, skill: Skill, level: i32) -> Vec<Box<AnimationElement>> {
+    let entity_data = world_view.character(entity);
+
+    let start_color = entity_data.graphics.color;
+    let glow_animation = EntityFieldAnimation::new(
+        entity,
+        Interpolation::linear_from_endpoints(start_color, Color::new(1.0, 0.9, 0.4, 1.0)).circular(),
+        |data: &mut GraphicsData, new_value| { data.color = new_value; },
+        1.0,
+    ).with_blocking_duration(0.0);
+
+    let color = Color::new(0.95, 0.8, 0.2, 1.0);
+    let msg = format!("{} {}!", skill.name(), level);
+    let rising_text = TextAnimationElement::new(msg, FontSize::HeadingMajor, entity_data.position.hex.as_cart_vec() + CartVec::new(0.0, 0.8), color, 3.0)
+        .with_delta(CartVec::new(0.0, 1.0), InterpolationType::Linear)
+        .with_end_color(color.with_a(0.0), InterpolationType::Linear)
+        .with_outline_color(Color::greyscale(0.2).with_a(0.7), Color::greyscale(0.2).with_a(0.0), InterpolationType::Linear)
+        .with_blocking_duration(0.0);
+
+    vec![
+        box glow_animation,
+        box rising_text,
+    ]
+}
+
 fn animate_harvest(world_view: &WorldView, harvester: Entity, harvestable: Entity, harvested_from : Entity, resource: Entity, amount: Option<i32>) -> Vec<Box<AnimationElement>> {
     let move_duration = 0.7;
 
@@ -18,6 +18,7 @@ use gui::*;
 use gui::ToGUIUnit;
 use common::Color;
 use game::entities::actions::*;
+use game::archetype::EntityArchetype;
 use game::entities::reactions::reaction_types;
 
 use game::entities::reactions::ReactionTypeRef;
@@ -41,6 +42,7 @@ use gui::attack_descriptions::AttackDetailsWidget;
 use game::reflect::*;
 use gui::messages_widget::MessagesDisplay;
 use gui::messages_widget::Message;
+use gui::objectives_widget::ObjectivesDisplay;
 use gui::inventory_widget::*;
 use game::entities::inventory::InventoryData;
 use action_ui_handlers::*;
@@ -59,6 +61,7 @@ use gui::harvest_detail_widget::HarvestSummaryWidget;
 use graphics::GraphicsResources;
 use action_ui_handlers::player_action_handler::PlayerActionHandler;
 use action_ui_handlers::harvest_handler::HarvestHandler;
+use action_ui_handlers::pick_up_handler::PickUpHandler;
 use game::logic::item;
 use std::collections::HashSet;
 use game::prelude::GameEventWrapper;
@@ -83,6 +86,7 @@ pub struct TacticalGui {
     targeting_draw_list : DrawList,
     last_targeting_info : Option<(KeyGameState, PlayerActionType)>,
     messages_display : MessagesDisplay,
+    objectives_display : ObjectivesDisplay,
     inventory_widget: inventory_widget::InventoryDisplay,
     crafting_widget: crafting_widget::CraftingWidget,
     open_auxiliary_windows : Vec<AuxiliaryWindows>,
@@ -123,6 +127,7 @@ impl TacticalGui {
         let player_action_handlers : Vec<Box<PlayerActionHandler>> = vec![
             MoveAndAttackHandler::new(),
             HarvestHandler::new(),
+            PickUpHandler::new(),
         ];
 
         TacticalGui {
@@ -136,6 +141,7 @@ impl TacticalGui {
             targeting_draw_list : DrawList::none(),
             last_targeting_info : None,
             messages_display : MessagesDisplay::new(gui, &main_area),
+            objectives_display : ObjectivesDisplay::new(gui, &main_area),
             inventory_widget : inventory_widget::InventoryDisplay::new(strf("Character Inventory"), &main_area),
             crafting_widget : crafting_widget::CraftingWidget::new(&main_area),
             open_auxiliary_windows: Vec::new(),
@@ -267,6 +273,7 @@ impl TacticalGui {
 
     pub fn update_gui(&mut self, world: &mut World, world_view : &WorldView, gsrc : &mut GraphicsResources, gui: &mut GUI, frame_id: Option<Wid>, game_state: GameState, game_mode_event_bus : &mut EventBus<GameModeEvent>) {
         self.messages_display.update(gui);
+        self.objectives_display.update(gui, world_view, game_state.player_faction);
 
         let selected_action = self.selected_player_action(world_view, &game_state);
 
@@ -288,6 +295,7 @@ impl TacticalGui {
                 }
                 actions.push(PlayerActionType::InteractWithInventory);
                 actions.push(PlayerActionType::Harvest);
+                actions.push(PlayerActionType::PickUp);
                 actions.push(PlayerActionType::Wait);
 
                 self.action_bar.update(gui, world_view, actions, &game_state, &mut control);
@@ -308,7 +316,16 @@ impl TacticalGui {
                 .flat_map(|ent| world.data_opt::<EquipmentData>(*ent).map(|eq| eq.equipped.clone()).unwrap_or(Vec::new()))
                 .collect();
 
-            let main_inv = vec![InventoryDisplayData::new(items.clone(), destacked, HashSet::new(), all_equipped_items, "Character Inventory", vec![selected], true, inv_data.inventory_size)];
+            let load = InventoryLoad {
+                weight : item::inventory_weight(world_view, selected),
+                capacity : item::carry_capacity(world_view, selected),
+                encumbrance : item::encumbrance(world_view, selected),
+            };
+            let mut main_inv = vec![InventoryDisplayData::new(items.clone(), destacked, HashSet::new(), all_equipped_items, "Character Inventory", vec![selected], true, inv_data.inventory_size).with_load(load)];
+            // bags and the like that the character is carrying each get a tab of their own alongside the character's inventory
+            for container in item::carried_containers(world_view, selected) {
+                main_inv.push(container_display_data(world_view, container));
+            }
 
             let mut ground_items = Vec::new();
             let mut ground_entities = Vec::new();
@@ -318,12 +335,16 @@ impl TacticalGui {
                 if let Some(ent) = world_view.tile_ent_opt(ground_coord) {
                     ground_entities.push(ent.entity);
                     if let Some(inv) = world_view.data_opt::<InventoryData>(ent.entity) {
-                        ground_items.extend(inv.items.clone());
-                        destacked_ground_items.extend(item::items_in_inventory(world_view, ent.entity));
+                        // whatever has been built here stands on the ground rather than lying on it to be picked up
+                        ground_items.extend(inv.items.iter().filter(|i| ! logic::building::is_built(world_view, **i)));
+                        destacked_ground_items.extend(logic::loot::ground_items_at(world_view, ground_coord));
                     }
                 }
             }
-            let other_inv = vec![InventoryDisplayData::new(ground_items, destacked_ground_items, HashSet::new(), HashSet::new(), "Ground", ground_entities, false, None)];
+            let mut other_inv = vec![InventoryDisplayData::new(ground_items, destacked_ground_items, HashSet::new(), HashSet::new(), "Ground", ground_entities, false, None)];
+            for container in item::ground_containers_near(world_view, selected) {
+                other_inv.push(container_display_data(world_view, container));
+            }
             self.inventory_widget.update(gui, world, main_inv, other_inv, &mut control);
             self.crafting_widget.update(world, gui, &game_state, &mut control);
         } else {
@@ -412,6 +433,18 @@ impl TacticalGui {
                             logic::item::unequip_item(world, *item, *equip_on, true);
                         }
                     },
+                    TacticalEvents::ConsumeItemRequested { item, consumer } => {
+                        logic::needs::consume(world, *consumer, *item);
+                    },
+                    TacticalEvents::BuildRequested { structure, builder } => {
+                        let at = movement::position_of(world_view, *builder);
+                        let (can, reason) = logic::building::can_build(world_view, *builder, *structure, at);
+                        if can {
+                            logic::building::build(world, *builder, *structure, at);
+                        } else {
+                            self.messages_display.add_message(Message::new(format!("Could not build {}: {}", world_view.signifier(*structure), reason)));
+                        }
+                    },
                     TacticalEvents::SpeechDialogDismissed(character, wid) => {
                         self.speech_widgets.retain(|w| w.id() != *wid);
                     },
@@ -459,7 +492,118 @@ impl TacticalGui {
 
                 vec![box WaitAnimationElement::new(1.0)]
             },
+            Some(GameEvent::TimeOfDayChanged { time_of_day, day }) => {
+                let text = match time_of_day {
+                    TimeOfDay::Dawn => format!("Dawn breaks on day {}", day + 1),
+                    TimeOfDay::Daylight => strf("The sun is up"),
+                    TimeOfDay::Dusk => strf("Dusk falls, the light is fading"),
+                    TimeOfDay::Night => strf("Night has fallen, beware what hunts in the dark"),
+                };
+                self.messages_display.add_message(Message::new(text));
+                vec![]
+            },
+            Some(GameEvent::SeasonChanged { season }) => {
+                self.messages_display.add_message(Message::new(format!("{:?} has arrived", season)));
+                vec![]
+            },
+            Some(GameEvent::WeatherChanged { weather }) => {
+                let text = match weather {
+                    Weather::Clear => strf("The skies clear"),
+                    Weather::Rain => strf("Rain begins to fall, fires will burn poorly"),
+                    Weather::Snow => strf("Snow is falling, travel will be slow"),
+                    Weather::Fog => strf("A thick fog rolls in, it is hard to see far"),
+                    Weather::Heat => strf("A heat wave sets in, tiring everyone quickly"),
+                };
+                self.messages_display.add_message(Message::new(text));
+                vec![]
+            },
+            Some(GameEvent::ItemBroken { item, .. }) => {
+                self.messages_display.add_message(Message::new(format!("{} has broken", world_view.signifier(*item))));
+                vec![]
+            },
+            Some(GameEvent::EncumbranceChanged { character, encumbrance }) => {
+                let text = match encumbrance {
+                    Encumbrance::Unencumbered => format!("{} is no longer weighed down", world_view.signifier(*character)),
+                    other => format!("{} is {}", world_view.signifier(*character), other.name()),
+                };
+                self.messages_display.add_message(Message::new(text));
+                vec![]
+            },
+            Some(GameEvent::ActionInterrupted { entity, action }) => {
+                self.messages_display.add_message(Message::new(format!("{} was interrupted, {} progress saved ({}/{})", world_view.signifier(*entity), action.action_type.name().to_lowercase(), action.ap.current, action.ap.required)));
+                vec![]
+            },
+            Some(GameEvent::RecipeDiscovered { character, recipe }) => {
+                let recipe_name = match world_view.data::<Recipe>(*recipe).result {
+                    EntityArchetype::Archetype(arch) | EntityArchetype::CopyEnitity(arch) => world_view.signifier(arch),
+                    EntityArchetype::Sentinel => strf("something new"),
+                };
+                self.messages_display.add_message(Message::new(format!("{} has figured out how to make {}", world_view.signifier(*character), recipe_name)));
+                vec![]
+            },
+            Some(GameEvent::SkillLevelUp { entity, skill, level }) => {
+                self.messages_display.add_message(Message::new(format!("{} has reached level {} in {}", world_view.signifier(*entity), level, skill.name())));
+                vec![]
+            },
+            Some(GameEvent::MoraleCheck { entity, morale, state, reason }) => {
+                let outcome = match state {
+                    MoraleState::Steady => "rallies",
+                    MoraleState::Fleeing => "breaks and flees",
+                    MoraleState::Surrendered => "surrenders",
+                };
+                self.messages_display.add_message(Message::new(format!("{} {} ({}, morale {})", world_view.signifier(*entity), outcome, reason, morale)));
+                vec![]
+            },
+            Some(GameEvent::NeedSeverityChanged { character, need, severity }) => {
+                let text = match (need, severity) {
+                    (_, NeedSeverity::Satisfied) => format!("{}'s {} has been seen to", world_view.signifier(*character), need.name()),
+                    (Need::Hunger, _) => format!("{} is {} hungry", world_view.signifier(*character), severity_adverb(*severity)),
+                    (Need::Thirst, _) => format!("{} is {} thirsty", world_view.signifier(*character), severity_adverb(*severity)),
+                    (Need::Cold, _) => format!("{} is {} cold", world_view.signifier(*character), severity_adverb(*severity)),
+                };
+                self.messages_display.add_message(Message::new(text));
+                vec![]
+            },
+            Some(GameEvent::ItemConsumed { consumer, item }) => {
+                self.messages_display.add_message(Message::new(format!("{} ate {}", world_view.signifier(*consumer), world_view.signifier(*item))));
+                vec![]
+            },
+            Some(GameEvent::ObjectiveCompleted { faction, description, outcome }) if world_view.data::<FactionData>(*faction).player_faction => {
+                let message = match outcome {
+                    Outcome::Victory => format!("Objective complete: {}", description),
+                    Outcome::Defeat => format!("Objective failed: {}", description),
+                };
+                self.messages_display.add_message(Message::new(message));
+                vec![]
+            },
+            Some(GameEvent::PerkGained { entity, perk }) => {
+                let description = perks::perk_with_name(perk).description;
+                self.messages_display.add_message(Message::new(format!("{} has gained the {} perk: {}", world_view.signifier(*entity), perk, description)));
+                vec![]
+            },
+            Some(GameEvent::ItemCrafted { item, crafter, .. }) => {
+                self.messages_display.add_message(Message::new(format!("{} crafted {}", world_view.signifier(*crafter), world_view.signifier(*item))));
+                vec![]
+            },
+            Some(GameEvent::ItemRepaired { item, durability_restored, .. }) => {
+                let durability = &world_view.data::<DurabilityData>(*item).durability;
+                self.messages_display.add_message(Message::new(format!("{} repaired by {} ({}/{})", world_view.signifier(*item), durability_restored, durability.cur_value(), durability.max_value())));
+                vec![]
+            },
             _ => vec![]
         }
     }
-}
\ No newline at end of file
+}
+fn severity_adverb(severity : NeedSeverity) -> &'static str {
+    match severity {
+        NeedSeverity::Satisfied | NeedSeverity::Uncomfortable => "getting",
+        NeedSeverity::Suffering => "badly",
+        NeedSeverity::Desperate => "desperately",
+    }
+}
+
+fn container_display_data(view : &WorldView, container : Entity) -> InventoryDisplayData {
+    let inv = view.data::<InventoryData>(container);
+    let name = view.data_opt::<IdentityData>(container).map(|ident| ident.effective_name().to_string().capitalized()).unwrap_or_else(|| strf("Container"));
+    InventoryDisplayData::new(inv.items.clone(), item::items_in_inventory(view, container), HashSet::new(), HashSet::new(), name, vec![container], false, inv.inventory_size)
+}
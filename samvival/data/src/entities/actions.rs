@@ -31,12 +31,16 @@ pub enum ActionType {
     TransferItem { item : Entity, from : Entity, to : Entity },
     Harvest { from : AxialCoord, harvestable : Entity, preserve_renewable : bool },
     Craft { base_recipe : Entity, ingredients : HashMap<Taxon, Vec<Entity>> },
+    PickUp { from : AxialCoord },
+    Build { structure : Entity, at : AxialCoord },
 }
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize, Fields)]
 pub struct ActionData {
     pub active_action : Option<Action>,
     pub active_reaction: ReactionTypeRef,
+    // an action that was cut short, its progress is picked back up if the same action is started again
+    pub interrupted_action : Option<Action>,
 }
 impl EntityData for ActionData {}
 
@@ -49,6 +53,8 @@ impl ActionType {
             TransferItem { .. } => "Transfer Item",
             Harvest { .. } => "Harvest",
             Craft { .. } => "Craft",
+            PickUp { .. } => "Pick Up",
+            Build { .. } => "Build",
         }
     }
 }
\ No newline at end of file
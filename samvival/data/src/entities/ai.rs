@@ -0,0 +1,76 @@
+use common::prelude::*;
+use game::prelude::*;
+use game::EntityData;
+use std::collections::HashMap;
+
+
+/// A single factor an AI controlled creature takes into account when weighing up what to do. Each candidate action is
+/// scored against every consideration the creature has, scaled by the weight given to it, and the best total wins
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum Consideration {
+    /// favors attacks by the damage they can be expected to do
+    DamageDealt,
+    /// favors attacks likely to put their target down
+    KillChance,
+    /// disfavors attacks by the damage expected to be taken in counterattacks
+    DamageTaken,
+    /// disfavors ending up in hexes that many enemies could reach and attack next turn
+    Threat,
+    /// favors closing the distance to the nearest enemy when there is nothing to attack yet
+    Approach,
+    /// favors pulling back out of reach of enemies, more strongly the more hurt the creature is
+    SelfPreservation,
+    /// favors gathering from nearby harvestables when no enemies are around
+    Forage,
+    /// favors moving out into hexes that can't currently be seen, when no enemies are known of at all
+    Search,
+    /// favors attacking whichever enemy the faction as a whole has picked out for this creature
+    FocusFire,
+    /// disfavors creatures fighting from range getting ahead of their faction's melee fighters
+    Formation,
+    /// favors reactions suited to how threatened the creature is at the end of its turn
+    Reaction,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, Fields)]
+pub struct AIData {
+    pub considerations : Vec<(Consideration, f32)>,
+}
+impl EntityData for AIData {}
+
+impl Default for AIData {
+    fn default() -> Self {
+        AIData {
+            considerations : vec![
+                (Consideration::DamageDealt, 1.0),
+                (Consideration::KillChance, 4.0),
+                (Consideration::DamageTaken, 0.75),
+                (Consideration::Threat, 0.25),
+                (Consideration::Approach, 0.5),
+                (Consideration::SelfPreservation, 0.5),
+                (Consideration::Search, 0.25),
+                (Consideration::FocusFire, 1.5),
+                (Consideration::Formation, 1.0),
+                (Consideration::Reaction, 1.0),
+            ]
+        }
+    }
+}
+
+impl AIData {
+    pub fn new(considerations : Vec<(Consideration, f32)>) -> AIData {
+        AIData { considerations }
+    }
+
+    /// how much the given consideration matters, 0.0 for any the creature doesn't care about
+    pub fn weight(&self, consideration : Consideration) -> f32 {
+        self.considerations.iter().filter(|(c,_)| *c == consideration).map(|(_,w)| *w).sum()
+    }
+}
+
+/// where a faction last saw each of its enemies, attached to the faction itself
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Fields)]
+pub struct AwarenessData {
+    pub last_seen : HashMap<Entity, AxialCoord>,
+}
+impl EntityData for AwarenessData {}
@@ -6,6 +6,9 @@ use game::EntityData;
 use entities::selectors::EntitySelector;
 use entities::item::Worth;
 use entities::item::StackWith;
+use entities::item::DurabilityData;
+use entities::item::StructureData;
+use entities::inventory::ContainerData;
 
 #[derive(Debug,Clone,Default,Serialize,Deserialize,Fields)]
 pub struct EntityMetadata {
@@ -22,6 +25,13 @@ pub struct ItemArchetype {
     pub worth : Worth,
     pub tool_data : Option<ToolData>,
     pub attributes : AttributeData,
+    // maximum durability of items created from this archetype, items without durability never wear out
+    pub durability : Option<i32>,
+    pub weight : i32,
+    // items created from this archetype can hold other items
+    pub container : Option<ContainerData>,
+    // items created from this archetype are put up on the map rather than carried
+    pub structure : Option<StructureData>,
 }
 impl EntityData for ItemArchetype {}
 
@@ -35,6 +45,10 @@ impl Default for ItemArchetype {
             worth : Worth::low(-1),
             tool_data : None,
             attributes : AttributeData::default(),
+            durability : Some(DurabilityData::DEFAULT_DURABILITY),
+            weight : 2,
+            container : None,
+            structure : None,
         }
     }
 }
\ No newline at end of file
@@ -6,8 +6,6 @@ use game::modifiers::*;
 use game::prelude::*;
 use game::EntityData;
 use std::collections::HashMap;
-use std::hash::Hash;
-use std::hash::Hasher;
 use serde::Serialize;
 use serde::Serializer;
 use serde::Deserialize;
@@ -15,45 +13,119 @@ use serde::Deserializer;
 use serde::de::Visitor;
 use std::fmt::Formatter;
 use std::fmt::Error;
-use std::ops::Add;
 use std::fmt::Debug;
-use std::ops::Sub;
 
+use std::borrow::Cow;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+use std::path::PathBuf;
+use std::env::current_exe;
+use ron;
+use find_folder;
+
+/// Attributes are defined in data rather than code, so that new ones can be added to the game without recompiling. The few
+/// that game logic needs to refer to directly are declared here by id, but their definitions come from the definitions
+/// file like any other
 pub mod attributes {
     use super::*;
 
-    pub static TrainingWeapon: AttributeType = &AttributeTypeStruct {
-        name: "training weapons",
-        description: "this weapon is more suitable for practice than actual combat. It doesn't do much damage but it provides an experience bonus when used.",
-        value_type: ValueType::BoundedNumeric(None, Some(3)),
-        combination: Combination::Additive,
-        remove_on_zero: true,
-    };
+    pub static TrainingWeapon: AttributeType = AttributeType(Cow::Borrowed("training_weapon"));
 
-    pub static Sentinel: AttributeType = &AttributeTypeStruct {
-        name: "default",
-        description: "default attribute, indicates that something couldn't be found",
-        value_type: ValueType::Boolean,
-        combination: Combination::Replace,
-        remove_on_zero: true
-    };
+    pub static Sentinel: AttributeType = AttributeType(Cow::Borrowed("default"));
+
+    /// where designers keep attribute definitions, relative to the assets folder
+    pub const ATTRIBUTE_DEFINITIONS_PATH : &str = "survival/attributes.ron";
+
+    /// stands in for any attribute that has no definition, so that looking one up never fails outright
+    pub(crate) fn sentinel_definition() -> AttributeDefinition {
+        AttributeDefinition {
+            id: Sentinel.id().to_string(),
+            name: strf("default"),
+            description: strf("default attribute, indicates that something couldn't be found"),
+            value_type: ValueType::Boolean,
+            combination: Combination::Replace,
+            remove_on_zero: true,
+            effects: Vec::new(),
+        }
+    }
+
+    /// reads a RON list of attribute definitions
+    pub fn read_attribute_definitions<P : AsRef<Path>>(path : P) -> Result<Vec<AttributeDefinition>, String> {
+        let file = File::open(path.as_ref()).map_err(|e| format!("could not open attribute definitions {:?}: {}", path.as_ref(), e))?;
+        ron::de::from_reader(BufReader::new(file)).map_err(|e| format!("could not parse attribute definitions {:?}: {}", path.as_ref(), e))
+    }
 
+    /// reads a RON list of attribute definitions and registers all of them with the world, returning how many there were
+    pub fn load_attribute_definitions<P : AsRef<Path>>(world : &mut World, path : P) -> Result<usize, String> {
+        let definitions = read_attribute_definitions(path)?;
+        let count = definitions.len();
+        for definition in definitions {
+            AttributeDefinitions::register_attribute(world, definition);
+        }
+        Ok(count)
+    }
+
+    /// loads the attribute definitions from the game's assets folder, searching from the executable's location the same way
+    /// the graphics assets are found
+    pub fn load_attribute_definitions_from_assets(world : &mut World) -> Result<usize, String> {
+        let mut cur_dir = current_exe().map_err(|e| format!("could not determine executable location: {}", e))?;
+        cur_dir.pop();
+        let assets_path : PathBuf = find_folder::SearchFolder {
+            start: cur_dir,
+            direction: find_folder::Search::ParentsThenKids(4, 4),
+        }.for_folder("assets").map_err(|e| format!("could not find assets folder: {}", e))?;
+        load_attribute_definitions(world, assets_path.join(ATTRIBUTE_DEFINITIONS_PATH))
+    }
+}
 
-    pub static AllAttributes : &[AttributeType] = &[Sentinel, TrainingWeapon];
+/// Every attribute definition known to the world, keyed by id. Starts out knowing only the sentinel, everything else is
+/// registered when the definitions file is loaded
+#[derive(Clone, Debug, Serialize, Deserialize, Fields)]
+pub struct AttributeDefinitions {
+    pub(crate) definitions : HashMap<String, AttributeDefinition>,
+}
+impl EntityData for AttributeDefinitions {}
 
-    pub fn attribute_with_name(name : &str) -> AttributeType {
-        AllAttributes.iter().find(|at| at.name == name).unwrap_or(&&Sentinel)
+impl Default for AttributeDefinitions {
+    fn default() -> Self {
+        AttributeDefinitions {
+            definitions : vec![attributes::sentinel_definition()].into_iter().map(|d| (d.id.clone(), d)).collect()
+        }
     }
 }
 
-#[derive(PartialEq,Clone,Copy,Eq,Debug)]
+impl AttributeDefinitions {
+    pub fn init_attributes(world : &mut World) {
+        world.ensure_world_data::<AttributeDefinitions>();
+    }
+
+    /// adds or replaces the definition of an attribute
+    pub fn register_attribute(world : &mut World, definition : AttributeDefinition) {
+        world.modify_world(AttributeDefinitions::definitions.set_key_to(definition.id.clone(), definition), None);
+    }
+
+    /// the registered definition of the given attribute. Unknown attributes are reported and treated as the sentinel
+    pub fn definition(&self, attr : &AttributeType) -> &AttributeDefinition {
+        self.definitions.get(attr.id()).unwrap_or_else(|| {
+            warn!("No definition registered for attribute {:?}, treating it as having no effect", attr.id());
+            self.definitions.get(attributes::Sentinel.id()).expect("sentinel attribute must always be registered")
+        })
+    }
+
+    pub fn all_attributes(&self) -> Vec<AttributeType> {
+        self.definitions.keys().filter(|k| k.as_str() != attributes::Sentinel.id()).map(|k| AttributeType::new(k.clone())).collect()
+    }
+}
+
+#[derive(PartialEq,Clone,Copy,Eq,Debug,Serialize,Deserialize)]
 pub enum ValueType {
     Boolean,
     Numeric,
     BoundedNumeric(Option<i32>, Option<i32>),
 }
 
-#[derive(PartialEq,Clone,Copy,Eq,Debug)]
+#[derive(PartialEq,Clone,Copy,Eq,Debug,Serialize,Deserialize)]
 pub enum Combination {
     Replace,
     Additive,
@@ -61,36 +133,55 @@ pub enum Combination {
     Minimal,
 }
 
-#[derive(Clone, Copy, Eq)]
-pub struct AttributeTypeStruct {
-    name: Str,
+/// What an attribute does to the item that has it, all amounts are per point of the attribute
+#[derive(PartialEq,Clone,Debug,Serialize,Deserialize)]
+pub enum AttributeEffect {
+    ToHit(i32),
+    Damage(i32),
+    ApCost(i32),
+    HarvestAmount(i32),
+    Xp(i32),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AttributeDefinition {
+    // stable identifier, this is what is saved and what other data refers to, so it should never change once in use
+    pub id: String,
+    pub name: String,
+    pub description: String,
     // what kind of value is this represented as
-    value_type: ValueType,
+    pub value_type: ValueType,
     // how this attribute should be combined, when multiple of the same key are added together
-    combination: Combination,
+    pub combination: Combination,
     // whether the trait should automatically be removed on zero
-    remove_on_zero: bool,
-    // a description of the attribute
-    description: Str,
+    #[serde(default)]
+    pub remove_on_zero: bool,
+    #[serde(default)]
+    pub effects: Vec<AttributeEffect>,
 }
-pub type AttributeType = &'static AttributeTypeStruct;
 
-impl Debug for AttributeTypeStruct {
-    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
-        write!(f, "{}", self.name)
+/// A reference to an attribute by its stable id, the definition itself is looked up in the registry
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct AttributeType(Cow<'static, str>);
+
+impl AttributeType {
+    pub fn new<S : Into<String>>(id : S) -> AttributeType {
+        AttributeType(Cow::Owned(id.into()))
+    }
+
+    pub fn id(&self) -> &str {
+        &self.0
     }
 }
-impl PartialEq<AttributeTypeStruct> for AttributeTypeStruct {
-    fn eq(&self, other: &AttributeTypeStruct) -> bool { self.name == other.name }
-}
-impl Hash for AttributeTypeStruct {
-    fn hash<H: Hasher>(&self, state: &mut H) {
-        state.write(self.name.as_bytes());
+
+impl Debug for AttributeType {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "{}", self.id())
     }
 }
 impl Serialize for AttributeType {
     fn serialize<S>(&self, serializer: S) -> Result<<S as Serializer>::Ok, <S as Serializer>::Error> where S: Serializer {
-        serializer.serialize_str(self.name)
+        serializer.serialize_str(self.id())
     }
 }
 struct ATVisitor;
@@ -98,15 +189,15 @@ impl <'de> Visitor<'de> for ATVisitor {
     type Value = AttributeType;
 
     fn expecting(&self, formatter: &mut Formatter) -> Result<(), Error> {
-        write!(formatter, "AttributeType name")
+        write!(formatter, "AttributeType id")
     }
 
     fn visit_str<E>(self, v: &str) -> Result<<Self as Visitor<'de>>::Value, E> where E: ::serde::de::Error, {
-        Ok(attributes::attribute_with_name(v))
+        Ok(AttributeType::new(v))
     }
 
     fn visit_string<E>(self, v: String) -> Result<<Self as Visitor<'de>>::Value, E> where E: ::serde::de::Error, {
-        Ok(attributes::attribute_with_name(&v))
+        Ok(AttributeType::new(v))
     }
 }
 impl <'de> Deserialize<'de> for AttributeType {
@@ -114,74 +205,36 @@ impl <'de> Deserialize<'de> for AttributeType {
         deserializer.deserialize_str(ATVisitor)
     }
 }
-impl Add for AttributeValue {
-    type Output = AttributeValue;
-    fn add(self, rhs: AttributeValue) -> AttributeValue {
-        let AttributeValue(attr_type, amnt_a) = self;
-        let AttributeValue(other_attr_type, amnt_b) = rhs;
-
-        if attr_type != other_attr_type {
-            warn!("Attempting to combine two attributes of different types, {:?} and {:?}", attr_type, other_attr_type);
-            self
-        } else {
-            let new_raw_value = match attr_type.combination {
-                Combination::Additive => amnt_a + amnt_b,
-                Combination::Maximal => amnt_a.max(amnt_b),
-                Combination::Minimal => amnt_a.min(amnt_b),
-                Combination::Replace => amnt_b
-            };
-
-            let new_effective_value = match attr_type.value_type {
-                ValueType::Boolean => if new_raw_value > 0 { 1 } else { 0 },
-                ValueType::Numeric => new_raw_value,
-                ValueType::BoundedNumeric(min, max) => {
-                    let mind = if let Some(min) = min { new_raw_value.min(min) } else { new_raw_value };
-                    let maxd = if let Some(max) = max { mind.max(max) } else { mind };
-                    maxd
-                }
-            };
-
-            AttributeValue(attr_type, new_effective_value)
-        }
+impl AttributeDefinition {
+    /// the value an attribute ends up with when more of it is added to an existing value, according to how it combines
+    fn combine(&self, existing : i32, added : i32) -> i32 {
+        let raw_value = match self.combination {
+            Combination::Additive => existing + added,
+            Combination::Maximal => existing.max(added),
+            Combination::Minimal => existing.min(added),
+            Combination::Replace => added
+        };
+        self.effective_value(raw_value)
     }
-}
-impl Sub for AttributeValue {
-    type Output = AttributeValue;
-    fn sub(self, rhs: AttributeValue) -> AttributeValue {
-        let AttributeValue(attr_type, amnt_a) = self;
-        let AttributeValue(other_attr_type, amnt_b) = rhs;
-
-        if attr_type != other_attr_type {
-            warn!("Attempting to combine two attributes of different types, {:?} and {:?}", attr_type, other_attr_type);
-            self
-        } else {
-            let new_raw_value = match attr_type.combination {
-                Combination::Additive => amnt_a - amnt_b,
-                Combination::Maximal => amnt_a.min(amnt_b),
-                Combination::Minimal => amnt_a.max(amnt_b),
-                Combination::Replace => amnt_b
-            };
-
-            let new_effective_value = match attr_type.value_type {
-                ValueType::Boolean => if new_raw_value > 0 { 1 } else { 0 },
-                ValueType::Numeric => new_raw_value,
-                ValueType::BoundedNumeric(min, max) => {
-                    let mind = if let Some(min) = min { new_raw_value.min(min) } else { new_raw_value };
-                    let maxd = if let Some(max) = max { mind.max(max) } else { mind };
-                    maxd
-                }
-            };
-
-            AttributeValue(attr_type, new_effective_value)
+
+    fn effective_value(&self, raw_value : i32) -> i32 {
+        match self.value_type {
+            ValueType::Boolean => if raw_value > 0 { 1 } else { 0 },
+            ValueType::Numeric => raw_value,
+            ValueType::BoundedNumeric(min, max) => {
+                let mind = if let Some(min) = min { raw_value.max(min) } else { raw_value };
+                let maxd = if let Some(max) = max { mind.min(max) } else { mind };
+                maxd
+            }
         }
     }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize, Copy)]
-pub struct AttributeValue(AttributeType, i32);
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct AttributeValue(pub AttributeType, pub i32);
 impl Default for AttributeValue {
     fn default() -> Self {
-        AttributeValue(attributes::Sentinel, 0)
+        AttributeValue(attributes::Sentinel.clone(), 0)
     }
 }
 
@@ -189,39 +242,63 @@ impl Default for AttributeValue {
 #[derive(Clone, Debug, Fields, Default, Serialize, Deserialize)]
 pub struct AttributeData {
 //    pub(crate) attributes: Vec<AttributeValue>
+    // keyed by attribute id
     pub(crate) attributes: HashMap<String, AttributeValue>
 }
 
-pub fn increase_attribute(world : &mut World, entity : Entity, attr: AttributeType, by : i32) {
-    world.modify(entity, AttributeData::attributes.add_to_key(attr.name.to_string(), AttributeValue(attr, by)));
-//    let attr_data = world.view().data::<AttributeData>(entity);
-//    if let Some(existing) = attr_data.attributes.get(attr.name) {
-//
-//    } else {
-//        world.modify(entity, )
-//    }
+pub fn increase_attribute(world : &mut World, entity : Entity, attr: &AttributeType, by : i32) {
+    let view = world.view();
+    let definition = view.world_data::<AttributeDefinitions>().definition(attr);
+    let new_value = match view.data::<AttributeData>(entity).attributes.get(attr.id()) {
+        Some(AttributeValue(_, existing)) => definition.combine(*existing, by),
+        None => definition.effective_value(by),
+    };
+
+    if new_value == 0 && definition.remove_on_zero {
+        world.modify(entity, AttributeData::attributes.remove_key(attr.id().to_string()));
+    } else {
+        world.modify(entity, AttributeData::attributes.set_key_to(attr.id().to_string(), AttributeValue(attr.clone(), new_value)));
+    }
 }
-pub fn decrease_attribute(world : &mut World, entity : Entity, attr: AttributeType, by : i32) {
-    world.modify(entity, AttributeData::attributes.add_to_key(attr.name.to_string(), AttributeValue(attr, -by)));
+pub fn decrease_attribute(world : &mut World, entity : Entity, attr: &AttributeType, by : i32) {
+    increase_attribute(world, entity, attr, -by);
 }
 
 impl AttributeData {
-    pub fn value_for(&self, for_attr: AttributeType) -> Option<i32> {
-        self.attributes.get(for_attr.name).map(|attr| attr.1)
+    pub fn value_for(&self, for_attr: &AttributeType) -> Option<i32> {
+        self.attributes.get(for_attr.id()).map(|attr| attr.1)
     }
 
+    pub fn increase_attribute(&mut self, definitions : &AttributeDefinitions, attr : &AttributeType, by : i32) {
+        let definition = definitions.definition(attr);
+        let new_value = match self.attributes.get(attr.id()) {
+            Some(AttributeValue(_, existing)) => definition.combine(*existing, by),
+            None => definition.effective_value(by),
+        };
 
-    pub fn increase_attribute(&mut self, attr : AttributeType, by : i32) {
-        let new_value = if let Some(existing) = self.attributes.get(attr.name) {
-            *existing + AttributeValue(attr, by)
+        if new_value == 0 && definition.remove_on_zero {
+            self.attributes.remove(attr.id());
         } else {
-            AttributeValue(attr, by)
-        };
-        self.attributes.insert(attr.name.to_string(), new_value);
+            self.attributes.insert(attr.id().to_string(), AttributeValue(attr.clone(), new_value));
+        }
+    }
+
+    pub fn decrease_attribute(&mut self, definitions : &AttributeDefinitions, attr : &AttributeType, by : i32) {
+        self.increase_attribute(definitions, attr, -by);
     }
 
-    pub fn decrease_attribute(&mut self, attr : AttributeType, by : i32) {
-        self.increase_attribute(attr, -by);
+    /// how much each attribute contributes to one particular kind of effect, paired with the attribute's name so that it
+    /// can be shown in a breakdown. Attributes that contribute nothing are left out
+    pub fn effect_contributions<F : Fn(&AttributeEffect) -> Option<i32>>(&self, definitions : &AttributeDefinitions, effect : F) -> Vec<(String, i32)> {
+        self.attributes.values().filter_map(|AttributeValue(attr, value)| {
+            let definition = definitions.definition(attr);
+            let per_point : i32 = definition.effects.iter().filter_map(|e| effect(e)).sum();
+            if per_point != 0 && *value != 0 {
+                Some((definition.name.clone(), per_point * value))
+            } else {
+                None
+            }
+        }).collect()
     }
 
 //    pub fn value_for(&self, for_attr: &AttributeTypeStruct) -> Option<i32> {
@@ -292,4 +369,37 @@ impl AttributeData {
 //    fn description(&self) -> Transformation {
 //        Transformation::Custom(format!("Added {} to {}", self.value, self.attr.name))
 //    }
-//}
\ No newline at end of file
+//}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    pub fn test_attribute_definitions_load_and_combine() {
+        let loaded = attributes::read_attribute_definitions("../../assets/survival/attributes.ron").expect("attribute definitions should parse");
+        assert!(loaded.len() >= 4);
+        let mut definitions = AttributeDefinitions::default();
+        for definition in loaded {
+            definitions.definitions.insert(definition.id.clone(), definition);
+        }
+
+        let heavy = AttributeType::new("heavy");
+        let mut data = AttributeData::default();
+        data.increase_attribute(&definitions, &heavy, 2);
+        data.increase_attribute(&definitions, &heavy, 5);
+        // heavy is bounded to at most 3
+        assert_eq!(data.value_for(&heavy), Some(3));
+
+        let damage = data.effect_contributions(&definitions, |e| if let AttributeEffect::Damage(d) = e { Some(*d) } else { None });
+        assert_eq!(damage, vec![(strf("heavy"), 6)]);
+
+        data.decrease_attribute(&definitions, &heavy, 3);
+        assert_eq!(data.value_for(&heavy), None);
+
+        // ids survive a round trip and unknown ones are kept rather than silently replaced
+        let serialized = ron::ser::to_string(&AttributeType::new("not yet defined")).expect("could not serialize");
+        let deserialized : AttributeType = ron::de::from_str(&serialized).expect("could not deserialize");
+        assert_eq!(deserialized.id(), "not yet defined");
+    }
+}
@@ -130,4 +130,157 @@ impl Default for AllegianceData {
             faction : Entity::default()
         }
     }
-}
\ No newline at end of file
+}
+
+/// How willing a character is to keep fighting. It drops as the fight turns against them, allies falling nearby and
+/// wounds mounting, and recovers turn by turn given numbers on their side and someone to lead them. Low enough and they
+/// break and run, lower still and they give up entirely
+#[derive(Clone, Debug, Serialize, Deserialize, Fields)]
+pub struct MoraleData {
+    pub morale : Reduceable<i32>,
+    /// how much this character steadies the nerves of allies around them each turn
+    pub leadership : i32,
+    pub state : MoraleState,
+}
+impl EntityData for MoraleData {}
+
+impl Default for MoraleData {
+    fn default() -> Self {
+        MoraleData {
+            morale : Reduceable::new(10),
+            leadership : 0,
+            state : MoraleState::Steady,
+        }
+    }
+}
+
+impl MoraleData {
+    pub fn with_morale(morale : i32) -> MoraleData {
+        MoraleData { morale : Reduceable::new(morale), ..Default::default() }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub enum MoraleState {
+    Steady,
+    /// running from the fight, doing nothing but getting away
+    Fleeing,
+    /// given up, won't act or fight back
+    Surrendered,
+}
+
+impl Default for MoraleState {
+    fn default() -> Self { MoraleState::Steady }
+}
+
+
+/// How fed, watered and warm a character is. Each runs down turn by turn, and a character who goes long enough without
+/// recovers less, then manages less, and eventually starts wasting away
+#[derive(Clone, Debug, Serialize, Deserialize, Fields)]
+pub struct NeedsData {
+    pub satiation : Reduceable<i32>,
+    pub hydration : Reduceable<i32>,
+    pub warmth : Reduceable<i32>,
+}
+impl EntityData for NeedsData {}
+
+impl Default for NeedsData {
+    fn default() -> Self {
+        NeedsData {
+            satiation : Reduceable::new(64),
+            hydration : Reduceable::new(48),
+            warmth : Reduceable::new(32),
+        }
+    }
+}
+
+impl NeedsData {
+    pub fn level(&self, need : Need) -> &Reduceable<i32> {
+        match need {
+            Need::Hunger => &self.satiation,
+            Need::Thirst => &self.hydration,
+            Need::Cold => &self.warmth,
+        }
+    }
+
+    pub fn severity(&self, need : Need) -> NeedSeverity {
+        let level = self.level(need);
+        NeedSeverity::from_fraction(level.cur_value() as f64 / level.max_value().max(1) as f64)
+    }
+
+    /// the worst of the character's needs
+    pub fn worst_severity(&self) -> NeedSeverity {
+        Need::all().iter().map(|n| self.severity(*n)).max().unwrap_or(NeedSeverity::Satisfied)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum Need {
+    Hunger,
+    Thirst,
+    Cold,
+}
+
+impl Need {
+    pub fn all() -> [Need; 3] {
+        [Need::Hunger, Need::Thirst, Need::Cold]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Need::Hunger => "hunger",
+            Need::Thirst => "thirst",
+            Need::Cold => "cold",
+        }
+    }
+}
+
+/// How badly a need has gone unmet, each step bringing harsher penalties than the last
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum NeedSeverity {
+    Satisfied,
+    Uncomfortable,
+    Suffering,
+    Desperate,
+}
+
+impl NeedSeverity {
+    pub fn from_fraction(fraction : f64) -> NeedSeverity {
+        if fraction > 0.5 {
+            NeedSeverity::Satisfied
+        } else if fraction > 0.25 {
+            NeedSeverity::Uncomfortable
+        } else if fraction > 0.0 {
+            NeedSeverity::Suffering
+        } else {
+            NeedSeverity::Desperate
+        }
+    }
+
+    /// amount by which the character's per-turn stamina recovery is reduced
+    pub fn stamina_recovery_penalty(&self) -> Sext {
+        match self {
+            NeedSeverity::Satisfied => Sext::zero(),
+            NeedSeverity::Uncomfortable => Sext::of_parts(0, 2),
+            NeedSeverity::Suffering => Sext::of_parts(0, 4),
+            NeedSeverity::Desperate => Sext::of(1),
+        }
+    }
+
+    /// action points the character starts each turn without
+    pub fn action_point_penalty(&self) -> i32 {
+        match self {
+            NeedSeverity::Satisfied | NeedSeverity::Uncomfortable => 0,
+            NeedSeverity::Suffering => 1,
+            NeedSeverity::Desperate => 3,
+        }
+    }
+
+    /// health lost each turn, only once a need has gone entirely unmet
+    pub fn health_loss(&self) -> i32 {
+        match self {
+            NeedSeverity::Desperate => 2,
+            _ => 0
+        }
+    }
+}
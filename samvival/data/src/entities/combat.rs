@@ -19,6 +19,8 @@ use entities::common_entities::IdentityData;
 #[derive(Clone,Debug,Serialize,Deserialize)]
 pub enum DerivedAttackKind {
     PiercingStrike,
+    PowerAttack,
+    AimedShot,
     None
 }
 
@@ -36,6 +38,22 @@ impl DerivedAttackKind {
                 new_attack.pattern = HexPattern::Line(0, 2);
                 Some(new_attack)
             },
+            DerivedAttackKind::PowerAttack => {
+                let mut new_attack = world.attack(attack).clone();
+                new_attack.name = format!("power {}", new_attack.name);
+                new_attack.ap_cost += 2;
+                new_attack.stamina_cost += 2;
+                new_attack.to_hit_bonus -= 1;
+                new_attack.damage_bonus += 3;
+                Some(new_attack)
+            },
+            DerivedAttackKind::AimedShot => {
+                let mut new_attack = world.attack(attack).clone();
+                new_attack.name = format!("aimed {}", new_attack.name);
+                new_attack.ap_cost += 2;
+                new_attack.to_hit_bonus += 3;
+                Some(new_attack)
+            },
             DerivedAttackKind::None => None
         }
     }
@@ -566,6 +566,29 @@ pub mod taxonomy {
     pub static HeavyShield: Taxon = taxon("heavy shield", &Shield);
     pub static TowerShield: Taxon = taxon("tower shield", &Shield);
 
+    pub static Container: Taxon = taxon("container", &Item);
+    // --------------- containers --------------------
+    pub mod containers {
+        use super::*;
+
+        pub static Bag: Taxon = taxon2("bag", &Container, &DelicateItem);
+        pub static Chest: Taxon = taxon2("chest", &Container, &SturdyItem);
+        pub static Stockpile: Taxon = taxon("stockpile", &Container);
+    }
+
+    pub static Shelter: Taxon = taxon("shelter", &SturdyItem);
+    // --------------- shelters ----------------------
+    pub mod shelters {
+        use super::*;
+
+        pub static LeanTo: Taxon = taxon("lean-to", &Shelter);
+        pub static Hut: Taxon = taxon("hut", &Shelter);
+    }
+
+    pub static Campfire: Taxon = taxon("campfire", &SturdyItem);
+
+    pub static Schematic: Taxon = taxon("schematic", &DelicateItem);
+
 
     pub static LivingThing: Taxon = root_taxon("living thing");
 
@@ -637,12 +660,16 @@ pub mod taxonomy {
         pub static Straw: Taxon = taxon2("straw", &PlantResource, &Material);
         pub static Fruit: Taxon = taxon("fruit", &PlantResource);
         pub static Wood: Taxon = taxon2("wood", &PlantResource, &Material);
+        pub static Reeds: Taxon = taxon2("reeds", &PlantResource, &Material);
+
+        pub static Fish: Taxon = taxon("fish", &Resource);
 
         pub static Stone: Taxon = taxon2("stone", &Mineral, &Material);
         pub static QuarriedStone: Taxon = taxon("quarried stone", &Stone);
         pub static LooseStone: Taxon = taxon("loose stone", &Stone);
 
         pub static Dirt: Taxon = taxon("dirt", &Material);
+        pub static Clay: Taxon = taxon2("clay", &Mineral, &Material);
         pub static Iron: Taxon = taxon2("iron", &Metal, &Material);
     }
 
@@ -661,7 +688,14 @@ pub mod taxonomy {
 
         pub static Plains: Taxon = taxon("plains", &Terrain);
         pub static Hills: Taxon = taxon("hills", &Terrain);
-        pub static Mountains: Taxon = taxon("mountains", &Mountains);
+        pub static Mountains: Taxon = taxon("mountains", &Terrain);
+        pub static Tundra: Taxon = taxon("tundra", &Terrain);
+        pub static Desert: Taxon = taxon("desert", &Terrain);
+        pub static Marsh: Taxon = taxon("marsh", &Terrain);
+
+        pub static Water: Taxon = taxon("water", &Terrain);
+        pub static River: Taxon = taxon("river", &Water);
+        pub static Lake: Taxon = taxon("lake", &Water);
     }
 
     pub static Vegetation: Taxon = root_taxon("vegetation");
@@ -673,6 +707,7 @@ pub mod taxonomy {
         pub static Forest: Taxon = taxon("forest", &Vegetation);
         pub static PineForest: Taxon = taxon("pine forest", &Forest);
         pub static DeciduousForest: Taxon = taxon("deciduous forest", &Forest);
+        pub static Reedbed: Taxon = taxon("reedbed", &Vegetation);
     }
 
     pub static IngredientType: Taxon = root_taxon("ingredient type");
@@ -690,6 +725,18 @@ pub mod taxonomy {
         pub static Binding: Taxon = taxon("binding", &WeaponReinforcementIngredient);
         pub static Blade: Taxon = taxon("blade", &WeaponHeadIngredient);
         pub static Plate: Taxon = taxon("plate", &WeaponReinforcementIngredient);
+
+        pub static RepairTarget: Taxon = taxon("item to repair", &IngredientType);
+        pub static RepairMaterial: Taxon = taxon("repair material", &IngredientType);
+
+        pub static Pickhead: Taxon = taxon("pickhead", &WeaponHeadIngredient);
+        pub static ContainerBody: Taxon = taxon("container body", &IngredientType);
+
+        pub static Frame: Taxon = taxon("frame", &IngredientType);
+        pub static Roofing: Taxon = taxon("roofing", &IngredientType);
+        pub static Walls: Taxon = taxon("walls", &IngredientType);
+        pub static Fuel: Taxon = taxon("fuel", &IngredientType);
+        pub static Kindling: Taxon = taxon("kindling", &IngredientType);
     }
 
 
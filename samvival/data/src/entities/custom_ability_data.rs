@@ -5,22 +5,94 @@ use game::EntityData;
 use game::ModifierReference;
 use common::prelude::*;
 use std::collections::HashSet;
+use entities::time::{TimeOfDay, Season};
 
 #[derive(Clone,Debug,Serialize,Deserialize)]
 pub enum SpawnEntity {
-    Character(String)
+    Character(String),
+    /// one of several character archetypes, picked at random by weight
+    Pool(Vec<WeightedSpawn>),
+}
+
+#[derive(Clone,Debug,Serialize,Deserialize)]
+pub struct WeightedSpawn {
+    pub archetype : String,
+    pub weight : f32,
+    /// how much of a wave's threat budget this creature uses up
+    pub threat : i32,
+}
+
+/// what has to hold for a spawn to go ahead on a turn it would otherwise spawn on
+#[derive(Clone,Debug,Serialize,Deserialize)]
+pub enum SpawnCondition {
+    TimeOfDay(Vec<TimeOfDay>),
+    Season(Vec<Season>),
+    /// a player character is at least this close to the spawner
+    PlayerWithin(f32),
+    /// no player character is this close to the spawner
+    PlayerBeyond(f32),
+    /// fewer than this many creatures the spawner has spawned are still alive
+    MaxAlive(i32),
+}
+
+/// How much threat a spawn may put into a single wave, growing the longer the spawn has been going so that a run
+/// gets harder the longer it's survived
+#[derive(Clone,Copy,Debug,Serialize,Deserialize)]
+pub struct ThreatBudget {
+    pub base : f32,
+    pub per_turn : f32,
+}
+
+impl ThreatBudget {
+    pub fn at_turn(&self, turns_since_start : i32) -> i32 {
+        (self.base + self.per_turn * turns_since_start.max(0) as f32).floor() as i32
+    }
 }
 
 #[derive(Clone,Debug,Serialize,Deserialize)]
 pub struct Spawn {
     pub entity : SpawnEntity,
     pub turns_between_spawns : i32,
-    pub start_spawn_turn : i32
+    pub start_spawn_turn : i32,
+    #[serde(default)]
+    pub conditions : Vec<SpawnCondition>,
+    /// spawns without a budget bring a single creature each wave
+    #[serde(default)]
+    pub threat_budget : Option<ThreatBudget>,
+    /// how far from the spawner creatures may appear
+    #[serde(default = "default_spawn_radius")]
+    pub spawn_radius : i32,
+}
+
+fn default_spawn_radius() -> i32 { 1 }
+
+impl Spawn {
+    pub fn new(entity : SpawnEntity, start_spawn_turn : i32, turns_between_spawns : i32) -> Spawn {
+        Spawn { entity, turns_between_spawns, start_spawn_turn, conditions : Vec::new(), threat_budget : None, spawn_radius : default_spawn_radius() }
+    }
+
+    pub fn with_condition(mut self, condition : SpawnCondition) -> Self {
+        self.conditions.push(condition);
+        self
+    }
+
+    pub fn with_threat_budget(mut self, base : f32, per_turn : f32) -> Self {
+        self.threat_budget = Some(ThreatBudget { base, per_turn });
+        self
+    }
+
+    pub fn with_spawn_radius(mut self, radius : i32) -> Self {
+        self.spawn_radius = radius;
+        self
+    }
 }
 
 #[derive(Default,Clone,Debug,Serialize, Deserialize, Fields)]
 pub struct MonsterSpawnerData {
-    pub spawns : Vec<Spawn>
+    pub spawns : Vec<Spawn>,
+    /// everything this spawner has brought into the world, living or not
+    #[serde(default)]
+    pub spawned : Vec<Entity>,
 }
 
 impl EntityData for MonsterSpawnerData {}
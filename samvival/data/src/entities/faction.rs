@@ -6,6 +6,7 @@ use game::core::*;
 use common::Color;
 use common::reflect::*;
 use game::entity;
+use std::collections::HashMap;
 
 #[derive(Clone, Default, Debug, Serialize, Deserialize, Fields)]
 pub struct FactionData {
@@ -16,6 +17,45 @@ pub struct FactionData {
 
 impl EntityData for FactionData {}
 
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Relationship {
+    Hostile,
+    /// will leave the other alone, unless attacked
+    Neutral,
+    Allied,
+}
+
+/// How every faction stands with every other. Relationships go both ways, so each pair is only stored once, lowest
+/// entity first. Any pair without an explicit relationship falls back to the stance either faction takes towards
+/// strangers, and failing that, they're hostile
+#[derive(Clone, Default, Debug, Serialize, Deserialize, Fields)]
+pub struct RelationshipData {
+    pub relationships : HashMap<(Entity, Entity), Relationship>,
+    pub default_relationships : HashMap<Entity, Relationship>,
+}
+impl EntityData for RelationshipData {}
+
+impl RelationshipData {
+    pub fn key(a : Entity, b : Entity) -> (Entity, Entity) {
+        if a <= b { (a, b) } else { (b, a) }
+    }
+
+    pub fn relationship(&self, a : Entity, b : Entity) -> Relationship {
+        if a == b {
+            Relationship::Allied
+        } else if let Some(relationship) = self.relationships.get(&RelationshipData::key(a, b)) {
+            *relationship
+        } else {
+            // it takes both sides to keep the peace, so the less friendly of the two stances wins out
+            match (self.default_relationships.get(&a), self.default_relationships.get(&b)) {
+                (Some(ra), Some(rb)) => *ra.min(rb),
+                (Some(r), None) | (None, Some(r)) => *r,
+                (None, None) => Relationship::Hostile
+            }
+        }
+    }
+}
+
 pub trait FactionStore {
     fn faction(&self, entity : Entity) -> &FactionData;
 }
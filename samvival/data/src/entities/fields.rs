@@ -18,27 +18,27 @@ impl TileData { pub const name_modifiers : Field < TileData , Vec < String > > =
 impl TerrainData { pub const fertility : Field < TerrainData , i8 > = Field :: new ( stringify ! ( fertility ) , | t | & t . fertility , | t | & mut t . fertility , | t , v | { t . fertility = v ; } ) ; pub const cover : Field < TerrainData , i8 > = Field :: new ( stringify ! ( cover ) , | t | & t . cover , | t | & mut t . cover , | t , v | { t . cover = v ; } ) ; pub const elevation : Field < TerrainData , i8 > = Field :: new ( stringify ! ( elevation ) , | t | & t . elevation , | t | & mut t . elevation , | t , v | { t . elevation = v ; } ) ; pub const position : Field < TerrainData , AxialCoord > = Field :: new ( stringify ! ( position ) , | t | & t . position , | t | & mut t . position , | t , v | { t . position = v ; } ) ; pub const move_cost : Field < TerrainData , Sext > = Field :: new ( stringify ! ( move_cost ) , | t | & t . move_cost , | t | & mut t . move_cost , | t , v | { t . move_cost = v ; } ) ; pub const occupied_by : Field < TerrainData , Option < Entity > > = Field :: new ( stringify ! ( occupied_by ) , | t | & t . occupied_by , | t | & mut t . occupied_by , | t , v | { t . occupied_by = v ; } ) ; pub const harvestables : Field < TerrainData , HashMap < String , Entity > > = Field :: new ( stringify ! ( harvestables ) , | t | & t . harvestables , | t | & mut t . harvestables , | t , v | { t . harvestables = v ; } ) ; pub const kind : Field < TerrainData , Taxon > = Field :: new ( stringify ! ( kind ) , | t | & t . kind , | t | & mut t . kind , | t , v | { t . kind = v ; } ) ; }
 impl VegetationData { pub const cover : Field < VegetationData , i8 > = Field :: new ( stringify ! ( cover ) , | t | & t . cover , | t | & mut t . cover , | t , v | { t . cover = v ; } ) ; pub const move_cost : Field < VegetationData , Sext > = Field :: new ( stringify ! ( move_cost ) , | t | & t . move_cost , | t | & mut t . move_cost , | t , v | { t . move_cost = v ; } ) ; pub const harvestables : Field < VegetationData , HashMap < String , Entity > > = Field :: new ( stringify ! ( harvestables ) , | t | & t . harvestables , | t | & mut t . harvestables , | t , v | { t . harvestables = v ; } ) ; pub const kind : Field < VegetationData , Taxon > = Field :: new ( stringify ! ( kind ) , | t | & t . kind , | t | & mut t . kind , | t , v | { t . kind = v ; } ) ; }
 impl RenewRate { pub const fertility_dependent : Field < RenewRate , bool > = Field :: new ( stringify ! ( fertility_dependent ) , | t | & t . fertility_dependent , | t | & mut t . fertility_dependent , | t , v | { t . fertility_dependent = v ; } ) ; pub const season_multipliers : Field < RenewRate , HashMap < Season , f32 > > = Field :: new ( stringify ! ( season_multipliers ) , | t | & t . season_multipliers , | t | & mut t . season_multipliers , | t , v | { t . season_multipliers = v ; } ) ; pub const rate : Field < RenewRate , Sext > = Field :: new ( stringify ! ( rate ) , | t | & t . rate , | t | & mut t . rate , | t , v | { t . rate = v ; } ) ; }
-impl Harvestable { pub const ap_per_harvest : Field < Harvestable , i32 > = Field :: new ( stringify ! ( ap_per_harvest ) , | t | & t . ap_per_harvest , | t | & mut t . ap_per_harvest , | t , v | { t . ap_per_harvest = v ; } ) ; pub const name_modifiers : Field < Harvestable , Vec < String > > = Field :: new ( stringify ! ( name_modifiers ) , | t | & t . name_modifiers , | t | & mut t . name_modifiers , | t , v | { t . name_modifiers = v ; } ) ; pub const amount : Field < Harvestable , Reduceable < Sext > > = Field :: new ( stringify ! ( amount ) , | t | & t . amount , | t | & mut t . amount , | t , v | { t . amount = v ; } ) ; pub const dice_amount_per_harvest : Field < Harvestable , DicePool > = Field :: new ( stringify ! ( dice_amount_per_harvest ) , | t | & t . dice_amount_per_harvest , | t | & mut t . dice_amount_per_harvest , | t , v | { t . dice_amount_per_harvest = v ; } ) ; pub const fixed_amount_per_harvest : Field < Harvestable , i32 > = Field :: new ( stringify ! ( fixed_amount_per_harvest ) , | t | & t . fixed_amount_per_harvest , | t | & mut t . fixed_amount_per_harvest , | t , v | { t . fixed_amount_per_harvest = v ; } ) ; pub const renew_rate : Field < Harvestable , Option < RenewRate > > = Field :: new ( stringify ! ( renew_rate ) , | t | & t . renew_rate , | t | & mut t . renew_rate , | t , v | { t . renew_rate = v ; } ) ; pub const resource : Field < Harvestable , Entity > = Field :: new ( stringify ! ( resource ) , | t | & t . resource , | t | & mut t . resource , | t , v | { t . resource = v ; } ) ; pub const on_depletion : Field < Harvestable , DepletionBehavior > = Field :: new ( stringify ! ( on_depletion ) , | t | & t . on_depletion , | t | & mut t . on_depletion , | t , v | { t . on_depletion = v ; } ) ; pub const action_name : Field < Harvestable , String > = Field :: new ( stringify ! ( action_name ) , | t | & t . action_name , | t | & mut t . action_name , | t , v | { t . action_name = v ; } ) ; pub const tool : Field < Harvestable , EntitySelector > = Field :: new ( stringify ! ( tool ) , | t | & t . tool , | t | & mut t . tool , | t , v | { t . tool = v ; } ) ; pub const tool_use : Field < Harvestable , ToolUse > = Field :: new ( stringify ! ( tool_use ) , | t | & t . tool_use , | t | & mut t . tool_use , | t , v | { t . tool_use = v ; } ) ; pub const skills_used : Field < Harvestable , Vec < Skill > > = Field :: new ( stringify ! ( skills_used ) , | t | & t . skills_used , | t | & mut t . skills_used , | t , v | { t . skills_used = v ; } ) ; pub const character_requirements : Field < Harvestable , EntitySelector > = Field :: new ( stringify ! ( character_requirements ) , | t | & t . character_requirements , | t | & mut t . character_requirements , | t , v | { t . character_requirements = v ; } ) ; }
+impl Harvestable { pub const ap_per_harvest : Field < Harvestable , i32 > = Field :: new ( stringify ! ( ap_per_harvest ) , | t | & t . ap_per_harvest , | t | & mut t . ap_per_harvest , | t , v | { t . ap_per_harvest = v ; } ) ; pub const name_modifiers : Field < Harvestable , Vec < String > > = Field :: new ( stringify ! ( name_modifiers ) , | t | & t . name_modifiers , | t | & mut t . name_modifiers , | t , v | { t . name_modifiers = v ; } ) ; pub const amount : Field < Harvestable , Reduceable < Sext > > = Field :: new ( stringify ! ( amount ) , | t | & t . amount , | t | & mut t . amount , | t , v | { t . amount = v ; } ) ; pub const dice_amount_per_harvest : Field < Harvestable , DicePool > = Field :: new ( stringify ! ( dice_amount_per_harvest ) , | t | & t . dice_amount_per_harvest , | t | & mut t . dice_amount_per_harvest , | t , v | { t . dice_amount_per_harvest = v ; } ) ; pub const fixed_amount_per_harvest : Field < Harvestable , i32 > = Field :: new ( stringify ! ( fixed_amount_per_harvest ) , | t | & t . fixed_amount_per_harvest , | t | & mut t . fixed_amount_per_harvest , | t , v | { t . fixed_amount_per_harvest = v ; } ) ; pub const renew_rate : Field < Harvestable , Option < RenewRate > > = Field :: new ( stringify ! ( renew_rate ) , | t | & t . renew_rate , | t | & mut t . renew_rate , | t , v | { t . renew_rate = v ; } ) ; pub const resource : Field < Harvestable , Entity > = Field :: new ( stringify ! ( resource ) , | t | & t . resource , | t | & mut t . resource , | t , v | { t . resource = v ; } ) ; pub const on_depletion : Field < Harvestable , DepletionBehavior > = Field :: new ( stringify ! ( on_depletion ) , | t | & t . on_depletion , | t | & mut t . on_depletion , | t , v | { t . on_depletion = v ; } ) ; pub const action_name : Field < Harvestable , String > = Field :: new ( stringify ! ( action_name ) , | t | & t . action_name , | t | & mut t . action_name , | t , v | { t . action_name = v ; } ) ; pub const tool : Field < Harvestable , EntitySelector > = Field :: new ( stringify ! ( tool ) , | t | & t . tool , | t | & mut t . tool , | t , v | { t . tool = v ; } ) ; pub const tool_use : Field < Harvestable , ToolUse > = Field :: new ( stringify ! ( tool_use ) , | t | & t . tool_use , | t | & mut t . tool_use , | t , v | { t . tool_use = v ; } ) ; pub const skills_used : Field < Harvestable , Vec < Skill > > = Field :: new ( stringify ! ( skills_used ) , | t | & t . skills_used , | t | & mut t . skills_used , | t , v | { t . skills_used = v ; } ) ; pub const character_requirements : Field < Harvestable , EntitySelector > = Field :: new ( stringify ! ( character_requirements ) , | t | & t . character_requirements , | t | & mut t . character_requirements , | t , v | { t . character_requirements = v ; } ) ; pub const depletion : Field < Harvestable , Option < Depletion > > = Field :: new ( stringify ! ( depletion ) , | t | & t . depletion , | t | & mut t . depletion , | t , v | { t . depletion = v ; } ) ; }
 impl Resources { pub const main : Field < Resources , ConstantResources > = Field :: new ( stringify ! ( main ) , | t | & t . main , | t | & mut t . main , | t , v | { t . main = v ; } ) ; pub const custom_resources : Field < Resources , HashMap < String , Entity > > = Field :: new ( stringify ! ( custom_resources ) , | t | & t . custom_resources , | t | & mut t . custom_resources , | t , v | { t . custom_resources = v ; } ) ; }
-impl FoodInfo { pub const satiation : Field < FoodInfo , i32 > = Field :: new ( stringify ! ( satiation ) , | t | & t . satiation , | t | & mut t . satiation , | t , v | { t . satiation = v ; } ) ; }
+impl FoodInfo { pub const satiation : Field < FoodInfo , i32 > = Field :: new ( stringify ! ( satiation ) , | t | & t . satiation , | t | & mut t . satiation , | t , v | { t . satiation = v ; } ) ; pub const hydration : Field < FoodInfo , i32 > = Field :: new ( stringify ! ( hydration ) , | t | & t . hydration , | t | & mut t . hydration , | t , v | { t . hydration = v ; } ) ; }
 impl Material { pub const edge : Field < Material , i32 > = Field :: new ( stringify ! ( edge ) , | t | & t . edge , | t | & mut t . edge , | t , v | { t . edge = v ; } ) ; pub const point : Field < Material , i32 > = Field :: new ( stringify ! ( point ) , | t | & t . point , | t | & mut t . point , | t , v | { t . point = v ; } ) ; pub const hardness : Field < Material , i32 > = Field :: new ( stringify ! ( hardness ) , | t | & t . hardness , | t | & mut t . hardness , | t , v | { t . hardness = v ; } ) ; pub const flammable : Field < Material , bool > = Field :: new ( stringify ! ( flammable ) , | t | & t . flammable , | t | & mut t . flammable , | t , v | { t . flammable = v ; } ) ; pub const density : Field < Material , i32 > = Field :: new ( stringify ! ( density ) , | t | & t . density , | t | & mut t . density , | t , v | { t . density = v ; } ) ; pub const strength : Field < Material , i32 > = Field :: new ( stringify ! ( strength ) , | t | & t . strength , | t | & mut t . strength , | t , v | { t . strength = v ; } ) ; pub const ductile : Field < Material , bool > = Field :: new ( stringify ! ( ductile ) , | t | & t . ductile , | t | & mut t . ductile , | t , v | { t . ductile = v ; } ) ; pub const cordable : Field < Material , bool > = Field :: new ( stringify ! ( cordable ) , | t | & t . cordable , | t | & mut t . cordable , | t , v | { t . cordable = v ; } ) ; pub const magnetic : Field < Material , bool > = Field :: new ( stringify ! ( magnetic ) , | t | & t . magnetic , | t | & mut t . magnetic , | t , v | { t . magnetic = v ; } ) ; pub const item_quality : Field < Material , i32 > = Field :: new ( stringify ! ( item_quality ) , | t | & t . item_quality , | t | & mut t . item_quality , | t , v | { t . item_quality = v ; } ) ; pub const building_quality : Field < Material , i32 > = Field :: new ( stringify ! ( building_quality ) , | t | & t . building_quality , | t | & mut t . building_quality , | t , v | { t . building_quality = v ; } ) ; pub const material_effects : Field < Material , Vec < MaterialEffect > > = Field :: new ( stringify ! ( material_effects ) , | t | & t . material_effects , | t | & mut t . material_effects , | t , v | { t . material_effects = v ; } ) ; }
 impl IngredientData { pub const effects_by_kinds : Field < IngredientData , HashMap < Taxon , EffectReference > > = Field :: new ( stringify ! ( effects_by_kinds ) , | t | & t . effects_by_kinds , | t | & mut t . effects_by_kinds , | t , v | { t . effects_by_kinds = v ; } ) ; }
 impl TurnData { pub const turn_number : Field < TurnData , u32 > = Field :: new ( stringify ! ( turn_number ) , | t | & t . turn_number , | t | & mut t . turn_number , | t , v | { t . turn_number = v ; } ) ; pub const active_faction : Field < TurnData , Entity > = Field :: new ( stringify ! ( active_faction ) , | t | & t . active_faction , | t | & mut t . active_faction , | t , v | { t . active_faction = v ; } ) ; }
-impl TimeData { pub const moments_since_world_start : Field < TimeData , u32 > = Field :: new ( stringify ! ( moments_since_world_start ) , | t | & t . moments_since_world_start , | t | & mut t . moments_since_world_start , | t , v | { t . moments_since_world_start = v ; } ) ; pub const moments_per_time_of_day : Field < TimeData , HashMap < TimeOfDay , u32 > > = Field :: new ( stringify ! ( moments_per_time_of_day ) , | t | & t . moments_per_time_of_day , | t | & mut t . moments_per_time_of_day , | t , v | { t . moments_per_time_of_day = v ; } ) ; pub const days_per_season : Field < TimeData , HashMap < Season , u32 > > = Field :: new ( stringify ! ( days_per_season ) , | t | & t . days_per_season , | t | & mut t . days_per_season , | t , v | { t . days_per_season = v ; } ) ; pub const moments_since_day_start : Field < TimeData , u32 > = Field :: new ( stringify ! ( moments_since_day_start ) , | t | & t . moments_since_day_start , | t | & mut t . moments_since_day_start , | t , v | { t . moments_since_day_start = v ; } ) ; }
+impl TimeData { pub const moments_since_world_start : Field < TimeData , u32 > = Field :: new ( stringify ! ( moments_since_world_start ) , | t | & t . moments_since_world_start , | t | & mut t . moments_since_world_start , | t , v | { t . moments_since_world_start = v ; } ) ; pub const moments_per_time_of_day : Field < TimeData , HashMap < TimeOfDay , u32 > > = Field :: new ( stringify ! ( moments_per_time_of_day ) , | t | & t . moments_per_time_of_day , | t | & mut t . moments_per_time_of_day , | t , v | { t . moments_per_time_of_day = v ; } ) ; pub const days_per_season : Field < TimeData , HashMap < Season , u32 > > = Field :: new ( stringify ! ( days_per_season ) , | t | & t . days_per_season , | t | & mut t . days_per_season , | t , v | { t . days_per_season = v ; } ) ; pub const moments_since_day_start : Field < TimeData , u32 > = Field :: new ( stringify ! ( moments_since_day_start ) , | t | & t . moments_since_day_start , | t | & mut t . moments_since_day_start , | t , v | { t . moments_since_day_start = v ; } ) ; pub const days_since_world_start : Field < TimeData , u32 > = Field :: new ( stringify ! ( days_since_world_start ) , | t | & t . days_since_world_start , | t | & mut t . days_since_world_start , | t , v | { t . days_since_world_start = v ; } ) ; }
 impl PositionData { pub const hex : Field < PositionData , AxialCoord > = Field :: new ( stringify ! ( hex ) , | t | & t . hex , | t | & mut t . hex , | t , v | { t . hex = v ; } ) ; }
 impl IdentityData { pub const name : Field < IdentityData , Option < String > > = Field :: new ( stringify ! ( name ) , | t | & t . name , | t | & mut t . name , | t , v | { t . name = v ; } ) ; pub const kinds : Field < IdentityData , Vec < Taxon > > = Field :: new ( stringify ! ( kinds ) , | t | & t . kinds , | t | & mut t . kinds , | t , v | { t . kinds = v ; } ) ; }
 impl RuntimeTaxonData { pub const runtime_taxons : Field < RuntimeTaxonData , Vec < Taxon > > = Field :: new ( stringify ! ( runtime_taxons ) , | t | & t . runtime_taxons , | t | & mut t . runtime_taxons , | t , v | { t . runtime_taxons = v ; } ) ; pub const runtime_parents : Field < RuntimeTaxonData , Vec < Vec < Taxon > > > = Field :: new ( stringify ! ( runtime_parents ) , | t | & t . runtime_parents , | t | & mut t . runtime_parents , | t , v | { t . runtime_parents = v ; } ) ; }
 impl ModifierTrackingData { pub const modifiers_by_key : Field < ModifierTrackingData , HashMap < String , ModifierReference > > = Field :: new ( stringify ! ( modifiers_by_key ) , | t | & t . modifiers_by_key , | t | & mut t . modifiers_by_key , | t , v | { t . modifiers_by_key = v ; } ) ; }
-impl ActionData { pub const active_action : Field < ActionData , Option < Action > > = Field :: new ( stringify ! ( active_action ) , | t | & t . active_action , | t | & mut t . active_action , | t , v | { t . active_action = v ; } ) ; pub const active_reaction : Field < ActionData , ReactionTypeRef > = Field :: new ( stringify ! ( active_reaction ) , | t | & t . active_reaction , | t | & mut t . active_reaction , | t , v | { t . active_reaction = v ; } ) ; }
+impl ActionData { pub const active_action : Field < ActionData , Option < Action > > = Field :: new ( stringify ! ( active_action ) , | t | & t . active_action , | t | & mut t . active_action , | t , v | { t . active_action = v ; } ) ; pub const active_reaction : Field < ActionData , ReactionTypeRef > = Field :: new ( stringify ! ( active_reaction ) , | t | & t . active_reaction , | t | & mut t . active_reaction , | t , v | { t . active_reaction = v ; } ) ; pub const interrupted_action : Field < ActionData , Option < Action > > = Field :: new ( stringify ! ( interrupted_action ) , | t | & t . interrupted_action , | t | & mut t . interrupted_action , | t , v | { t . interrupted_action = v ; } ) ; }
 impl AttributeData { pub const attributes : Field < AttributeData , HashMap < String , AttributeValue > > = Field :: new ( stringify ! ( attributes ) , | t | & t . attributes , | t | & mut t . attributes , | t , v | { t . attributes = v ; } ) ; }
-impl MonsterSpawnerData { pub const spawns : Field < MonsterSpawnerData , Vec < Spawn > > = Field :: new ( stringify ! ( spawns ) , | t | & t . spawns , | t | & mut t . spawns , | t , v | { t . spawns = v ; } ) ; }
+impl MonsterSpawnerData { pub const spawns : Field < MonsterSpawnerData , Vec < Spawn > > = Field :: new ( stringify ! ( spawns ) , | t | & t . spawns , | t | & mut t . spawns , | t , v | { t . spawns = v ; } ) ; pub const spawned : Field < MonsterSpawnerData , Vec < Entity > > = Field :: new ( stringify ! ( spawned ) , | t | & t . spawned , | t | & mut t . spawned , | t , v | { t . spawned = v ; } ) ; }
 impl VisibilityData { pub const visibility_by_faction : Field < VisibilityData , HashMap < Entity , Visibility > > = Field :: new ( stringify ! ( visibility_by_faction ) , | t | & t . visibility_by_faction , | t | & mut t . visibility_by_faction , | t , v | { t . visibility_by_faction = v ; } ) ; }
 impl MovementType { pub const name : Field < MovementType , String > = Field :: new ( stringify ! ( name ) , | t | & t . name , | t | & mut t . name , | t , v | { t . name = v ; } ) ; pub const move_multiplier : Field < MovementType , Sext > = Field :: new ( stringify ! ( move_multiplier ) , | t | & t . move_multiplier , | t | & mut t . move_multiplier , | t , v | { t . move_multiplier = v ; } ) ; pub const move_bonus : Field < MovementType , Sext > = Field :: new ( stringify ! ( move_bonus ) , | t | & t . move_bonus , | t | & mut t . move_bonus , | t , v | { t . move_bonus = v ; } ) ; pub const ap_activation_cost : Field < MovementType , i32 > = Field :: new ( stringify ! ( ap_activation_cost ) , | t | & t . ap_activation_cost , | t | & mut t . ap_activation_cost , | t , v | { t . ap_activation_cost = v ; } ) ; pub const stamina_cost : Field < MovementType , Sext > = Field :: new ( stringify ! ( stamina_cost ) , | t | & t . stamina_cost , | t | & mut t . stamina_cost , | t , v | { t . stamina_cost = v ; } ) ; }
 impl MovementData { pub const active_movement_type : Field < MovementData , Option < MovementTypeRef > > = Field :: new ( stringify ! ( active_movement_type ) , | t | & t . active_movement_type , | t | & mut t . active_movement_type , | t , v | { t . active_movement_type = v ; } ) ; pub const move_speed : Field < MovementData , Sext > = Field :: new ( stringify ! ( move_speed ) , | t | & t . move_speed , | t | & mut t . move_speed , | t , v | { t . move_speed = v ; } ) ; pub const moves : Field < MovementData , Sext > = Field :: new ( stringify ! ( moves ) , | t | & t . moves , | t | & mut t . moves , | t , v | { t . moves = v ; } ) ; pub const movement_types : Field < MovementData , Vec < Entity > > = Field :: new ( stringify ! ( movement_types ) , | t | & t . movement_types , | t | & mut t . movement_types , | t , v | { t . movement_types = v ; } ) ; }
 impl Effects { pub const effects : Field < Effects , Vec < Effect > > = Field :: new ( stringify ! ( effects ) , | t | & t . effects , | t | & mut t . effects , | t , v | { t . effects = v ; } ) ; pub const named_effects : Field < Effects , HashMap < String , usize > > = Field :: new ( stringify ! ( named_effects ) , | t | & t . named_effects , | t | & mut t . named_effects , | t , v | { t . named_effects = v ; } ) ; pub const applied_effects : Field < Effects , HashMap < ( Entity , EffectReference ) , EffectApplication > > = Field :: new ( stringify ! ( applied_effects ) , | t | & t . applied_effects , | t | & mut t . applied_effects , | t , v | { t . applied_effects = v ; } ) ; }
-impl Recipe { pub const parent_recipe : Field < Recipe , Option < Entity > > = Field :: new ( stringify ! ( parent_recipe ) , | t | & t . parent_recipe , | t | & mut t . parent_recipe , | t , v | { t . parent_recipe = v ; } ) ; pub const ingredients_by_kind : Field < Recipe , HashMap < Taxon , Ingredient > > = Field :: new ( stringify ! ( ingredients_by_kind ) , | t | & t . ingredients_by_kind , | t | & mut t . ingredients_by_kind , | t , v | { t . ingredients_by_kind = v ; } ) ; pub const name_from_ingredient : Field < Recipe , Option < Taxon > > = Field :: new ( stringify ! ( name_from_ingredient ) , | t | & t . name_from_ingredient , | t | & mut t . name_from_ingredient , | t , v | { t . name_from_ingredient = v ; } ) ; pub const result : Field < Recipe , EntityArchetype > = Field :: new ( stringify ! ( result ) , | t | & t . result , | t | & mut t . result , | t , v | { t . result = v ; } ) ; pub const tools_used : Field < Recipe , Vec < ( EntitySelector , RecipeToolUse ) > > = Field :: new ( stringify ! ( tools_used ) , | t | & t . tools_used , | t | & mut t . tools_used , | t , v | { t . tools_used = v ; } ) ; pub const skills_used : Field < Recipe , Vec < SkillUse > > = Field :: new ( stringify ! ( skills_used ) , | t | & t . skills_used , | t | & mut t . skills_used , | t , v | { t . skills_used = v ; } ) ; }
+impl Recipe { pub const parent_recipe : Field < Recipe , Option < Entity > > = Field :: new ( stringify ! ( parent_recipe ) , | t | & t . parent_recipe , | t | & mut t . parent_recipe , | t , v | { t . parent_recipe = v ; } ) ; pub const ingredients_by_kind : Field < Recipe , HashMap < Taxon , Ingredient > > = Field :: new ( stringify ! ( ingredients_by_kind ) , | t | & t . ingredients_by_kind , | t | & mut t . ingredients_by_kind , | t , v | { t . ingredients_by_kind = v ; } ) ; pub const name_from_ingredient : Field < Recipe , Option < Taxon > > = Field :: new ( stringify ! ( name_from_ingredient ) , | t | & t . name_from_ingredient , | t | & mut t . name_from_ingredient , | t , v | { t . name_from_ingredient = v ; } ) ; pub const result : Field < Recipe , EntityArchetype > = Field :: new ( stringify ! ( result ) , | t | & t . result , | t | & mut t . result , | t , v | { t . result = v ; } ) ; pub const tools_used : Field < Recipe , Vec < ( EntitySelector , RecipeToolUse ) > > = Field :: new ( stringify ! ( tools_used ) , | t | & t . tools_used , | t | & mut t . tools_used , | t , v | { t . tools_used = v ; } ) ; pub const skills_used : Field < Recipe , Vec < SkillUse > > = Field :: new ( stringify ! ( skills_used ) , | t | & t . skills_used , | t | & mut t . skills_used , | t , v | { t . skills_used = v ; } ) ; pub const flavor : Field < Recipe , RecipeFlavor > = Field :: new ( stringify ! ( flavor ) , | t | & t . flavor , | t | & mut t . flavor , | t , v | { t . flavor = v ; } ) ; pub const discovered_by : Field < Recipe , Vec < RecipeDiscovery > > = Field :: new ( stringify ! ( discovered_by ) , | t | & t . discovered_by , | t | & mut t . discovered_by , | t , v | { t . discovered_by = v ; } ) ; }
 impl EntityMetadata { pub const archetype : Field < EntityMetadata , Entity > = Field :: new ( stringify ! ( archetype ) , | t | & t . archetype , | t | & mut t . archetype , | t , v | { t . archetype = v ; } ) ; }
-impl ItemArchetype { pub const attacks : Field < ItemArchetype , Vec < ( IdentityData , Attack ) > > = Field :: new ( stringify ! ( attacks ) , | t | & t . attacks , | t | & mut t . attacks , | t , v | { t . attacks = v ; } ) ; pub const stack_limit : Field < ItemArchetype , i32 > = Field :: new ( stringify ! ( stack_limit ) , | t | & t . stack_limit , | t | & mut t . stack_limit , | t , v | { t . stack_limit = v ; } ) ; pub const stack_with : Field < ItemArchetype , StackWith > = Field :: new ( stringify ! ( stack_with ) , | t | & t . stack_with , | t | & mut t . stack_with , | t , v | { t . stack_with = v ; } ) ; pub const worth : Field < ItemArchetype , Worth > = Field :: new ( stringify ! ( worth ) , | t | & t . worth , | t | & mut t . worth , | t , v | { t . worth = v ; } ) ; pub const tool_data : Field < ItemArchetype , Option < ToolData > > = Field :: new ( stringify ! ( tool_data ) , | t | & t . tool_data , | t | & mut t . tool_data , | t , v | { t . tool_data = v ; } ) ; pub const attributes : Field < ItemArchetype , AttributeData > = Field :: new ( stringify ! ( attributes ) , | t | & t . attributes , | t | & mut t . attributes , | t , v | { t . attributes = v ; } ) ; }
+impl ItemArchetype { pub const attacks : Field < ItemArchetype , Vec < ( IdentityData , Attack ) > > = Field :: new ( stringify ! ( attacks ) , | t | & t . attacks , | t | & mut t . attacks , | t , v | { t . attacks = v ; } ) ; pub const stack_limit : Field < ItemArchetype , i32 > = Field :: new ( stringify ! ( stack_limit ) , | t | & t . stack_limit , | t | & mut t . stack_limit , | t , v | { t . stack_limit = v ; } ) ; pub const stack_with : Field < ItemArchetype , StackWith > = Field :: new ( stringify ! ( stack_with ) , | t | & t . stack_with , | t | & mut t . stack_with , | t , v | { t . stack_with = v ; } ) ; pub const worth : Field < ItemArchetype , Worth > = Field :: new ( stringify ! ( worth ) , | t | & t . worth , | t | & mut t . worth , | t , v | { t . worth = v ; } ) ; pub const tool_data : Field < ItemArchetype , Option < ToolData > > = Field :: new ( stringify ! ( tool_data ) , | t | & t . tool_data , | t | & mut t . tool_data , | t , v | { t . tool_data = v ; } ) ; pub const attributes : Field < ItemArchetype , AttributeData > = Field :: new ( stringify ! ( attributes ) , | t | & t . attributes , | t | & mut t . attributes , | t , v | { t . attributes = v ; } ) ; pub const durability : Field < ItemArchetype , Option < i32 > > = Field :: new ( stringify ! ( durability ) , | t | & t . durability , | t | & mut t . durability , | t , v | { t . durability = v ; } ) ; pub const weight : Field < ItemArchetype , i32 > = Field :: new ( stringify ! ( weight ) , | t | & t . weight , | t | & mut t . weight , | t , v | { t . weight = v ; } ) ; pub const container : Field < ItemArchetype , Option < ContainerData > > = Field :: new ( stringify ! ( container ) , | t | & t . container , | t | & mut t . container , | t , v | { t . container = v ; } ) ; pub const structure : Field < ItemArchetype , Option < StructureData > > = Field :: new ( stringify ! ( structure ) , | t | & t . structure , | t | & mut t . structure , | t , v | { t . structure = v ; } ) ; }
 
 
 use *;
@@ -54,4 +54,24 @@ use entities::*;
 use archetype::EntityArchetype;
 use game::ModifierReference;
 use std::collections::HashSet;
-use std::collections::HashMap;
\ No newline at end of file
+use std::collections::HashMap;
+impl WeatherData { pub const weather : Field < WeatherData , Weather > = Field :: new ( stringify ! ( weather ) , | t | & t . weather , | t | & mut t . weather , | t , v | { t . weather = v ; } ) ; pub const days_of_current_weather : Field < WeatherData , u32 > = Field :: new ( stringify ! ( days_of_current_weather ) , | t | & t . days_of_current_weather , | t | & mut t . days_of_current_weather , | t , v | { t . days_of_current_weather = v ; } ) ; }
+impl TerrainGenConfig { pub const seed : Field < TerrainGenConfig , u32 > = Field :: new ( stringify ! ( seed ) , | t | & t . seed , | t | & mut t . seed , | t , v | { t . seed = v ; } ) ; pub const shape : Field < TerrainGenConfig , MapShape > = Field :: new ( stringify ! ( shape ) , | t | & t . shape , | t | & mut t . shape , | t , v | { t . shape = v ; } ) ; pub const height_scale : Field < TerrainGenConfig , f64 > = Field :: new ( stringify ! ( height_scale ) , | t | & t . height_scale , | t | & mut t . height_scale , | t , v | { t . height_scale = v ; } ) ; pub const height_octaves : Field < TerrainGenConfig , usize > = Field :: new ( stringify ! ( height_octaves ) , | t | & t . height_octaves , | t | & mut t . height_octaves , | t , v | { t . height_octaves = v ; } ) ; pub const height_frequency : Field < TerrainGenConfig , f64 > = Field :: new ( stringify ! ( height_frequency ) , | t | & t . height_frequency , | t | & mut t . height_frequency , | t , v | { t . height_frequency = v ; } ) ; pub const height_persistence : Field < TerrainGenConfig , f64 > = Field :: new ( stringify ! ( height_persistence ) , | t | & t . height_persistence , | t | & mut t . height_persistence , | t , v | { t . height_persistence = v ; } ) ; pub const hill_threshold : Field < TerrainGenConfig , f64 > = Field :: new ( stringify ! ( hill_threshold ) , | t | & t . hill_threshold , | t | & mut t . hill_threshold , | t , v | { t . hill_threshold = v ; } ) ; pub const mountain_threshold : Field < TerrainGenConfig , f64 > = Field :: new ( stringify ! ( mountain_threshold ) , | t | & t . mountain_threshold , | t | & mut t . mountain_threshold , | t , v | { t . mountain_threshold = v ; } ) ; pub const forest_scale : Field < TerrainGenConfig , f64 > = Field :: new ( stringify ! ( forest_scale ) , | t | & t . forest_scale , | t | & mut t . forest_scale , | t , v | { t . forest_scale = v ; } ) ; pub const temperature_scale : Field < TerrainGenConfig , f64 > = Field :: new ( stringify ! ( temperature_scale ) , | t | & t . temperature_scale , | t | & mut t . temperature_scale , | t , v | { t . temperature_scale = v ; } ) ; pub const moisture_scale : Field < TerrainGenConfig , f64 > = Field :: new ( stringify ! ( moisture_scale ) , | t | & t . moisture_scale , | t | & mut t . moisture_scale , | t , v | { t . moisture_scale = v ; } ) ; pub const resource_density : Field < TerrainGenConfig , f64 > = Field :: new ( stringify ! ( resource_density ) , | t | & t . resource_density , | t | & mut t . resource_density , | t , v | { t . resource_density = v ; } ) ; pub const river_rarity : Field < TerrainGenConfig , u32 > = Field :: new ( stringify ! ( river_rarity ) , | t | & t . river_rarity , | t | & mut t . river_rarity , | t , v | { t . river_rarity = v ; } ) ; pub const biome_weights : Field < TerrainGenConfig , BiomeWeights > = Field :: new ( stringify ! ( biome_weights ) , | t | & t . biome_weights , | t | & mut t . biome_weights , | t , v | { t . biome_weights = v ; } ) ; }
+impl DurabilityData { pub const durability : Field < DurabilityData , Reduceable < i32 > > = Field :: new ( stringify ! ( durability ) , | t | & t . durability , | t | & mut t . durability , | t , v | { t . durability = v ; } ) ; }
+impl EncumbranceData { pub const encumbrance : Field < EncumbranceData , Encumbrance > = Field :: new ( stringify ! ( encumbrance ) , | t | & t . encumbrance , | t | & mut t . encumbrance , | t , v | { t . encumbrance = v ; } ) ; pub const move_speed_modifier : Field < EncumbranceData , Option < ModifierReference > > = Field :: new ( stringify ! ( move_speed_modifier ) , | t | & t . move_speed_modifier , | t | & mut t . move_speed_modifier , | t , v | { t . move_speed_modifier = v ; } ) ; }
+impl WeightData { pub const weight : Field < WeightData , i32 > = Field :: new ( stringify ! ( weight ) , | t | & t . weight , | t | & mut t . weight , | t , v | { t . weight = v ; } ) ; }
+impl LootTableData { pub const drops : Field < LootTableData , Vec < LootDrop > > = Field :: new ( stringify ! ( drops ) , | t | & t . drops , | t | & mut t . drops , | t , v | { t . drops = v ; } ) ; }
+impl ContainerData { pub const slots : Field < ContainerData , u32 > = Field :: new ( stringify ! ( slots ) , | t | & t . slots , | t | & mut t . slots , | t , v | { t . slots = v ; } ) ; pub const carry_capacity_bonus : Field < ContainerData , i32 > = Field :: new ( stringify ! ( carry_capacity_bonus ) , | t | & t . carry_capacity_bonus , | t | & mut t . carry_capacity_bonus , | t , v | { t . carry_capacity_bonus = v ; } ) ; }
+impl QualityData { pub const quality : Field < QualityData , ItemQuality > = Field :: new ( stringify ! ( quality ) , | t | & t . quality , | t | & mut t . quality , | t , v | { t . quality = v ; } ) ; }
+impl RecipeKnowledgeData { pub const known_recipes : Field < RecipeKnowledgeData , Vec < Entity > > = Field :: new ( stringify ! ( known_recipes ) , | t | & t . known_recipes , | t | & mut t . known_recipes , | t , v | { t . known_recipes = v ; } ) ; }
+impl SchematicData { pub const recipes : Field < SchematicData , Vec < Entity > > = Field :: new ( stringify ! ( recipes ) , | t | & t . recipes , | t | & mut t . recipes , | t , v | { t . recipes = v ; } ) ; }
+impl PerkData { pub const perks : Field < PerkData , Vec < String > > = Field :: new ( stringify ! ( perks ) , | t | & t . perks , | t | & mut t . perks , | t , v | { t . perks = v ; } ) ; }
+impl AIData { pub const considerations : Field < AIData , Vec < ( Consideration , f32 ) > > = Field :: new ( stringify ! ( considerations ) , | t | & t . considerations , | t | & mut t . considerations , | t , v | { t . considerations = v ; } ) ; }
+impl AwarenessData { pub const last_seen : Field < AwarenessData , HashMap < Entity , AxialCoord > > = Field :: new ( stringify ! ( last_seen ) , | t | & t . last_seen , | t | & mut t . last_seen , | t , v | { t . last_seen = v ; } ) ; }
+impl MoraleData { pub const morale : Field < MoraleData , Reduceable < i32 > > = Field :: new ( stringify ! ( morale ) , | t | & t . morale , | t | & mut t . morale , | t , v | { t . morale = v ; } ) ; pub const leadership : Field < MoraleData , i32 > = Field :: new ( stringify ! ( leadership ) , | t | & t . leadership , | t | & mut t . leadership , | t , v | { t . leadership = v ; } ) ; pub const state : Field < MoraleData , MoraleState > = Field :: new ( stringify ! ( state ) , | t | & t . state , | t | & mut t . state , | t , v | { t . state = v ; } ) ; }
+impl RelationshipData { pub const relationships : Field < RelationshipData , HashMap < ( Entity , Entity ) , Relationship > > = Field :: new ( stringify ! ( relationships ) , | t | & t . relationships , | t | & mut t . relationships , | t , v | { t . relationships = v ; } ) ; pub const default_relationships : Field < RelationshipData , HashMap < Entity , Relationship > > = Field :: new ( stringify ! ( default_relationships ) , | t | & t . default_relationships , | t | & mut t . default_relationships , | t , v | { t . default_relationships = v ; } ) ; }
+impl ObjectiveData { pub const objectives : Field < ObjectiveData , Vec < Objective > > = Field :: new ( stringify ! ( objectives ) , | t | & t . objectives , | t | & mut t . objectives , | t , v | { t . objectives = v ; } ) ; pub const outcome : Field < ObjectiveData , Option < Outcome > > = Field :: new ( stringify ! ( outcome ) , | t | & t . outcome , | t | & mut t . outcome , | t , v | { t . outcome = v ; } ) ; }
+impl TriggerData { pub const triggers : Field < TriggerData , Vec < ScenarioTrigger > > = Field :: new ( stringify ! ( triggers ) , | t | & t . triggers , | t | & mut t . triggers , | t , v | { t . triggers = v ; } ) ; }
+impl NeedsData { pub const satiation : Field < NeedsData , Reduceable < i32 > > = Field :: new ( stringify ! ( satiation ) , | t | & t . satiation , | t | & mut t . satiation , | t , v | { t . satiation = v ; } ) ; pub const hydration : Field < NeedsData , Reduceable < i32 > > = Field :: new ( stringify ! ( hydration ) , | t | & t . hydration , | t | & mut t . hydration , | t , v | { t . hydration = v ; } ) ; pub const warmth : Field < NeedsData , Reduceable < i32 > > = Field :: new ( stringify ! ( warmth ) , | t | & t . warmth , | t | & mut t . warmth , | t , v | { t . warmth = v ; } ) ; }
+impl AttributeDefinitions { pub const definitions : Field < AttributeDefinitions , HashMap < String , AttributeDefinition > > = Field :: new ( stringify ! ( definitions ) , | t | & t . definitions , | t | & mut t . definitions , | t , v | { t . definitions = v ; } ) ; }
+impl StructureData { pub const build_cost : Field < StructureData , i32 > = Field :: new ( stringify ! ( build_cost ) , | t | & t . build_cost , | t | & mut t . build_cost , | t , v | { t . build_cost = v ; } ) ; pub const built_at : Field < StructureData , Option < AxialCoord > > = Field :: new ( stringify ! ( built_at ) , | t | & t . built_at , | t | & mut t . built_at , | t , v | { t . built_at = v ; } ) ; }
\ No newline at end of file
@@ -5,6 +5,8 @@ use common::hex::AxialCoord;
 use game::core::*;
 use game::entity;
 use entities::selectors::EntitySelector;
+use common::prelude::*;
+use game::ModifierReference;
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize, Fields)]
 pub struct EquipmentData {
@@ -40,9 +42,93 @@ impl InventoryDataStore for WorldView {
 }
 
 
+/// an item that holds other items in its own inventory. Bags are carried and let their carrier haul more before they
+/// are slowed down, chests and stockpiles are set down on a hex and filled from it or its neighbors. Containers can be
+/// packed inside one another, a bag in a chest or a bag in a bag
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Fields)]
+pub struct ContainerData {
+    pub slots : u32,
+    pub carry_capacity_bonus : i32,
+}
+impl EntityData for ContainerData {}
+
+
 #[derive(Clone, Debug, Default, Serialize, Deserialize, Fields)]
 pub struct StackData {
     pub entities : Vec<Entity>,
     pub stack_limit : i32,
 }
-impl EntityData for StackData {}
\ No newline at end of file
+impl EntityData for StackData {}
+
+
+/// how heavily laden a character is relative to what they can comfortably carry
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum Encumbrance {
+    Unencumbered,
+    Burdened,
+    Encumbered,
+    Overloaded,
+}
+impl Default for Encumbrance {
+    fn default() -> Self {
+        Encumbrance::Unencumbered
+    }
+}
+impl Encumbrance {
+    pub fn for_load(weight : i32, capacity : i32) -> Encumbrance {
+        if weight <= capacity {
+            Encumbrance::Unencumbered
+        } else if weight * 2 <= capacity * 3 {
+            Encumbrance::Burdened
+        } else if weight <= capacity * 2 {
+            Encumbrance::Encumbered
+        } else {
+            Encumbrance::Overloaded
+        }
+    }
+
+    pub fn name(&self) -> Str {
+        match self {
+            Encumbrance::Unencumbered => "unencumbered",
+            Encumbrance::Burdened => "burdened",
+            Encumbrance::Encumbered => "encumbered",
+            Encumbrance::Overloaded => "overloaded",
+        }
+    }
+
+    /// how much slower a character moves for each AP put into movement
+    pub fn move_speed_penalty(&self) -> Sext {
+        match self {
+            Encumbrance::Unencumbered => Sext::zero(),
+            Encumbrance::Burdened => Sext::part(1),
+            Encumbrance::Encumbered => Sext::part(2),
+            Encumbrance::Overloaded => Sext::part(3),
+        }
+    }
+
+    /// additional stamina spent on strenuous actions, like attacking
+    pub fn stamina_cost_increase(&self) -> Sext {
+        match self {
+            Encumbrance::Unencumbered | Encumbrance::Burdened => Sext::zero(),
+            Encumbrance::Encumbered => Sext::of(1),
+            Encumbrance::Overloaded => Sext::of(2),
+        }
+    }
+
+    /// stamina spent for every hex moved, hauling a load around is tiring
+    pub fn stamina_cost_per_move(&self) -> Sext {
+        match self {
+            Encumbrance::Unencumbered | Encumbrance::Burdened => Sext::zero(),
+            Encumbrance::Encumbered => Sext::part(1),
+            Encumbrance::Overloaded => Sext::part(2),
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Fields)]
+pub struct EncumbranceData {
+    pub encumbrance : Encumbrance,
+    // the move speed penalty currently applied for the encumbrance, if any
+    pub move_speed_modifier : Option<ModifierReference>,
+}
+impl EntityData for EncumbranceData {}
@@ -7,6 +7,8 @@ use entities::common_entities::Taxon;
 use game::entity;
 use game::DicePool;
 use entities::selectors::EntitySelector;
+use game::core::Reduceable;
+use common::prelude::Str;
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum StackWith {
@@ -36,6 +38,150 @@ impl EntityData for ItemData {
     }
 }
 
+#[derive(Clone, Debug, Serialize, Deserialize, Fields)]
+pub struct WeightData {
+    pub weight : i32,
+}
+impl EntityData for WeightData {}
+impl Default for WeightData {
+    fn default() -> Self {
+        WeightData { weight : 1 }
+    }
+}
+impl WeightData {
+    pub fn new(weight : i32) -> WeightData { WeightData { weight } }
+}
+
+/// an item that is put up on a hex rather than carried about, a shelter or a campfire. Until it has been built it is
+/// only a bundle of parts in someone's inventory, once built it stands where it was put up and can't be picked up again
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Fields)]
+pub struct StructureData {
+    // action points it takes to put the structure up
+    pub build_cost : i32,
+    pub built_at : Option<AxialCoord>,
+}
+impl EntityData for StructureData {}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct LootDrop {
+    // the item dropped, copies of it are created whenever the drop occurs
+    pub item : Entity,
+    // percent chance that the drop occurs at all
+    pub chance : i32,
+    pub amount : DicePool,
+}
+impl LootDrop {
+    pub fn new(item : Entity, amount : DicePool) -> LootDrop { LootDrop { item, chance : 100, amount } }
+    pub fn with_chance(mut self, chance : i32) -> Self {
+        self.chance = chance;
+        self
+    }
+}
+
+/// items a character drops on death in addition to anything they were carrying
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Fields)]
+pub struct LootTableData {
+    pub drops : Vec<LootDrop>,
+}
+impl EntityData for LootTableData {}
+
+/// how well made a crafted item is, better made items hit harder, work faster and last longer
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum ItemQuality {
+    Crude,
+    Poor,
+    Standard,
+    Fine,
+    Superior,
+    Masterwork,
+}
+impl Default for ItemQuality {
+    fn default() -> Self {
+        ItemQuality::Standard
+    }
+}
+impl ItemQuality {
+    /// the quality resulting from a crafting roll, after skill and difficulty have been taken into account
+    pub fn for_roll(roll : i32) -> ItemQuality {
+        if roll <= 0 {
+            ItemQuality::Crude
+        } else if roll <= 5 {
+            ItemQuality::Poor
+        } else if roll <= 13 {
+            ItemQuality::Standard
+        } else if roll <= 18 {
+            ItemQuality::Fine
+        } else if roll <= 24 {
+            ItemQuality::Superior
+        } else {
+            ItemQuality::Masterwork
+        }
+    }
+
+    pub fn name(&self) -> Str {
+        match self {
+            ItemQuality::Crude => "crude",
+            ItemQuality::Poor => "poor",
+            ItemQuality::Standard => "standard",
+            ItemQuality::Fine => "fine",
+            ItemQuality::Superior => "superior",
+            ItemQuality::Masterwork => "masterwork",
+        }
+    }
+
+    /// the bonus applied to the attacks and tool use of an item of this quality
+    pub fn bonus(&self) -> i32 {
+        match self {
+            ItemQuality::Crude => -2,
+            ItemQuality::Poor => -1,
+            ItemQuality::Standard => 0,
+            ItemQuality::Fine => 1,
+            ItemQuality::Superior => 2,
+            ItemQuality::Masterwork => 3,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Fields)]
+pub struct QualityData {
+    pub quality : ItemQuality,
+}
+impl EntityData for QualityData {}
+
+/// how much more use an item can take before it breaks. Items wear with every strike made with them and every harvest
+/// they are used as a tool for, and become less effective as they approach breaking
+#[derive(Clone, Debug, Serialize, Deserialize, Fields)]
+pub struct DurabilityData {
+    pub durability : Reduceable<i32>,
+}
+impl EntityData for DurabilityData {}
+impl Default for DurabilityData {
+    fn default() -> Self {
+        DurabilityData { durability : Reduceable::new(DurabilityData::DEFAULT_DURABILITY) }
+    }
+}
+impl DurabilityData {
+    pub const DEFAULT_DURABILITY : i32 = 30;
+
+    pub fn new(durability : i32) -> DurabilityData { DurabilityData { durability : Reduceable::new(durability) } }
+
+    pub fn is_broken(&self) -> bool { self.durability.cur_value() <= 0 }
+
+    /// penalty to the effectiveness of the item due to wear. A well kept item suffers nothing, below half durability it
+    /// starts to lose its edge, and below a quarter it is barely holding together
+    pub fn wear_penalty(&self) -> i32 {
+        let cur = self.durability.cur_value();
+        let max = self.durability.max_value().max(1);
+        if cur * 4 <= max {
+            2
+        } else if cur * 2 <= max {
+            1
+        } else {
+            0
+        }
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, Fields)]
 pub struct WorthData {
     pub base_worth : Worth,
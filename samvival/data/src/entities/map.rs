@@ -4,6 +4,12 @@ use game::world::WorldView;
 use common::hex::AxialCoord;
 use game::core::*;
 use game::entity;
+use std::path::Path;
+use std::fs::File;
+use std::io::BufReader;
+use std::io::Write;
+use ron;
+use cgmath::InnerSpace;
 
 #[derive(Clone, Default, Debug, Serialize, Deserialize, Fields)]
 pub struct MapData {
@@ -11,3 +17,142 @@ pub struct MapData {
     pub max_tile_bound : AxialCoord
 }
 impl EntityData for MapData {}
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum MapShape {
+    /// all hexes whose centers lie within the given distance of the center of the map, a round map
+    Circle { radius : f32 },
+    /// all hexes within the given distance of the center of the map
+    Hexagon { radius : i32 },
+    /// a map of the given number of hex columns and rows, centered on the center of the map
+    Rectangle { width : i32, height : i32 },
+}
+
+impl MapShape {
+    pub fn hexes(&self) -> Vec<AxialCoord> {
+        let mut ret = Vec::new();
+        match *self {
+            MapShape::Circle { radius } => {
+                // axial coordinates stretch along the diagonals, so the circle can reach out to twice its radius in q or r
+                let bound = (radius * 2.0).ceil() as i32;
+                for q in -bound ..= bound {
+                    for r in -bound ..= bound {
+                        let coord = AxialCoord::new(q, r);
+                        if coord.as_cart_vec().magnitude2() < radius * radius {
+                            ret.push(coord);
+                        }
+                    }
+                }
+            },
+            MapShape::Hexagon { radius } => {
+                let center = AxialCoord::new(0, 0).as_cube_coord();
+                for q in -radius ..= radius {
+                    for r in -radius ..= radius {
+                        let coord = AxialCoord::new(q, r);
+                        if coord.as_cube_coord().distance(&center) <= radius as u32 {
+                            ret.push(coord);
+                        }
+                    }
+                }
+            },
+            MapShape::Rectangle { width, height } => {
+                for row in -height / 2 .. height - height / 2 {
+                    // shift each row back by half its offset so that the columns line up vertically
+                    let offset = (row - (row & 1)) / 2;
+                    for col in -width / 2 .. width - width / 2 {
+                        ret.push(AxialCoord::new(col - offset, row));
+                    }
+                }
+            }
+        }
+        ret
+    }
+}
+
+/// relative prevalence of each biome, at 1.0 a biome appears as often as it does by default, 0.0 removes it entirely
+/// and larger values make it more common
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BiomeWeights {
+    pub tundra : f64,
+    pub desert : f64,
+    pub marsh : f64,
+    pub forest : f64,
+}
+impl Default for BiomeWeights {
+    fn default() -> Self {
+        BiomeWeights { tundra : 1.0, desert : 1.0, marsh : 1.0, forest : 1.0 }
+    }
+}
+
+/// everything the terrain generator needs to produce a map. Generating twice from the same config produces the same
+/// terrain, so sharing a config (or just its seed) is enough to share a map
+#[derive(Clone, Debug, Serialize, Deserialize, Fields)]
+#[serde(default)]
+pub struct TerrainGenConfig {
+    pub seed : u32,
+    pub shape : MapShape,
+    /// scale applied to hex coordinates when sampling the height field, smaller values give broader features
+    pub height_scale : f64,
+    pub height_octaves : usize,
+    pub height_frequency : f64,
+    pub height_persistence : f64,
+    /// heights above which hexes become hills and mountains respectively
+    pub hill_threshold : f64,
+    pub mountain_threshold : f64,
+    pub forest_scale : f64,
+    pub temperature_scale : f64,
+    pub moisture_scale : f64,
+    /// multiplier on the starting amount of every resource placed on the map, from stone and clay to the fish in its
+    /// waters and the wood of its forests. Grass, fruit and reeds are unaffected
+    pub resource_density : f64,
+    /// one in how many mountain hexes gives rise to a river
+    pub river_rarity : u32,
+    pub biome_weights : BiomeWeights,
+}
+impl EntityData for TerrainGenConfig {}
+
+impl Default for TerrainGenConfig {
+    fn default() -> Self {
+        TerrainGenConfig {
+            seed : 2344109,
+            shape : MapShape::Circle { radius : 60.0 },
+            height_scale : 0.05,
+            height_octaves : 3,
+            height_frequency : 0.7,
+            height_persistence : 1.1,
+            hill_threshold : 0.0,
+            mountain_threshold : 0.35,
+            forest_scale : 0.074,
+            temperature_scale : 0.02,
+            moisture_scale : 0.03,
+            resource_density : 1.0,
+            river_rarity : 200,
+            biome_weights : BiomeWeights::default(),
+        }
+    }
+}
+
+impl TerrainGenConfig {
+    pub fn with_seed(seed : u32) -> TerrainGenConfig {
+        TerrainGenConfig { seed, ..Default::default() }
+    }
+
+    pub fn with_shape(mut self, shape : MapShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// reads a config from a RON file, any fields missing from the file take their default values
+    pub fn load<P : AsRef<Path>>(path : P) -> Result<TerrainGenConfig, String> {
+        let file = File::open(path.as_ref()).map_err(|e| format!("could not open terrain config {:?}: {}", path.as_ref(), e))?;
+        ron::de::from_reader(BufReader::new(file)).map_err(|e| format!("could not parse terrain config {:?}: {}", path.as_ref(), e))
+    }
+
+    pub fn save<P : AsRef<Path>>(&self, path : P) -> Result<(), String> {
+        let serialized = ron::ser::to_string_pretty(self, ron::ser::PrettyConfig::default()).map_err(|e| format!("could not serialize terrain config: {}", e))?;
+        let mut file = File::create(path.as_ref()).map_err(|e| format!("could not create terrain config {:?}: {}", path.as_ref(), e))?;
+        file.write_all(serialized.as_bytes()).map_err(|e| format!("could not write terrain config {:?}: {}", path.as_ref(), e))
+    }
+}
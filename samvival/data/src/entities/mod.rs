@@ -8,6 +8,7 @@ pub mod modifiers;
 pub mod skill;
 pub mod tile;
 pub mod time;
+pub mod weather;
 pub mod common_entities;
 pub mod actions;
 pub mod reactions;
@@ -18,6 +19,10 @@ pub mod movement;
 pub mod selectors;
 pub mod effects;
 pub mod recipes;
+pub mod perks;
+pub mod ai;
+pub mod objectives;
+pub mod triggers;
 pub mod archetype_data;
 pub mod helpers;
 mod taxonomy_registration;
@@ -33,6 +38,7 @@ pub use entities::map::*;
 pub use entities::skill::*;
 pub use entities::tile::*;
 pub use entities::time::*;
+pub use entities::weather::*;
 pub use entities::fields::*;
 pub use entities::common_entities::*;
 pub use entities::attributes::*;
@@ -44,5 +50,9 @@ pub use entities::effects::*;
 pub use entities::actions::*;
 pub use entities::reactions::*;
 pub use entities::recipes::*;
+pub use entities::perks::*;
+pub use entities::ai::*;
+pub use entities::objectives::*;
+pub use entities::triggers::*;
 pub use entities::archetype_data::*;
 pub use entities::helpers::*;
\ No newline at end of file
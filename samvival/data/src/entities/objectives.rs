@@ -0,0 +1,71 @@
+use common::prelude::*;
+use game::prelude::*;
+use game::EntityData;
+
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub enum Outcome {
+    Victory,
+    Defeat,
+}
+
+/// What has to happen for an objective to be met. Everything is from the point of view of the faction the objective
+/// belongs to
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum ObjectiveCondition {
+    /// no living enemy of the faction remains that hasn't surrendered
+    KillAllEnemies,
+    /// every member of the faction is dead or has surrendered
+    AllCharactersLost,
+    /// the given turn has been reached
+    SurviveTurns(u32),
+    /// any member of the faction stands on the given hex
+    ReachHex(AxialCoord),
+    /// a member of the faction crafts an item of the given kind
+    CraftItem(String),
+    /// members of the faction harvest at least the given amount of the named resource, in total
+    GatherResource { resource : String, amount : i32 },
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Objective {
+    pub description : String,
+    pub condition : ObjectiveCondition,
+    /// what meeting the objective brings about. A faction wins once all of its victory objectives are met, and loses as
+    /// soon as any defeat objective is
+    pub outcome : Outcome,
+    /// objectives stay met once they have been, even if whatever met them is later undone
+    #[serde(default)]
+    pub completed : bool,
+    /// how far along a gathering objective is
+    #[serde(default)]
+    pub progress : i32,
+}
+
+impl Objective {
+    pub fn new<S : Into<String>>(description : S, condition : ObjectiveCondition, outcome : Outcome) -> Objective {
+        Objective { description : description.into(), condition, outcome, completed : false, progress : 0 }
+    }
+}
+
+/// the objectives of a faction, and how the game ended for it, attached to the faction itself
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Fields)]
+pub struct ObjectiveData {
+    pub objectives : Vec<Objective>,
+    pub outcome : Option<Outcome>,
+}
+impl EntityData for ObjectiveData {}
+
+impl ObjectiveData {
+    pub fn new(objectives : Vec<Objective>) -> ObjectiveData {
+        ObjectiveData { objectives, outcome : None }
+    }
+
+    /// what every scenario was won and lost by before objectives could be declared: wipe out the enemy, or be wiped out
+    pub fn standard() -> ObjectiveData {
+        ObjectiveData::new(vec![
+            Objective::new("Defeat all enemies", ObjectiveCondition::KillAllEnemies, Outcome::Victory),
+            Objective::new("Lose all characters", ObjectiveCondition::AllCharactersLost, Outcome::Defeat),
+        ])
+    }
+}
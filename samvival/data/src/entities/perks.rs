@@ -0,0 +1,139 @@
+use common::prelude::*;
+use game::prelude::*;
+use game::EntityData;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::fmt::Error;
+
+use entities::skill::Skill;
+use entities::combat::DerivedAttackKind;
+use entities::selectors::EntitySelector;
+use entities::taxonomy;
+
+
+/// Perks are fixed rewards for reaching particular levels in a skill. Rather than every level simply being a few percent
+/// better than the last, certain levels grant something new: a special attack, a new way of reacting, a passive bonus
+pub mod perks {
+    use super::*;
+
+    pub static PowerAttack: Perk = &PerkStruct {
+        name: "Power Attack",
+        description: "put your whole weight behind a melee attack, costing more time and effort but hitting much harder",
+        skill: Skill::Melee,
+        level: 3,
+        effect: PerkEffect::DerivedAttack(DerivedAttackKind::PowerAttack, power_attack_condition),
+    };
+
+    pub static Riposte: Perk = &PerkStruct {
+        name: "Riposte",
+        description: "able to counterattack one additional time whenever attacked",
+        skill: Skill::Melee,
+        level: 5,
+        effect: PerkEffect::ExtraCounters(1),
+    };
+
+    pub static AimedShot: Perk = &PerkStruct {
+        name: "Aimed Shot",
+        description: "take the time to line up a shot, spending more action points for a much better chance to hit",
+        skill: Skill::Ranged,
+        level: 3,
+        effect: PerkEffect::DerivedAttack(DerivedAttackKind::AimedShot, aimed_shot_condition),
+    };
+
+    pub static SteadyHands: Perk = &PerkStruct {
+        name: "Steady Hands",
+        description: "+1 accuracy with all ranged attacks",
+        skill: Skill::Ranged,
+        level: 5,
+        effect: PerkEffect::RangedAccuracy(1),
+    };
+
+    pub static LightFooted: Perk = &PerkStruct {
+        name: "Light Footed",
+        description: "+1 to dodge",
+        skill: Skill::Dodge,
+        level: 3,
+        effect: PerkEffect::Dodge(1),
+    };
+
+    pub static ReflexSave: Perk = &PerkStruct {
+        name: "Reflex Save",
+        description: "+3 to avoid attacks that strike more than a single target, by throwing yourself clear of them",
+        skill: Skill::Dodge,
+        level: 5,
+        effect: PerkEffect::ReflexSave(3),
+    };
+
+    pub static Sentinel: Perk = &PerkStruct {
+        name: "default",
+        description: "default perk, indicates that something couldn't be found",
+        skill: Skill::Sentinel,
+        level: 1000,
+        effect: PerkEffect::None,
+    };
+
+    pub static AllPerks : &[Perk] = &[PowerAttack, Riposte, AimedShot, SteadyHands, LightFooted, ReflexSave];
+
+    pub fn perk_with_name(name : &str) -> Perk {
+        *AllPerks.iter().find(|p| p.name == name).unwrap_or(&Sentinel)
+    }
+
+    /// all perks granted by the given skill, in the order they're reached
+    pub fn perks_for_skill(skill : Skill) -> Vec<Perk> {
+        let mut res : Vec<Perk> = AllPerks.iter().cloned().filter(|p| p.skill == skill).collect();
+        res.sort_by_key(|p| p.level);
+        res
+    }
+
+    fn power_attack_condition() -> EntitySelector {
+        EntitySelector::is_one_of(vec![&taxonomy::attacks::SlashingAttack, &taxonomy::attacks::StabbingAttack, &taxonomy::attacks::BludgeoningAttack])
+    }
+
+    fn aimed_shot_condition() -> EntitySelector {
+        EntitySelector::is_a(&taxonomy::attacks::ProjectileAttack)
+    }
+}
+
+#[derive(Clone)]
+pub enum PerkEffect {
+    /// grants a special attack derived from any attack of an equipped weapon that matches the condition
+    DerivedAttack(DerivedAttackKind, fn() -> EntitySelector),
+    ExtraCounters(i32),
+    RangedAccuracy(i32),
+    Dodge(i32),
+    /// a bonus to avoid attacks whose pattern covers more than a single hex
+    ReflexSave(i32),
+    None,
+}
+
+pub struct PerkStruct {
+    pub name: Str,
+    pub description: Str,
+    pub skill: Skill,
+    pub level: i32,
+    pub effect: PerkEffect,
+}
+pub type Perk = &'static PerkStruct;
+
+impl Debug for PerkStruct {
+    fn fmt(&self, f: &mut Formatter) -> Result<(), Error> {
+        write!(f, "{}", self.name)
+    }
+}
+impl PartialEq<PerkStruct> for PerkStruct {
+    fn eq(&self, other: &PerkStruct) -> bool { self.name == other.name }
+}
+
+
+/// the perks a character has been granted, by name
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Fields)]
+pub struct PerkData {
+    pub perks : Vec<String>,
+}
+impl EntityData for PerkData {}
+
+impl PerkData {
+    pub fn has_perk(&self, perk : Perk) -> bool {
+        self.perks.iter().any(|p| p == perk.name)
+    }
+}
@@ -35,6 +35,34 @@ impl Default for RecipeToolUse {
 }
 
 
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum RecipeFlavor {
+    // creates a new item from the result archetype, consuming all ingredients
+    Create,
+    // restores durability to the item supplied for the given ingredient kind, consuming all other ingredients
+    Repair { item_ingredient : Taxon, durability_per_material : i32 },
+}
+impl Default for RecipeFlavor {
+    fn default() -> Self {
+        RecipeFlavor::Create
+    }
+}
+
+
+/// how a character comes to know a recipe, a recipe with several ways of being discovered is learned through any one of them
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum RecipeDiscovery {
+    // everyone knows how to make it from the start
+    Known,
+    // learned by handling an item that matches, i.e. a material the character hasn't worked with before
+    Examine(EntitySelector),
+    // learned upon reaching the given level in a skill
+    SkillLevel(Skill, i32),
+    // learned from a schematic, which every recipe can be, so this only marks recipes that cannot be learned otherwise
+    Schematic,
+}
+
+
 #[derive(PartialEq, Serialize, Deserialize, Debug, Clone, Default, Fields)]
 pub struct Recipe {
     // i.e. MithrilDoomAxe -> DoomAxe -> Axe, so we can choose the most specific recipe
@@ -44,10 +72,26 @@ pub struct Recipe {
     pub result: EntityArchetype,
     pub tools_used: Vec<(EntitySelector, RecipeToolUse)>,
     pub skills_used : Vec<SkillUse>,
+    pub flavor : RecipeFlavor,
+    pub discovered_by : Vec<RecipeDiscovery>,
 }
 
 impl EntityData for Recipe {}
 
+/// the recipes a character has learned beyond those everyone knows
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Fields)]
+pub struct RecipeKnowledgeData {
+    pub known_recipes : Vec<Entity>,
+}
+impl EntityData for RecipeKnowledgeData {}
+
+/// an item describing how to make something, whoever picks it up learns the recipes it describes
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Fields)]
+pub struct SchematicData {
+    pub recipes : Vec<Entity>,
+}
+impl EntityData for SchematicData {}
+
 #[derive(PartialEq, Serialize, Deserialize, Debug, Clone, Default)]
 pub struct SkillUse {
     pub skill : Skill,
@@ -71,6 +115,8 @@ impl Recipe {
             result,
             skills_used : Vec::new(),
             tools_used: Vec::new(),
+            flavor : RecipeFlavor::Create,
+            discovered_by : vec![RecipeDiscovery::Known],
         }
     }
 
@@ -82,9 +128,38 @@ impl Recipe {
             result,
             skills_used : Vec::new(),
             tools_used: Vec::new(),
+            flavor : RecipeFlavor::Create,
+            discovered_by : vec![RecipeDiscovery::Known],
         }
     }
 
+    /// a recipe that creates nothing new, instead restoring durability to an existing item. The item to repair is supplied
+    /// as an ingredient of the given kind, any further ingredients are the materials consumed by the repair
+    pub fn repair<T : Into<Taxon>>(item_ingredient : T, item_selector : EntitySelector, durability_per_material : i32) -> Recipe {
+        let item_ingredient = item_ingredient.into();
+        Recipe {
+            parent_recipe : None,
+            name_from_ingredient : None,
+            ingredients_by_kind : HashMap::new(),
+            result : EntityArchetype::Sentinel,
+            skills_used : Vec::new(),
+            tools_used : Vec::new(),
+            flavor : RecipeFlavor::Repair { item_ingredient : item_ingredient.clone(), durability_per_material },
+            discovered_by : vec![RecipeDiscovery::Known],
+        }.with_ingredient(item_ingredient, item_selector, 1)
+    }
+
+    /// adds a way of discovering this recipe, recipes are known by everyone unless given some other way of discovering them
+    pub fn discovered_by(mut self, discovery : RecipeDiscovery) -> Self {
+        self.discovered_by.retain(|d| d != &RecipeDiscovery::Known);
+        self.discovered_by.push(discovery);
+        self
+    }
+
+    pub fn is_known_by_default(&self) -> bool {
+        self.discovered_by.contains(&RecipeDiscovery::Known)
+    }
+
     pub fn name_from<T : Into<Taxon>>(mut self, ingredient_type : T) -> Self {
         self.name_from_ingredient = Some(ingredient_type.into());
         self
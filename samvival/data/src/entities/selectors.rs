@@ -16,7 +16,7 @@ use entities::common_entities::LookupSignifier;
 pub enum EntitySelector {
     Friend { of: Entity },
     Enemy { of: Entity },
-    //    Neutral { of: Entity },
+    Neutral { of: Entity },
     InMoveRange { hex_range: u32, of: Entity },
     IsCharacter,
     IsTile,
@@ -79,6 +79,7 @@ impl EntitySelector {
             EntitySelector::Is(ent) => view.signifier(*ent),
             EntitySelector::Friend { of } => format!("a friend of {}", view.signifier(*of)),
             EntitySelector::Enemy { of } => format!("an enemy of {}", view.signifier(*of)),
+            EntitySelector::Neutral { of } => format!("someone neutral towards {}", view.signifier(*of)),
             EntitySelector::IsCharacter => format!("a character"),
             EntitySelector::HasInventory => format!("something with an inventory"),
             EntitySelector::HasEquipmentKind(taxon) => prefix_with_indefinite_article(taxon.name()),
@@ -93,6 +94,7 @@ impl EntitySelector {
             EntitySelector::Is(ent) => view.signifier(*ent),
             EntitySelector::Friend { of } => format!("friend of {}", view.signifier(*of)),
             EntitySelector::Enemy { of } => format!("enemy of {}", view.signifier(*of)),
+            EntitySelector::Neutral { of } => format!("neutral towards {}", view.signifier(*of)),
             EntitySelector::IsCharacter => format!("character"),
             EntitySelector::HasInventory => format!("something with an inventory"),
 //            EntitySelector::HasEquipmentKind(taxon) => taxon.name(),
@@ -46,7 +46,8 @@ pub enum Skill {
     Spear = 9,
     Sword = 10,
     Mining = 11,
-    Sentinel = 12,
+    Crafting = 12,
+    Sentinel = 13,
 }
 
 impl Skill {
@@ -64,6 +65,7 @@ impl Skill {
             Skill::Spear => "Spear",
             Skill::Sword => "Sword",
             Skill::Mining => "Mining",
+            Skill::Crafting => "Crafting",
             Skill::Sentinel => "Sentinel",
         }
     }
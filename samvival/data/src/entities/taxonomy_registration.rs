@@ -36,6 +36,15 @@ pub(crate) fn register_taxonomy() {
 		taxonomy::register_taxon(&taxonomy::LightShield);
 		taxonomy::register_taxon(&taxonomy::HeavyShield);
 		taxonomy::register_taxon(&taxonomy::TowerShield);
+		taxonomy::register_taxon(&taxonomy::Container);
+		taxonomy::register_taxon(&taxonomy::containers::Bag);
+		taxonomy::register_taxon(&taxonomy::containers::Chest);
+		taxonomy::register_taxon(&taxonomy::containers::Stockpile);
+		taxonomy::register_taxon(&taxonomy::Shelter);
+		taxonomy::register_taxon(&taxonomy::shelters::LeanTo);
+		taxonomy::register_taxon(&taxonomy::shelters::Hut);
+		taxonomy::register_taxon(&taxonomy::Campfire);
+		taxonomy::register_taxon(&taxonomy::Schematic);
 		taxonomy::register_taxon(&taxonomy::LivingThing);
 		taxonomy::register_taxon(&taxonomy::Creature);
 		taxonomy::register_taxon(&taxonomy::Person);
@@ -69,20 +78,30 @@ pub(crate) fn register_taxonomy() {
 		taxonomy::register_taxon(&taxonomy::resources::Straw);
 		taxonomy::register_taxon(&taxonomy::resources::Fruit);
 		taxonomy::register_taxon(&taxonomy::resources::Wood);
+		taxonomy::register_taxon(&taxonomy::resources::Reeds);
+		taxonomy::register_taxon(&taxonomy::resources::Fish);
 		taxonomy::register_taxon(&taxonomy::resources::Stone);
 		taxonomy::register_taxon(&taxonomy::resources::QuarriedStone);
 		taxonomy::register_taxon(&taxonomy::resources::LooseStone);
 		taxonomy::register_taxon(&taxonomy::resources::Dirt);
+		taxonomy::register_taxon(&taxonomy::resources::Clay);
 		taxonomy::register_taxon(&taxonomy::resources::Iron);
 		taxonomy::register_taxon(&taxonomy::Terrain);
 		taxonomy::register_taxon(&taxonomy::terrain::Plains);
 		taxonomy::register_taxon(&taxonomy::terrain::Hills);
 		taxonomy::register_taxon(&taxonomy::terrain::Mountains);
+		taxonomy::register_taxon(&taxonomy::terrain::Tundra);
+		taxonomy::register_taxon(&taxonomy::terrain::Desert);
+		taxonomy::register_taxon(&taxonomy::terrain::Marsh);
+		taxonomy::register_taxon(&taxonomy::terrain::Water);
+		taxonomy::register_taxon(&taxonomy::terrain::River);
+		taxonomy::register_taxon(&taxonomy::terrain::Lake);
 		taxonomy::register_taxon(&taxonomy::Vegetation);
 		taxonomy::register_taxon(&taxonomy::vegetation::Grassland);
 		taxonomy::register_taxon(&taxonomy::vegetation::Forest);
 		taxonomy::register_taxon(&taxonomy::vegetation::PineForest);
 		taxonomy::register_taxon(&taxonomy::vegetation::DeciduousForest);
+		taxonomy::register_taxon(&taxonomy::vegetation::Reedbed);
 		taxonomy::register_taxon(&taxonomy::IngredientType);
 		taxonomy::register_taxon(&taxonomy::ingredient_types::Haft);
 		taxonomy::register_taxon(&taxonomy::ingredient_types::Axehead);
@@ -90,4 +109,13 @@ pub(crate) fn register_taxonomy() {
 		taxonomy::register_taxon(&taxonomy::ingredient_types::Binding);
 		taxonomy::register_taxon(&taxonomy::ingredient_types::Blade);
 		taxonomy::register_taxon(&taxonomy::ingredient_types::Plate);
+		taxonomy::register_taxon(&taxonomy::ingredient_types::RepairTarget);
+		taxonomy::register_taxon(&taxonomy::ingredient_types::RepairMaterial);
+		taxonomy::register_taxon(&taxonomy::ingredient_types::Pickhead);
+		taxonomy::register_taxon(&taxonomy::ingredient_types::ContainerBody);
+		taxonomy::register_taxon(&taxonomy::ingredient_types::Frame);
+		taxonomy::register_taxon(&taxonomy::ingredient_types::Roofing);
+		taxonomy::register_taxon(&taxonomy::ingredient_types::Walls);
+		taxonomy::register_taxon(&taxonomy::ingredient_types::Fuel);
+		taxonomy::register_taxon(&taxonomy::ingredient_types::Kindling);
 }
\ No newline at end of file
@@ -10,6 +10,7 @@ use game::prelude::*;
 use common::string::IStr;
 use entities::effects::*;
 use entities::EntitySelector;
+use entities::weather::{Weather, WeatherData};
 use game::DicePool;
 use game::EntityIndex;
 use game::DataView;
@@ -122,6 +123,9 @@ impl IntoHarvestable for Entity {
     }
 }
 
+/// fertility of ordinary ground, fertility dependent renewal proceeds at its base rate on ground this fertile
+pub const BASELINE_FERTILITY : i8 = 4;
+
 #[derive(Clone, Debug, Serialize, Deserialize, Fields)]
 pub struct RenewRate {
     pub fertility_dependent : bool,
@@ -129,6 +133,33 @@ pub struct RenewRate {
     pub rate : Sext
 }
 
+impl RenewRate {
+    pub fn new(rate : Sext) -> RenewRate {
+        RenewRate { fertility_dependent : false, season_multipliers : HashMap::new(), rate }
+    }
+
+    pub fn fertility_dependent(mut self) -> Self {
+        self.fertility_dependent = true;
+        self
+    }
+
+    pub fn in_season(mut self, season : Season, multiplier : f32) -> Self {
+        self.season_multipliers.insert(season, multiplier);
+        self
+    }
+
+    /// the amount that regrows over the course of a single day, seasons without an explicit multiplier renew at the base rate
+    pub fn amount_per_day(&self, season : Season, fertility : i8) -> Sext {
+        let season_multiplier = self.season_multipliers.get(&season).cloned().unwrap_or(1.0);
+        let fertility_multiplier = if self.fertility_dependent {
+            fertility.max(0) as f32 / BASELINE_FERTILITY as f32
+        } else {
+            1.0
+        };
+        Sext::of_rounded(self.rate.as_f32() * season_multiplier * fertility_multiplier)
+    }
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub enum DepletionBehavior {
     None, // do nothing when depleted
@@ -138,6 +169,14 @@ pub enum DepletionBehavior {
 }
 impl Default for DepletionBehavior { fn default() -> Self { DepletionBehavior::None } }
 
+/// record of the depletion behavior that was applied to a tile when a harvestable ran out, kept so that
+/// it can be reverted if the harvestable renews
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct Depletion {
+    pub tile : Entity,
+    pub effects : EffectApplication,
+}
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub enum ToolUse {
     None, // does not use a tool
@@ -162,9 +201,12 @@ pub struct Harvestable {
     pub tool_use: ToolUse,
     pub skills_used: Vec<Skill>,
     pub character_requirements: EntitySelector,
+    pub depletion: Option<Depletion>,
 }
 impl Harvestable {
     pub fn requires_tool(&self) -> bool { self.tool_use == ToolUse::Required }
+    pub fn is_depleted(&self) -> bool { self.amount.cur_value() <= Sext::zero() }
+    pub fn is_fully_grown(&self) -> bool { self.amount.cur_value() >= self.amount.max_value() }
 }
 
 impl EntityData for Harvestable {}
@@ -185,6 +227,7 @@ impl Default for Harvestable {
             tool_use: ToolUse::None,
             skills_used: Vec::new(),
             character_requirements: EntitySelector::Any,
+            depletion: None,
         }
     }
 }
@@ -195,6 +238,7 @@ pub struct TileAccessor<'a> {
     tiles : DataView<'a, TileData>,
     terrain : DataView<'a, TerrainData>,
     vegetation : DataView<'a, VegetationData>,
+    weather : Weather,
 }
 
 impl <'a> TileAccessor<'a> {
@@ -204,6 +248,7 @@ impl <'a> TileAccessor<'a> {
             tiles : from.all_data_of_type::<TileData>(),
             terrain : from.all_data_of_type::<TerrainData>(),
             vegetation : from.all_data_of_type::<VegetationData>(),
+            weather : from.world_data_opt::<WeatherData>().map(|w| w.weather).unwrap_or_default(),
         }
     }
     pub fn tile_opt(&self, pos : AxialCoord) -> Option<TileEntity> {
@@ -213,6 +258,14 @@ impl <'a> TileAccessor<'a> {
     pub fn terrain_at(&'a self, pos : AxialCoord) -> &'a TerrainData { self.index.get(&pos).map(|ent| self.terrain.data(*ent)).unwrap_or(self.terrain.sentinel()) }
     pub fn vegetation(&'a self, ent : &TileEntity) -> &'a VegetationData { self.vegetation.data(ent.entity) }
     pub fn vegetation_opt(&'a self, ent : &TileEntity) -> Option<&'a VegetationData> { self.vegetation.data_opt(ent.entity) }
+    /// the full cost of entering the tile, including its terrain, vegetation and the current weather
+    pub fn move_cost(&self, ent : &TileEntity) -> Sext {
+        self.terrain.data(ent.entity).move_cost + self.vegetation.data(ent.entity).move_cost + self.weather.move_cost_penalty()
+    }
+    /// whether the tile can be entered at all, lakes are too deep to wade so nothing can cross them on foot
+    pub fn is_passable(&self, ent : &TileEntity) -> bool {
+        self.terrain.data(ent.entity).kind != Taxon::of(&taxonomy::terrain::Lake)
+    }
 }
 
 //impl Harvestable {
@@ -242,6 +295,9 @@ pub struct ConstantResources {
     pub loose_stone: Entity,
     pub fruit: Entity,
     pub dirt: Entity,
+    pub fish: Entity,
+    pub reeds: Entity,
+    pub clay: Entity,
 }
 
 #[derive(Clone, Default, Debug, Serialize, Deserialize, Fields)]
@@ -252,9 +308,11 @@ pub struct Resources {
 
 impl EntityData for Resources {}
 
+/// What eating something does for a character, how much hunger it sates and thirst it quenches
 #[derive(Clone, Default, Debug, Serialize, Deserialize, Fields)]
 pub struct FoodInfo {
-    pub satiation: i32
+    pub satiation: i32,
+    pub hydration: i32,
 }
 
 impl EntityData for FoodInfo {}
@@ -363,7 +421,7 @@ impl Resources {
         if main.wood.is_sentinel() {
             let training_blade_material_effect = MaterialEffect(
                 MaterialEffectSelector::IngredientType((&taxonomy::ingredient_types::WeaponHeadIngredient).into()),
-                MaterialEffectType::WeaponAttribute(&attributes::TrainingWeapon, 1)
+                MaterialEffectType::WeaponAttribute(attributes::TrainingWeapon.clone(), 1)
             );
 
             main.wood = EntityBuilder::new()
@@ -480,8 +538,55 @@ impl Resources {
                 })
                 .with(WorthData::new(Worth::medium(0)))
                 .with(ItemData { stack_limit: 6, ..Default::default() })
+                .with(FoodInfo { satiation: 6, hydration: 4 })
                 .with(IdentityData::of_kind(&taxonomy::resources::Fruit))
                 .create(world);
+            main.fruit = fruit;
+        }
+
+        if main.fish.is_sentinel() {
+            main.fish = EntityBuilder::new()
+                .with(Material {
+                    hardness: 1,
+                    density: 2,
+                    ..Default::default()
+                })
+                .with(WorthData::new(Worth::medium(0)))
+                .with(ItemData { stack_limit: 3, ..Default::default() })
+                .with(FoodInfo { satiation: 12, hydration: 0 })
+                .with(IdentityData::of_kind(&taxonomy::resources::Fish))
+                .create(world);
+        }
+
+        if main.reeds.is_sentinel() {
+            main.reeds = EntityBuilder::new()
+                .with(Material {
+                    hardness: 1,
+                    density: 1,
+                    strength: 2,
+                    flammable: true,
+                    cordable: true,
+                    ..Default::default()
+                })
+                .with(WorthData::new(Worth::low(2)))
+                .with(ItemData { stack_limit: 6, ..Default::default() })
+                .with(IdentityData::of_kind(&taxonomy::resources::Reeds))
+                .create(world);
+        }
+
+        if main.clay.is_sentinel() {
+            main.clay = EntityBuilder::new()
+                .with(Material {
+                    density: 4,
+                    hardness: 2,
+                    item_quality: -1,
+                    building_quality: 1,
+                    ..Default::default()
+                })
+                .with(WorthData::new(Worth::low(3)))
+                .with(ItemData { stack_limit: 2, ..Default::default() })
+                .with(IdentityData::of_kind(&taxonomy::resources::Clay))
+                .create(world);
         }
 
         world.modify_world(Resources::main.set_to(main), "resource initialization");
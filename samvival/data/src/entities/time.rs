@@ -29,7 +29,7 @@ impl EntityData for TurnData {}
 //}
 
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum TimeOfDay {
     Dawn,
     Daylight,
@@ -42,8 +42,15 @@ impl Default for TimeOfDay {
     }
 }
 
+impl TimeOfDay {
+    /// the times of day in the order they occur, a day starts at dawn
+    pub fn in_order() -> [TimeOfDay; 4] {
+        [TimeOfDay::Dawn, TimeOfDay::Daylight, TimeOfDay::Dusk, TimeOfDay::Night]
+    }
+}
+
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Season {
     Spring,
     Summer,
@@ -56,17 +63,64 @@ impl Default for Season {
     }
 }
 
+impl Season {
+    /// the seasons in the order they occur, a year starts in spring
+    pub fn in_order() -> [Season; 4] {
+        [Season::Spring, Season::Summer, Season::Autumn, Season::Winter]
+    }
+}
+
 
 #[derive(Clone, Debug, Fields, Serialize, Deserialize)]
 pub struct TimeData {
     pub moments_since_world_start : u32,
     pub moments_per_time_of_day: HashMap<TimeOfDay, u32>,
     pub days_per_season: HashMap<Season, u32>,
-    pub moments_since_day_start : u32
+    pub moments_since_day_start : u32,
+    pub days_since_world_start : u32,
 }
 
 impl TimeData {
+    pub fn moments_per_day(&self) -> u32 {
+        self.moments_per_time_of_day.values().sum()
+    }
+
+    pub fn days_per_year(&self) -> u32 {
+        self.days_per_season.values().sum()
+    }
 
+    pub fn time_of_day(&self) -> TimeOfDay {
+        let mut moments_remaining = self.moments_since_day_start;
+        for time_of_day in TimeOfDay::in_order().iter() {
+            let duration = self.moments_per_time_of_day.get(time_of_day).cloned().unwrap_or(0);
+            if moments_remaining < duration {
+                return time_of_day.clone();
+            }
+            moments_remaining -= duration;
+        }
+        TimeOfDay::Night
+    }
+
+    pub fn season(&self) -> Season {
+        let days_per_year = self.days_per_year().max(1);
+        let mut days_remaining = self.days_since_world_start % days_per_year;
+        for season in Season::in_order().iter() {
+            let duration = self.days_per_season.get(season).cloned().unwrap_or(0);
+            if days_remaining < duration {
+                return season.clone();
+            }
+            days_remaining -= duration;
+        }
+        Season::Winter
+    }
+
+    /// the moment within the day at which the given time of day begins
+    pub fn start_of(&self, time_of_day : TimeOfDay) -> u32 {
+        TimeOfDay::in_order().iter()
+            .take_while(|t| *t != &time_of_day)
+            .map(|t| self.moments_per_time_of_day.get(t).cloned().unwrap_or(0))
+            .sum()
+    }
 }
 
 impl Default for TimeData {
@@ -84,11 +138,15 @@ impl Default for TimeData {
             (Season::Winter, 6u32)
         ].iter().cloned().collect();
 
+        // start the world at the beginning of daylight rather than at dawn
+        let moments_since_day_start = durations[&TimeOfDay::Dawn];
+
         TimeData {
             moments_since_world_start : 0,
-            moments_since_day_start : 0,
+            moments_since_day_start,
             moments_per_time_of_day: durations,
-            days_per_season
+            days_per_season,
+            days_since_world_start : 0,
         }
     }
 }
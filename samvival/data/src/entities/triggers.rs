@@ -0,0 +1,54 @@
+use common::prelude::*;
+use game::prelude::*;
+use game::EntityData;
+use entities::faction::Relationship;
+
+
+/// What sets a trigger off. Scenarios are written by hand, so characters and factions are referred to by name
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum TriggerCondition {
+    /// the given turn begins
+    OnTurn(u32),
+    /// a character moves into any of the given hexes, only members of the named faction count if one is given
+    OnEnteringRegion { faction : Option<String>, hexes : Vec<AxialCoord> },
+    /// the named character dies
+    OnDeath(String),
+    /// anyone crafts an item of the given kind
+    OnItemCrafted(String),
+}
+
+/// Something a trigger does when set off, run in order with the rest of the trigger's actions
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum ScriptedAction {
+    /// the named character says something, holding the game up until the player has read it if it requires confirmation
+    Dialog { speaker : String, text : String, #[serde(default)] requires_confirmation : bool },
+    /// a creature of the given archetype appears for the named faction, next to the given hex if that is taken
+    SpawnCharacter { archetype : String, faction : String, at : AxialCoord },
+    /// the named faction learns the lay of the land in the given hexes, without seeing who is there
+    RevealHexes { faction : String, hexes : Vec<AxialCoord> },
+    SetRelationship { faction_a : String, faction_b : String, relationship : Relationship },
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ScenarioTrigger {
+    pub condition : TriggerCondition,
+    pub actions : Vec<ScriptedAction>,
+    /// triggers only go off once unless they repeat
+    #[serde(default)]
+    pub repeat : bool,
+    #[serde(default)]
+    pub fired : bool,
+}
+
+impl ScenarioTrigger {
+    pub fn new(condition : TriggerCondition, actions : Vec<ScriptedAction>) -> ScenarioTrigger {
+        ScenarioTrigger { condition, actions, repeat : false, fired : false }
+    }
+}
+
+/// the scripted triggers of the scenario being played, attached to the world
+#[derive(Clone, Debug, Default, Serialize, Deserialize, Fields)]
+pub struct TriggerData {
+    pub triggers : Vec<ScenarioTrigger>,
+}
+impl EntityData for TriggerData {}
@@ -0,0 +1,85 @@
+use game::entity::EntityData;
+use common::reflect::*;
+use game::prelude::*;
+use entities::time::Season;
+
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Weather {
+    Clear,
+    Rain,
+    Snow,
+    Fog,
+    Heat,
+}
+impl Default for Weather {
+    fn default() -> Self {
+        Weather::Clear
+    }
+}
+
+impl Weather {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Weather::Clear => "clear",
+            Weather::Rain => "rain",
+            Weather::Snow => "snow",
+            Weather::Fog => "fog",
+            Weather::Heat => "heat",
+        }
+    }
+
+    /// relative likelihood of each kind of weather setting in on a given day of the season
+    pub fn likelihoods(season : Season) -> Vec<(Weather, u32)> {
+        match season {
+            Season::Spring => vec![(Weather::Clear, 5), (Weather::Rain, 4), (Weather::Fog, 2)],
+            Season::Summer => vec![(Weather::Clear, 6), (Weather::Rain, 2), (Weather::Heat, 3)],
+            Season::Autumn => vec![(Weather::Clear, 4), (Weather::Rain, 4), (Weather::Fog, 3), (Weather::Snow, 1)],
+            Season::Winter => vec![(Weather::Clear, 4), (Weather::Snow, 5), (Weather::Fog, 2)],
+        }
+    }
+
+    /// hexes by which all observers' vision range is reduced
+    pub fn vision_penalty(&self) -> i32 {
+        match self {
+            Weather::Fog => 3,
+            Weather::Rain | Weather::Snow => 1,
+            _ => 0
+        }
+    }
+
+    /// additional cost to enter any hex
+    pub fn move_cost_penalty(&self) -> Sext {
+        match self {
+            Weather::Snow => Sext::of(1),
+            Weather::Rain => Sext::of_parts(0, 2),
+            _ => Sext::zero()
+        }
+    }
+
+    /// amount by which characters' per-turn stamina recovery is reduced
+    pub fn stamina_recovery_penalty(&self) -> Sext {
+        match self {
+            Weather::Heat => Sext::of_parts(0, 3),
+            Weather::Snow => Sext::of_parts(0, 2),
+            _ => Sext::zero()
+        }
+    }
+
+    /// flat bonus (or penalty) to the damage of attacks that deal fire damage
+    pub fn fire_damage_bonus(&self) -> i32 {
+        match self {
+            Weather::Rain | Weather::Snow => -2,
+            Weather::Heat => 2,
+            _ => 0
+        }
+    }
+}
+
+
+#[derive(Clone, Default, Debug, Serialize, Deserialize, Fields)]
+pub struct WeatherData {
+    pub weather : Weather,
+    pub days_of_current_weather : u32,
+}
+impl EntityData for WeatherData {}
@@ -11,6 +11,16 @@ use std::collections::HashMap;
 use entities::reactions::ReactionTypeRef;
 use entities::actions::ActionType;
 use entities::actions::Action;
+use entities::time::TimeOfDay;
+use entities::time::Season;
+use entities::weather::Weather;
+use entities::inventory::Encumbrance;
+use entities::item::ItemQuality;
+use entities::skill::Skill;
+use entities::character::MoraleState;
+use entities::character::{Need, NeedSeverity};
+use entities::faction::Relationship;
+use entities::objectives::Outcome;
 
 
 #[derive(Clone, Debug,Serialize,Deserialize)]
@@ -31,6 +41,7 @@ pub enum GameEvent {
     WorldStart,
     SelectedAttackChanged { entity : Entity, attack_ref : AttackRef },
     ActionCanceled,
+    ActionInterrupted { entity : Entity, action : Action },
     SelectedCounterattackChanged { entity : Entity, attack_ref : AttackRef },
     SelectedReactionChanged { entity : Entity, reaction_type : ReactionTypeRef },
     ReactionEffectApplied { entity : Entity },
@@ -38,6 +49,23 @@ pub enum GameEvent {
     EntityHarvested { harvester : Entity, harvestable : Entity, harvested_from: Entity, resource : Entity, amount : Option<i32> },
     EntityCreated { entity : Entity },
     DialogSpoken { speaker : Entity, text : RichString, requires_confirmation : bool },
+    TimeOfDayChanged { time_of_day : TimeOfDay, day : u32 },
+    SeasonChanged { season : Season },
+    WeatherChanged { weather : Weather },
+    HarvestablesRenewed,
+    ItemBroken { item : Entity, owner : Option<Entity> },
+    ItemRepaired { item : Entity, repairer : Entity, durability_restored : i32 },
+    ItemCrafted { item : Entity, crafter : Entity, quality : ItemQuality },
+    RecipeDiscovered { character : Entity, recipe : Entity },
+    SkillLevelUp { entity : Entity, skill : Skill, level : i32 },
+    PerkGained { entity : Entity, perk : String },
+    EncumbranceChanged { character : Entity, encumbrance : Encumbrance },
+    MoraleCheck { entity : Entity, morale : i32, state : MoraleState, reason : String },
+    NeedSeverityChanged { character : Entity, need : Need, severity : NeedSeverity },
+    ItemConsumed { consumer : Entity, item : Entity },
+    RelationshipChanged { faction_a : Entity, faction_b : Entity, relationship : Relationship },
+    ObjectiveCompleted { faction : Entity, description : String, outcome : Outcome },
+    GameEnded { faction : Entity, outcome : Outcome },
 
     EffectRegistered,
     Default
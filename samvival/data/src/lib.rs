@@ -36,6 +36,7 @@ extern crate regex;
 extern crate num;
 extern crate cgmath;
 extern crate multimap;
+extern crate find_folder;
 
 pub mod entities;
 
@@ -6,18 +6,24 @@ use data::entities::EquipmentData;
 use data::entities::GraphicsData;
 use data::entities::IdentityData;
 use data::entities::InventoryData;
+use data::entities::{LootTableData, LootDrop, Resources};
+use data::entities::recipes::RecipeKnowledgeData;
+use data::entities::EncumbranceData;
 use data::entities::ModifierTrackingData;
 use data::entities::movement::MovementData;
 use data::entities::movement;
 use data::entities::ObserverData;
 use data::entities::PositionData;
 use data::entities::SkillData;
+use data::entities::PerkData;
+use data::entities::{AIData, Consideration, MoraleData, NeedsData};
 use prelude::*;
 use std::collections::HashMap;
 
 pub fn character_archetypes() -> ArchetypeLibrary {
     let baseline: EntityBuilder = EntityBuilder::new()
         .with(SkillData::default())
+        .with(PerkData::default())
         .with(InventoryData {
             items: Vec::new(),
             inventory_size: Some(5),
@@ -28,10 +34,13 @@ pub fn character_archetypes() -> ArchetypeLibrary {
             ..Default::default()
         })
         .with(EquipmentData::default())
+        .with(EncumbranceData::default())
         .with(PositionData::default())
         .with(GraphicsData::default())
         .with(ActionData::default())
         .with(ModifierTrackingData::default())
+        .with(AIData::default())
+        .with(MoraleData::default())
         .with(ObserverData { vision_range: 10, low_light_vision_range: 6, dark_vision_range: 3 })
         .with(IdentityData::of_kind(&taxonomy::Person));
 
@@ -39,6 +48,8 @@ pub fn character_archetypes() -> ArchetypeLibrary {
     let mut archetypes_by_name = HashMap::new();
 
     archetypes_by_name.insert(strf("human"), baseline.clone()
+        .with(RecipeKnowledgeData::default())
+        .with(NeedsData::default())
         .with_creator(|world| CombatData {
             natural_attacks: vec![
                 create_attack(world, "punch", vec![&taxonomy::attacks::NaturalAttack, &taxonomy::attacks::BludgeoningAttack, &taxonomy::attacks::MeleeAttack], Attack {
@@ -86,11 +97,35 @@ pub fn character_archetypes() -> ArchetypeLibrary {
                     })],
             ..Default::default()
         })
+        // it takes a lot to make a mud monster turn tail
+        .with(MoraleData::with_morale(16))
+        // mud monsters are single minded, they don't retreat and don't much care what they walk into, but they hunt as a
+        // pack, all piling onto the same prey
+        .with(AIData::new(vec![
+            (Consideration::DamageDealt, 1.0),
+            (Consideration::KillChance, 2.0),
+            (Consideration::FocusFire, 3.0),
+            (Consideration::Approach, 1.0),
+            (Consideration::Search, 0.5),
+            (Consideration::Reaction, 1.0),
+        ]))
+        // mud monsters hunt as well by night as by day
+        .with(ObserverData { vision_range: 8, low_light_vision_range: 8, dark_vision_range: 8 })
         .with(SkillData::default())
         .with(EquipmentData::default())
         .with(GraphicsData::default())
         .with(PositionData::default())
-        .with_creator(|world| IdentityData::of_kind(Taxon::new(world, "mud monster", &taxonomy::Monster))),
+        .with_creator(|world| IdentityData::of_kind(Taxon::new(world, "mud monster", &taxonomy::Monster)))
+        // mud monsters leave behind some of what they are made of
+        .with_creator(|world| match world.view().world_data_opt::<Resources>() {
+            Some(resources) => LootTableData {
+                drops: vec![
+                    LootDrop::new(resources.main.clay, DicePool::of(1,3)),
+                    LootDrop::new(resources.main.loose_stone, DicePool::of(1,2)).with_chance(50),
+                ]
+            },
+            None => LootTableData::default()
+        }),
     );
 
     ArchetypeLibrary {
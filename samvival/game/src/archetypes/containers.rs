@@ -0,0 +1,68 @@
+use game::prelude::*;
+use common::prelude::*;
+use data::entities::item::*;
+use data::entities::{IdentityData, ItemArchetype, ContainerData};
+
+use data::entities::taxonomy;
+use data::entities::taxonomy::ingredient_types::*;
+use data::entities::taxonomy::materials::*;
+use data::entities::taxonomy::resources::{Straw, Reeds};
+use data::archetype::EntityArchetype;
+use data::entities::recipes::*;
+use data::entities::EntitySelector;
+use data::entities::Skill;
+
+
+pub fn create_container_archetypes(world: &mut World) {
+    // a bag doesn't hold much, but it spreads the load so that more can be carried comfortably
+    let bag = EntityBuilder::new()
+        .with(ItemArchetype {
+            worth: Worth::low(-1),
+            weight: 1,
+            container: Some(ContainerData { slots: 4, carry_capacity_bonus: 4 }),
+            ..Default::default()
+        })
+        .with(IdentityData::of_kind(&taxonomy::containers::Bag))
+        .create(world);
+
+    let chest = EntityBuilder::new()
+        .with(ItemArchetype {
+            worth: Worth::low(1),
+            weight: 8,
+            durability: Some(DurabilityData::DEFAULT_DURABILITY * 2),
+            container: Some(ContainerData { slots: 12, carry_capacity_bonus: 0 }),
+            ..Default::default()
+        })
+        .with(IdentityData::of_kind(&taxonomy::containers::Chest))
+        .create(world);
+
+    // a stockpile is just a marked out, covered spot at a campsite, not something anyone would want to carry around
+    let stockpile = EntityBuilder::new()
+        .with(ItemArchetype {
+            worth: Worth::low(-1),
+            weight: 40,
+            durability: None,
+            container: Some(ContainerData { slots: 30, carry_capacity_bonus: 0 }),
+            ..Default::default()
+        })
+        .with(IdentityData::of_kind(&taxonomy::containers::Stockpile))
+        .create(world);
+
+    EntityBuilder::new()
+        .with(Recipe::new(EntityArchetype::Archetype(bag))
+            .with_ingredient(&ContainerBody, EntitySelector::is_either(&Straw, &Reeds), 3)
+            .discovered_by(RecipeDiscovery::Examine(EntitySelector::is_either(&Straw, &Reeds)))
+        ).create(world);
+
+    EntityBuilder::new()
+        .with(Recipe::new(EntityArchetype::Archetype(chest))
+            .with_ingredient(&ContainerBody, EntitySelector::is_a(&Wood), 4)
+            .with_single_skill(Skill::Crafting, None)
+            .discovered_by(RecipeDiscovery::SkillLevel(Skill::Crafting, 1))
+        ).create(world);
+
+    EntityBuilder::new()
+        .with(Recipe::new(EntityArchetype::Archetype(stockpile))
+            .with_ingredient(&ContainerBody, EntitySelector::is_either(&Wood, &Stone), 2)
+        ).create(world);
+}
@@ -1,5 +1,7 @@
 pub mod weapons;
 
+pub mod containers;
+
 //pub use archetypes::weapons::weapon_archetypes;
 
 pub mod characters;
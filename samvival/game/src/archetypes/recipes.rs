@@ -4,11 +4,69 @@ use entities::Recipe;
 use entities::taxonomy;
 use entities::taxonomy::ingredient_types::*;
 use entities::taxonomy::materials::*;
+use entities::taxonomy::resources::{Straw, Reeds, Clay};
 use entities::EntitySelector;
 use entities::Ingredient;
+use entities::{IdentityData, ItemArchetype, Skill};
+use entities::item::*;
+use entities::recipes::RecipeDiscovery;
 use archetype::EntityArchetype;
 
 
+/// the buildings and other odds and ends that don't belong with any particular kind of equipment
 pub fn create_recipes(world: &mut World) {
+    let lean_to = EntityBuilder::new()
+        .with(ItemArchetype {
+            worth: Worth::low(-1),
+            weight: 20,
+            structure: Some(StructureData { build_cost : 3, built_at : None }),
+            ..Default::default()
+        })
+        .with(IdentityData::of_kind(&taxonomy::shelters::LeanTo))
+        .create(world);
 
-}
\ No newline at end of file
+    let hut = EntityBuilder::new()
+        .with(ItemArchetype {
+            worth: Worth::medium(0),
+            weight: 60,
+            durability: Some(DurabilityData::DEFAULT_DURABILITY * 4),
+            structure: Some(StructureData { build_cost : 6, built_at : None }),
+            ..Default::default()
+        })
+        .with(IdentityData::of_kind(&taxonomy::shelters::Hut))
+        .create(world);
+
+    let campfire = EntityBuilder::new()
+        .with(ItemArchetype {
+            worth: Worth::low(-5),
+            weight: 8,
+            structure: Some(StructureData { build_cost : 2, built_at : None }),
+            ..Default::default()
+        })
+        .with(IdentityData::of_kind(&taxonomy::Campfire))
+        .create(world);
+
+    EntityBuilder::new()
+        .with(Recipe::new(EntityArchetype::Archetype(lean_to))
+            .with_ingredient(&Frame, EntitySelector::is_a(&Wood), 3)
+            .with_ingredient(&Roofing, EntitySelector::is_either(&Straw, &Reeds), 3)
+        ).create(world);
+
+    // walls that keep the weather out take either some practice or a good look at what packed clay can do
+    EntityBuilder::new()
+        .with(Recipe::new(EntityArchetype::Archetype(hut))
+            .with_ingredient(&Frame, EntitySelector::is_a(&Wood), 6)
+            .with_ingredient(&Walls, EntitySelector::is_either(&Clay, &Stone), 6)
+            .with_single_skill(Skill::Crafting, None)
+            .discovered_by(RecipeDiscovery::SkillLevel(Skill::Crafting, 3))
+            .discovered_by(RecipeDiscovery::Examine(EntitySelector::is_a(&Clay)))
+        ).create(world);
+
+    // a fire only needs something to burn and something to get it going, but it's what keeps a party alive through
+    // the nights and the winter
+    EntityBuilder::new()
+        .with(Recipe::new(EntityArchetype::Archetype(campfire))
+            .with_ingredient(&Fuel, EntitySelector::is_a(&Wood), 2)
+            .with_ingredient(&Kindling, EntitySelector::is_either(&Straw, &Reeds), 1)
+        ).create(world);
+}
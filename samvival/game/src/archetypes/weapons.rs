@@ -15,6 +15,7 @@ use data::entities::taxonomy::materials::*;
 use data::archetype::EntityArchetype;
 use data::entities::recipes::*;
 use data::entities::EntitySelector;
+use data::entities::Skill;
 
 
 pub fn create_weapon_archetypes(world: &mut World) {
@@ -95,6 +96,8 @@ pub fn create_weapon_archetypes(world: &mut World) {
                 attack.stamina_cost += 1;
                 attack.ap_cost += 1;
             }
+            // and brittle
+            base.durability = base.durability.map(|d| d / 2);
             base
         })
         .with(IdentityData::of_kind(Taxon::new(world, "stone longsword", &taxonomy::weapons::Longsword)))
@@ -229,6 +232,7 @@ pub fn create_weapon_archetypes(world: &mut World) {
         .with(Recipe::new(EntityArchetype::Archetype(hatchet))
             .with_ingredient(&Haft, EntitySelector::is_one_of(vec![&Wood, &Metal]), 2)
             .with_ingredient(&Axehead, EntitySelector::is_either(&Metal, &Stone), 2)
+            .with_single_skill(Skill::Crafting, None)
         ).create(world);
 
     let longsword_recipe = EntityBuilder::new()
@@ -236,6 +240,8 @@ pub fn create_weapon_archetypes(world: &mut World) {
             .name_from(&Blade)
             .with_ingredient(&Haft, EntitySelector::is_either(&Wood, &Metal), 2)
             .with_ingredient(&Blade, EntitySelector::is_one_of(vec![&Wood, &Metal, &Stone]), 3)
+            .with_single_skill(Skill::Crafting, None)
+            .discovered_by(RecipeDiscovery::SkillLevel(Skill::Crafting, 2))
         ).create(world);
 
     let stone_longsword_recipe = EntityBuilder::new()
@@ -245,6 +251,36 @@ pub fn create_weapon_archetypes(world: &mut World) {
     let training_longsword_recipe = EntityBuilder::new()
         .with(Recipe::new_child(EntityArchetype::Archetype(training_longsword), longsword_recipe)
             .with_ingredient(&Blade, EntitySelector::is_a(&Wood), 3)).create(world);
+
+    EntityBuilder::new()
+        .with(Recipe::new(EntityArchetype::Archetype(longspear))
+            .name_from(&Spearhead)
+            .with_ingredient(&Haft, EntitySelector::is_a(&Wood), 3)
+            .with_ingredient(&Spearhead, EntitySelector::is_either(&Stone, &Metal), 1)
+            .with_single_skill(Skill::Crafting, None)
+        ).create(world);
+
+    // nobody thinks to put a point on a stick and swing it at rocks until they've had a good look at some rocks
+    EntityBuilder::new()
+        .with(Recipe::new(EntityArchetype::Archetype(pickaxe))
+            .with_ingredient(&Haft, EntitySelector::is_a(&Wood), 2)
+            .with_ingredient(&Pickhead, EntitySelector::is_either(&Stone, &Metal), 2)
+            .with_single_skill(Skill::Crafting, None)
+            .discovered_by(RecipeDiscovery::Examine(EntitySelector::is_a(&Stone)))
+        ).create(world);
+
+    EntityBuilder::new()
+        .with(Recipe::new(EntityArchetype::Archetype(longbow))
+            .with_ingredient(&Haft, EntitySelector::is_a(&Wood), 3)
+            .with_ingredient(&Binding, EntitySelector::is_either(&taxonomy::resources::Straw, &taxonomy::resources::Reeds), 1)
+            .with_single_skill(Skill::Crafting, None)
+            .discovered_by(RecipeDiscovery::SkillLevel(Skill::Ranged, 2))
+        ).create(world);
+
+    EntityBuilder::new()
+        .with(Recipe::repair(&RepairTarget, EntitySelector::is_either(&taxonomy::Weapon, &taxonomy::Tool), 5)
+            .with_ingredient(&RepairMaterial, EntitySelector::is_one_of(vec![&Wood, &Metal, &Stone]), 1)
+        ).create(world);
 }
 
 #[test]
@@ -1,2 +1,4 @@
 pub mod spawning_component;
-pub use components::spawning_component::*;
\ No newline at end of file
+pub mod renewal_component;
+pub use components::spawning_component::*;
+pub use components::renewal_component::*;
@@ -0,0 +1,18 @@
+use common::prelude::*;
+use prelude::*;
+use data::entities::*;
+use logic;
+
+pub struct RenewalComponent {
+
+}
+
+impl RenewalComponent {
+    pub fn register(world : &mut World) {
+        world.add_callback(|world: &mut World, evt : &GameEventWrapper<GameEvent>| {
+            if let Some(GameEvent::TimeOfDayChanged { time_of_day : TimeOfDay::Dawn, .. }) = evt.if_starting() {
+                logic::harvest::renew_harvestables(world);
+            }
+        })
+    }
+}
@@ -16,4 +16,17 @@ pub fn position_of(entity : Entity, world : &WorldView) -> Option<AxialCoord> {
     } else {
         None
     }
-}
\ No newline at end of file
+}
+/// whether the entity goes by the given name, or is of a kind by that name
+pub fn has_name(entity : Entity, world : &WorldView, name : &str) -> bool {
+    world.data_opt::<IdentityData>(entity)
+        .map(|ident| ident.effective_name() == name || ident.kinds.iter().any(|k| k.name() == name))
+        .unwrap_or(false)
+}
+
+/// the first living character with the given name, as scenarios refer to characters by name
+pub fn character_named(world : &WorldView, name : &str) -> Option<Entity> {
+    world.entities_with_data::<CharacterData>()
+        .find(|(_, c)| c.is_alive() && c.name == name)
+        .map(|(ent, _)| *ent)
+}
@@ -32,6 +32,7 @@ extern crate noise;
 #[macro_use] extern crate serde_derive;
 #[macro_use] extern crate serde;
 extern crate ron;
+extern crate find_folder;
 
 extern crate num;
 extern crate cgmath;
@@ -33,6 +33,33 @@ pub fn apply_action(world: &mut World, character : Entity, action : Action) {
         ActionType::Harvest { from, harvestable, preserve_renewable } => {
             ::logic::harvest::harvest(world, character, from, harvestable, preserve_renewable, Some(action.ap.current))
         },
+        ActionType::Craft { base_recipe, ingredients } => {
+            if let Err(reason) = ::logic::crafting::craft_over_time(world, character, &ingredients, base_recipe, Some(action.ap.current)) {
+                warn!("Could not continue crafting: {}", reason);
+            }
+        },
         _ => error!("No action application has been set up for {:?}", action)
     }
-}
\ No newline at end of file
+}
+
+/// stops the character's active action, if any, keeping its progress so that it can be resumed by starting the same action again
+pub fn interrupt_action(world: &mut World, character : Entity) {
+    if let Some(action) = world.view().data_opt::<ActionData>(character).and_then(|a| a.active_action.clone()) {
+        world.modify(character, ActionData::active_action.set_to(None));
+        world.modify(character, ActionData::interrupted_action.set_to(Some(action.clone())));
+        world.add_event(GameEvent::ActionInterrupted { entity : character, action });
+    }
+}
+
+/// the progress already made toward the given action before it was interrupted, if it was. Taking the progress clears
+/// the interrupted action so it can only be resumed once
+pub fn take_interrupted_progress(world: &mut World, character : Entity, action_type : &ActionType) -> Option<i32> {
+    let interrupted = world.view().data_opt::<ActionData>(character).and_then(|a| a.interrupted_action.clone());
+    match interrupted {
+        Some(action) if &action.action_type == action_type => {
+            world.modify(character, ActionData::interrupted_action.set_to(None));
+            Some(action.ap.current)
+        },
+        _ => None
+    }
+}
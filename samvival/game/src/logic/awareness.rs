@@ -0,0 +1,101 @@
+use common::prelude::*;
+use prelude::*;
+use logic;
+use game::events::CoreEvent;
+use data::entities::{AwarenessData, VisibilityData, FactionData, PositionData, AllegianceData};
+
+
+/// What each faction knows of where its enemies are. A faction can only see what its observers can see, but it remembers
+/// where it last saw anyone that has since slipped out of view, until one of its own goes to that spot and finds them gone
+pub fn register(world: &mut World) {
+    world.add_callback(|world, event_w| {
+        match event_w.event {
+            GameEvent::Move { .. } | GameEvent::FactionTurn { .. } if event_w.is_ended() => {
+                let factions = world.view().entities_with_data::<FactionData>().map(|(faction,_)| *faction).collect_vec();
+                for faction in factions {
+                    update_awareness(world, faction);
+                }
+            },
+            _ => ()
+        }
+    });
+}
+
+/// whether the entity is currently standing somewhere the faction's observers can see
+pub fn can_see(view : &WorldView, faction : Entity, entity : Entity) -> bool {
+    view.data_opt::<PositionData>(entity).map(|pos| can_see_hex(view, faction, pos.hex)).unwrap_or(false)
+}
+
+/// the living enemies of the faction that its observers can currently see, not counting any that have surrendered
+pub fn visible_enemies(view : &WorldView, faction : Entity) -> Vec<Entity> {
+    view.entities_with_data::<CharacterData>()
+        .filter(|&(_, c)| c.is_alive())
+        .filter(|&(cref, _)| ! logic::morale::has_surrendered(view, *cref))
+        .filter(|&(cref, _)| logic::faction::is_enemy_of_faction(view, faction, *cref))
+        .filter(|&(cref, _)| can_see(view, faction, *cref))
+        .map(|(cref, _)| *cref)
+        .collect()
+}
+
+/// every enemy the faction knows of, at the position it's currently seen at, or where it was last seen if it's out of view
+pub fn known_enemy_positions(view : &WorldView, faction : Entity) -> Vec<(Entity, AxialCoord)> {
+    let mut res : Vec<(Entity, AxialCoord)> = visible_enemies(view, faction).into_iter()
+        .map(|e| (e, view.data::<PositionData>(e).hex))
+        .collect();
+
+    if let Some(awareness) = view.data_opt::<AwarenessData>(faction) {
+        for (enemy, hex) in &awareness.last_seen {
+            if ! res.iter().any(|(e,_)| e == enemy) && view.data::<CharacterData>(*enemy).is_alive() && ! logic::morale::has_surrendered(view, *enemy) {
+                res.push((*enemy, *hex));
+            }
+        }
+    }
+    res
+}
+
+pub fn last_seen_position(view : &WorldView, faction : Entity, entity : Entity) -> Option<AxialCoord> {
+    view.data_opt::<AwarenessData>(faction).and_then(|a| a.last_seen.get(&entity).cloned())
+}
+
+/// records where the faction can see its enemies now, and forgets any remembered positions it has been to and found empty
+pub fn update_awareness(world : &mut World, faction : Entity) {
+    let view = world.view();
+    if ! view.has_data::<AwarenessData>(faction) {
+        world.attach_data(faction, AwarenessData::default());
+    }
+
+    let mut modified = false;
+    let visible = visible_enemies(view, faction);
+    for enemy in &visible {
+        let hex = view.data::<PositionData>(*enemy).hex;
+        if last_seen_position(view, faction, *enemy) != Some(hex) {
+            world.modify_with_desc(faction, AwarenessData::last_seen.set_key_to(*enemy, hex), "spotted");
+            modified = true;
+        }
+    }
+
+    let forgotten = view.data_opt::<AwarenessData>(faction).map(|awareness| awareness.last_seen.iter()
+        .filter(|(enemy, hex)| ! visible.contains(enemy) && (has_member_at(view, faction, **hex) || ! view.data::<CharacterData>(**enemy).is_alive()))
+        .map(|(enemy, _)| *enemy)
+        .collect_vec()).unwrap_or_default();
+    for enemy in forgotten {
+        world.modify_with_desc(faction, AwarenessData::last_seen.remove_key(enemy), "lost track");
+        modified = true;
+    }
+
+    if modified {
+        world.add_event(CoreEvent::Recomputation);
+    }
+}
+
+/// whether one of the faction's own is standing on the given hex, having gone to look for themselves
+fn has_member_at(view : &WorldView, faction : Entity, hex : AxialCoord) -> bool {
+    view.entities_with_data::<CharacterData>()
+        .filter(|&(_, c)| c.is_alive())
+        .filter(|&(cref, _)| view.data_opt::<AllegianceData>(*cref).map(|a| a.faction == faction).unwrap_or(false))
+        .any(|(cref, _)| view.data::<PositionData>(*cref).hex == hex)
+}
+
+fn can_see_hex(view : &WorldView, faction : Entity, hex : AxialCoord) -> bool {
+    view.world_data_opt::<VisibilityData>().map(|vd| vd.visibility_for(faction).visible_hexes.contains(&hex)).unwrap_or(false)
+}
@@ -0,0 +1,67 @@
+use common::prelude::*;
+use prelude::*;
+use logic;
+use entities::actions::*;
+use entities::{ItemData, InventoryData, StructureData, TileAccessor};
+
+
+/// whether the given entity is a structure that has been put up somewhere, rather than one still being carried about
+pub fn is_built(view : &WorldView, entity : Entity) -> bool {
+    view.data_opt::<StructureData>(entity).map(|s| s.built_at.is_some()).unwrap_or(false)
+}
+
+/// the structures that have been built at the given location
+pub fn structures_at(view : &WorldView, at : AxialCoord) -> Vec<Entity> {
+    match view.tile_ent_opt(at) {
+        Some(tile) if view.has_data::<InventoryData>(tile.entity) => logic::item::items_in_inventory(view, tile.entity).into_iter()
+            .filter(|i| is_built(view, *i))
+            .collect(),
+        _ => Vec::new()
+    }
+}
+
+/// the structures the character is carrying that could be put up, including those packed away in bags
+pub fn unbuilt_structures(view : &WorldView, character : Entity) -> Vec<Entity> {
+    view.entities_with_data::<StructureData>()
+        .filter(|(s, data)| data.built_at.is_none() && logic::item::is_held_within(view, **s, character))
+        .map(|(s, _)| *s)
+        .collect()
+}
+
+pub fn can_build(view : &WorldView, character : Entity, structure : Entity, at : AxialCoord) -> (bool, String) {
+    let pos = view.character(character).position.hex;
+    let tiles = TileAccessor::new(view);
+    match view.data_opt::<StructureData>(structure) {
+        None => (false, strf("not something that can be built")),
+        Some(s) if s.built_at.is_some() => (false, strf("already built")),
+        Some(_) if ! logic::item::is_held_within(view, structure, character) => (false, strf("not being carried")),
+        Some(_) if pos.distance(&at) > 1.0 => (false, strf("too far away")),
+        Some(_) if ! tiles.tile_opt(at).map(|t| tiles.is_passable(&t)).unwrap_or(false) => (false, strf("nowhere to build it")),
+        Some(_) if ! structures_at(view, at).is_empty() => (false, strf("something is already built there")),
+        Some(s) if view.character(character).action_points.cur_value() < s.build_cost => (false, strf("not enough action points")),
+        Some(_) => (true, strf("Build"))
+    }
+}
+
+/// puts up a structure the character is carrying on the given hex, where it stays until it falls apart
+pub fn build(world : &mut World, character : Entity, structure : Entity, at : AxialCoord) {
+    let view = world.view();
+    let (can, reason) = can_build(view, character, structure, at);
+    if ! can {
+        warn!("Could not build {} at {:?}: {}", view.signifier(structure), at, reason);
+        return;
+    }
+
+    let cost = view.data::<StructureData>(structure).build_cost;
+    let action = Action { action_type : ActionType::Build { structure, at }, ap : Progress::new(cost, cost) };
+    world.start_event(GameEvent::ActionTaken { entity : character, action : action.clone() });
+
+    world.modify(character, CharacterData::action_points.reduce_by(cost));
+    if let Some(held_by) = view.data::<ItemData>(structure).in_inventory_of {
+        logic::item::remove_item_from_inventory(world, structure, held_by);
+    }
+    logic::item::place_item_in_world(world, structure, at);
+    world.modify(structure, StructureData::built_at.set_to(Some(at)));
+
+    world.end_event(GameEvent::ActionTaken { entity : character, action });
+}
@@ -18,9 +18,24 @@ pub fn apply_damage_to_character(world : &mut World, character : Entity, damage_
 
     world.add_event(GameEvent::DamageTaken { entity : character, damage_taken : damage_amount, damage_types : Vec::from(damage_types) });
 
-    if ! world.view().character(character).is_alive() {
-        logic::movement::remove_entity_from_world(world, character);
+    // taking a hit breaks a character's concentration on whatever longer task they were in the middle of
+    if damage_amount > 0 {
+        logic::action::interrupt_action(world, character);
+    }
 
-        world.add_event(GameEvent::EntityDied { entity : character });
+    if ! world.view().character(character).is_alive() {
+        handle_death(world, character);
+    } else {
+        logic::morale::on_damage_taken(world, character, damage_amount);
     }
+}
+
+/// takes a character whose health has run out out of the world, leaving behind whatever they were carrying
+pub fn handle_death(world : &mut World, character : Entity) {
+    let died_at = logic::movement::position_of(world.view(), character);
+    logic::loot::drop_loot(world, character);
+    logic::movement::remove_entity_from_world(world, character);
+
+    world.add_event(GameEvent::EntityDied { entity : character });
+    logic::morale::on_death(world, character, died_at);
 }
\ No newline at end of file
@@ -229,6 +229,11 @@ pub fn compute_strike_breakdown(world: &World, view: &WorldView, attacker_ref: E
     }
 
     ret.ap_cost_components.add(attack.ap_cost as i32, "weapon ap cost");
+    if let Some(weapon_attributes) = view.data_opt::<AttributeData>(weapon) {
+        for (attribute_name, cost) in weapon_attributes.effect_contributions(view.world_data::<AttributeDefinitions>(), |e| if let AttributeEffect::ApCost(c) = e { Some(*c) } else { None }) {
+            ret.ap_cost_components.add(cost, attribute_name);
+        }
+    }
 
     let attacker = view.character(attacker_ref);
     let attacker_combat = view.combat(attacker_ref);
@@ -260,8 +265,20 @@ pub fn compute_strike_breakdown(world: &World, view: &WorldView, attacker_ref: E
         target_breakdown.to_miss_components.add(defender_combat.defense_bonus, "defense");
         target_breakdown.to_miss_components.add(defender_combat.dodge_bonus, "dodge");
         target_breakdown.to_miss_components.add(defender_combat.block_bonus, "block");
+        let reflex_save = logic::perks::reflex_save_bonus(view, defender_ref);
+        if attack.pattern != HexPattern::Single && reflex_save > 0 {
+            target_breakdown.to_miss_components.add(reflex_save, "reflex save");
+        }
 
         target_breakdown.to_hit_components.add(attack.to_hit_bonus, "weapon accuracy");
+        if let Some(weapon_attributes) = view.data_opt::<AttributeData>(weapon) {
+            for (attribute_name, bonus) in weapon_attributes.effect_contributions(view.world_data::<AttributeDefinitions>(), |e| if let AttributeEffect::ToHit(b) = e { Some(*b) } else { None }) {
+                target_breakdown.to_hit_components.add(bonus, attribute_name);
+            }
+            for (attribute_name, bonus) in weapon_attributes.effect_contributions(view.world_data::<AttributeDefinitions>(), |e| if let AttributeEffect::Damage(b) = e { Some(*b) } else { None }) {
+                target_breakdown.damage_bonus_components.add(bonus, attribute_name);
+            }
+        }
 
         target_breakdown.to_miss_components.add(defender_terrain.cover as i32, "terrain cover");
         target_breakdown.to_miss_components.add(defender_vegetation.cover as i32, "vegetation cover");
@@ -276,6 +293,19 @@ pub fn compute_strike_breakdown(world: &World, view: &WorldView, attacker_ref: E
             _ => target_breakdown.damage_bonus_components.add(attacker_combat.ranged_damage_bonus, "base ranged damage bonus")
         }
         target_breakdown.damage_bonus_components.add(attack.damage_bonus, "weapon damage bonus");
+        if let Some(durability) = view.data_opt::<DurabilityData>(weapon) {
+            let wear_penalty = durability.wear_penalty();
+            if wear_penalty > 0 {
+                target_breakdown.to_hit_components.add(-wear_penalty, "worn weapon");
+                target_breakdown.damage_bonus_components.add(-wear_penalty, "worn weapon");
+            }
+        }
+        if ret.damage_types.contains(&DamageType::Fire) {
+            let weather = logic::weather::current_weather(view);
+            if weather.fire_damage_bonus() != 0 {
+                target_breakdown.damage_bonus_components.add(weather.fire_damage_bonus(), weather.name());
+            }
+        }
         ret.per_target_breakdowns.push(target_breakdown);
     }
 
@@ -288,6 +318,8 @@ pub fn handle_attack(world: &mut World, attacker: Entity, defender_ref: Entity,
 
         let attack_breakdown = compute_attack_breakdown(world, world_view, attacker, defender_ref, attack_ref, None, None);
 
+        logic::faction::provoke(world, attacker, defender_ref);
+
         world.start_event(GameEvent::Attack { attacker: attacker, defender: defender_ref });
 
 
@@ -302,8 +334,10 @@ pub fn handle_attack(world: &mut World, attacker: Entity, defender_ref: Entity,
             i if i <= 1 => Skill::Melee,
             _ => Skill::Ranged
         };
-        world.modify_with_desc(attacker, SkillData::skill_xp.add_to_key(attack_skill_type, 1), None);
-        world.modify_with_desc(attacker, CharacterData::stamina.reduce_by(Sext::of(1)), None);
+        let training_bonus = attack_ref.resolve_weapon(world_view, attacker).map(|w| logic::skill::weapon_xp_bonus(world_view, w)).unwrap_or(0);
+        logic::skill::grant_xp(world, attacker, attack_skill_type, 1 + training_bonus);
+        let stamina_cost = Sext::of(1) + item::encumbrance(world_view, attacker).stamina_cost_increase();
+        world.modify_with_desc(attacker, CharacterData::stamina.reduce_by(stamina_cost), None);
 
         world.end_event(GameEvent::Attack { attacker: attacker, defender: defender_ref });
 
@@ -349,8 +383,10 @@ pub fn handle_strike(world: &mut World, attacker_ref: Entity, primary_defender:
 
     let mut strike_results = HashMap::new();
 
+    // a weapon that broke on an earlier strike of the same attack can't be struck with again
+    let weapon_broken = view.data_opt::<DurabilityData>(weapon).map(|d| d.is_broken()).unwrap_or(false);
 
-    if attacker.is_alive() {
+    if attacker.is_alive() && ! weapon_broken {
         for target_breakdown in &strike.per_target_breakdowns {
             let defender_ref = target_breakdown.target;
             let defender = view.character(defender_ref);
@@ -414,10 +450,16 @@ pub fn handle_strike(world: &mut World, attacker_ref: Entity, primary_defender:
             for (target, strike_result) in &strike_results {
                 if strike_result.hit {
                     logic::character::apply_damage_to_character(world, *target, strike_result.damage_done as u32, &strike_result.damage_types);
+                } else {
+                    logic::skill::grant_xp(world, *target, Skill::Dodge, 1);
                 }
             }
             world.modify_with_desc(attacker_ref, MovementData::moves.set_to(Sext::of(0)), None);
 
+            if weapon != attacker_ref {
+                item::wear_item(world, weapon, 1);
+            }
+
             world.end_event(strike_event);
         }
     }
@@ -444,8 +486,8 @@ pub fn counters_for(world_view: &WorldView, defender_ref: Entity, countering_ref
     let defender_combat = world_view.combat(defender_ref);
     let countering = world_view.character(countering_ref);
 
-    // can't counter on ranged attacks
-    if defender.position.hex.distance(&countering.position.hex) > 1.0 {
+    // can't counter on ranged attacks, and those who've given up don't fight back at all
+    if defender.position.hex.distance(&countering.position.hex) > 1.0 || logic::morale::has_surrendered(world_view, defender_ref) {
         (Attack::default(), defender_ref, 0)
     } else {
         let counter_attack = counter_attack_ref_to_use(world_view, defender_ref);
@@ -3,18 +3,27 @@ use prelude::*;
 
 //use data::entities::ItemArchetype;
 use data::entities::recipes::*;
-use data::entities::{ItemArchetype, ItemData, Attack, WorthData, StackData, DamageType, Material, MaterialEffectSelector, MaterialEffect, MaterialEffectType};
+use data::entities::{ItemArchetype, ItemData, Attack, WorthData, StackData, DamageType, Material, MaterialEffectSelector, MaterialEffect, MaterialEffectType, DurabilityData, WeightData, InventoryData, ContainerData, StructureData, ItemQuality, QualityData, AttributeDefinitions};
 use data::archetype::EntityArchetype;
+use data::entities::actions::{Action, ActionType, ActionData};
 use logic;
+use logic::breakdown::Breakdown;
+use data::entities::common_entities::LookupSignifier;
 use std::collections::HashMap;
 
 
 pub struct CraftingBreakdown {
     pub recipe : Entity,
     pub result_identity : IdentityData,
-    pub effective_archetype : ItemArchetype
+    pub effective_archetype : ItemArchetype,
+    // added to a d20 roll to determine the quality of the crafted item
+    pub quality_bonus : Breakdown<i32>,
+    pub difficulty : i32,
+    pub ap_to_craft : Breakdown<i32>,
 }
 
+pub const BASE_CRAFTING_AP : i32 = 4;
+
 
 pub fn is_recipe_valid_with_ingredients(view : &WorldView, recipe : Entity, ingredients : &HashMap<Taxon, Vec<Entity>>) -> bool {
     let requirements = view.data::<Recipe>(recipe).effective_ingredients_by_kind(view);
@@ -32,6 +41,10 @@ pub fn is_recipe_valid_with_ingredients(view : &WorldView, recipe : Entity, ingr
 }
 
 pub fn can_entity_craft_recipe(view: &WorldView, crafter : Entity, recipe : Entity) -> Result<(), String> {
+    if ! logic::recipes::knows_recipe(view, crafter, recipe) {
+        return Err(strf("does not know how to make that"));
+    }
+
     let equipped = logic::item::equipped_items(view, crafter);
 
     let recipe_dat = view.data::<Recipe>(recipe);
@@ -81,10 +94,13 @@ pub fn compute_crafting_breakdown(world : &mut World, view : &WorldView, crafter
                 }
 
 
+                let mut crafted_weight = 0;
                 for (ingredient_type, ingredient_list) in ingredients {
                     if let Some(ingredient) = ingredient_list.first() {
                         let material_info = view.data_opt::<Material>(*ingredient);
                         if let Some(material_info) = material_info {
+                            crafted_weight += material_info.density * ingredient_list.len() as i32;
+
                             for (_,attack) in &mut arch.attacks {
                                 if ingredient_type.is_a(view, &taxonomy::ingredient_types::WeaponHeadIngredient) {
                                     match attack.primary_damage_type {
@@ -99,6 +115,13 @@ pub fn compute_crafting_breakdown(world : &mut World, view : &WorldView, crafter
                                 }
                             }
 
+                            // strong, hard materials hold up to use better than weak, soft ones
+                            if ingredient_type.is_a(view, &taxonomy::ingredient_types::WeaponHeadIngredient) || ingredient_type.is_a(view, &taxonomy::ingredient_types::HandleIngredient) {
+                                if let Some(durability) = &mut arch.durability {
+                                    *durability = (*durability + material_info.strength + material_info.hardness - 10).max(1);
+                                }
+                            }
+
                             for MaterialEffect(condition, effect) in &material_info.material_effects {
                                 if let MaterialEffectSelector::IngredientType(sel_ingredient_type) = condition {
                                     if ingredient_type.is_a(view, sel_ingredient_type) {
@@ -116,7 +139,19 @@ pub fn compute_crafting_breakdown(world : &mut World, view : &WorldView, crafter
 
 
 
-                let breakdown = CraftingBreakdown { recipe, effective_archetype : arch, result_identity : ident };
+                // the finished item weighs a fraction of the raw materials that went into it, the rest is worked away
+                // containers are mostly empty space, so their bulk sets a floor on how heavy they are
+                if crafted_weight > 0 {
+                    let min_weight = if arch.container.is_some() { arch.weight } else { 1 };
+                    arch.weight = (crafted_weight / 4).max(min_weight);
+                }
+
+                let difficulty = recipe_difficulty(view, recipe);
+                let quality_bonus = compute_quality_bonus(view, crafter, recipe, difficulty);
+                let mut ap_to_craft = Breakdown::new();
+                ap_to_craft.add(BASE_CRAFTING_AP, "base");
+                ap_to_craft.add(difficulty * 2, "recipe difficulty");
+                let breakdown = CraftingBreakdown { recipe, effective_archetype : arch, result_identity : ident, quality_bonus, difficulty, ap_to_craft };
                 Ok(breakdown)
             } else { Err(strf("non-item archetype based recipes not yet supported")) }
         } else { Err(strf("non-archetype based recipes not yet supported")) }
@@ -131,10 +166,57 @@ pub fn compute_crafting_breakdown(world : &mut World, view : &WorldView, crafter
     }
 }
 
+/// more involved recipes, and those that demand more skill to attempt at all, are harder to make well
+pub fn recipe_difficulty(view : &WorldView, recipe : Entity) -> i32 {
+    let recipe_dat = view.data::<Recipe>(recipe);
+    let ingredient_count : i32 = recipe_dat.effective_ingredients_by_kind(view).values().map(|i| i.amount_required).sum();
+    let highest_required_level = recipe_dat.skills_used.iter().filter_map(|s| s.required_level).max().unwrap_or(0);
+    ingredient_count + highest_required_level.max(0) * 2
+}
+
+pub fn compute_quality_bonus(view : &WorldView, crafter : Entity, recipe : Entity, difficulty : i32) -> Breakdown<i32> {
+    let recipe_dat = view.data::<Recipe>(recipe);
+    let mut bonus = Breakdown::new();
+    for skill_use in &recipe_dat.skills_used {
+        let lvl = logic::skill::skill_level(view, crafter, skill_use.skill);
+        bonus.add((skill_use.proportion * (lvl * 2)).as_i32(), format!("{} skill", skill_use.skill.name()));
+    }
+    bonus.add(-difficulty, "recipe difficulty");
+
+    let equipped = logic::item::equipped_items(view, crafter);
+    for (tool_sel, tool_use) in &recipe_dat.tools_used {
+        if let RecipeToolUse::DifficultWithout { quality_penalty : Some(penalty), .. } = tool_use {
+            if ! tool_sel.matches_any(view, &equipped) {
+                bonus.add(-penalty, format!("no {}", tool_sel.to_string_with_article(view)));
+            }
+        }
+    }
+    bonus
+}
+
+/// better (or worse) made items hit harder, harvest more, and last longer
+fn apply_quality(arch : &mut ItemArchetype, ident : &mut IdentityData, quality : ItemQuality) {
+    let bonus = quality.bonus();
+    for (_, attack) in &mut arch.attacks {
+        attack.to_hit_bonus += bonus;
+        attack.damage_bonus += bonus;
+    }
+    if let Some(tool_data) = &mut arch.tool_data {
+        tool_data.tool_harvest_fixed_bonus += bonus;
+        tool_data.tool_speed_bonus += bonus / 2;
+    }
+    if let Some(durability) = &mut arch.durability {
+        *durability = (*durability + bonus * 5).max(1);
+    }
+    if quality != ItemQuality::Standard {
+        ident.name = Some(format!("{} {}", quality.name(), ident.effective_name()));
+    }
+}
+
 fn apply_material_effect(view: &WorldView, arch : &mut ItemArchetype, effect : &MaterialEffectType) {
     match effect {
         MaterialEffectType::WeaponAttribute(attribute_type, amount) => {
-            arch.attributes.increase_attribute(attribute_type, *amount)
+            arch.attributes.increase_attribute(view.world_data::<AttributeDefinitions>(), attribute_type, *amount)
         },
         MaterialEffectType::ToHitBonus(attack_selector, bonus) => {
             for (ident, attack) in &mut arch.attacks {
@@ -154,9 +236,25 @@ fn apply_material_effect(view: &WorldView, arch : &mut ItemArchetype, effect : &
 }
 
 pub fn craft(world : &mut World, crafter : Entity, ingredients : &HashMap<Taxon, Vec<Entity>>, base_recipe : Entity) -> Result<Entity, String> {
-    let breakdown = compute_crafting_breakdown(world, world.view(), crafter, ingredients, base_recipe)?;
+    if let RecipeFlavor::Repair { .. } = world.view().data::<Recipe>(base_recipe).flavor {
+        return repair(world, crafter, ingredients, base_recipe);
+    }
+
+    let view = world.view();
+    let mut breakdown = compute_crafting_breakdown(world, view, crafter, ingredients, base_recipe)?;
+
+    let mut rng = world.random(4417);
+    let quality = ItemQuality::for_roll(DicePool::of(1, 20).roll(&mut rng).total_result as i32 + breakdown.quality_bonus.total);
+    apply_quality(&mut breakdown.effective_archetype, &mut breakdown.result_identity, quality);
 
     let crafted = create_item_from_archetype(world, &breakdown.effective_archetype, &breakdown.result_identity);
+    world.attach_data(crafted, QualityData { quality });
+
+    // harder crafts teach more
+    for skill_use in &view.data::<Recipe>(breakdown.recipe).skills_used {
+        let xp = (skill_use.proportion * (1 + breakdown.difficulty / 2)).as_i32().max(1);
+        logic::skill::grant_xp(world, crafter, skill_use.skill, xp);
+    }
 
     for ingredient_list in ingredients.values() {
         for ingredient in ingredient_list {
@@ -165,9 +263,52 @@ pub fn craft(world : &mut World, crafter : Entity, ingredients : &HashMap<Taxon,
     }
 
 
+    world.add_event(GameEvent::ItemCrafted { item : crafted, crafter, quality });
+
     Ok(crafted)
 }
 
+/// crafting takes time, the crafter puts as many action points as they have toward the item each turn until it is finished.
+/// Returns the crafted item once complete, which is placed in the crafter's inventory. Repairs are quick, and happen immediately
+pub fn craft_over_time(world : &mut World, crafter : Entity, ingredients : &HashMap<Taxon, Vec<Entity>>, base_recipe : Entity, progress : Option<i32>) -> Result<Option<Entity>, String> {
+    let view = world.view();
+    if let RecipeFlavor::Repair { .. } = view.data::<Recipe>(base_recipe).flavor {
+        return craft(world, crafter, ingredients, base_recipe).map(Some);
+    }
+
+    let breakdown = compute_crafting_breakdown(world, view, crafter, ingredients, base_recipe)?;
+    let action_type = ActionType::Craft { base_recipe, ingredients : ingredients.clone() };
+
+    let starting = progress.is_none();
+    // picking a craft back up after being interrupted continues from where it was left off
+    let ap_so_far = match progress {
+        Some(p) => p,
+        None => logic::action::take_interrupted_progress(world, crafter, &action_type).unwrap_or(0),
+    };
+    let ap_required = breakdown.ap_to_craft.total;
+    let ap_remaining = view.data::<CharacterData>(crafter).action_points.cur_value();
+
+    if ap_required > ap_remaining + ap_so_far {
+        world.modify(crafter, CharacterData::action_points.reduce_by(ap_remaining));
+        let in_progress_action = Action { action_type, ap : Progress::new(ap_remaining + ap_so_far, ap_required) };
+        world.modify(crafter, ActionData::active_action.set_to(Some(in_progress_action.clone())));
+        let event = GameEvent::ActionTaken { entity : crafter, action : in_progress_action };
+        if starting { world.start_event(event); } else { world.continue_event(event); }
+        Ok(None)
+    } else {
+        world.modify(crafter, CharacterData::action_points.reduce_by(ap_required - ap_so_far));
+        let crafted = craft(world, crafter, ingredients, base_recipe)?;
+        logic::item::put_item_in_inventory(world, crafted, crafter);
+
+        if world.data::<ActionData>(crafter).active_action.is_some() {
+            world.modify(crafter, ActionData::active_action.set_to(None));
+        }
+        let event = GameEvent::ActionTaken { entity : crafter, action : Action { action_type, ap : Progress::new(ap_required, ap_required) } };
+        if starting { world.add_event(event); } else { world.end_event(event); }
+        Ok(Some(crafted))
+    }
+}
+
 pub fn create_item_from_archetype(world : &mut World, archetype : &ItemArchetype, ident : &IdentityData) -> Entity {
     EntityBuilder::new()
         .with(ItemData {
@@ -184,9 +325,67 @@ pub fn create_item_from_archetype(world : &mut World, archetype : &ItemArchetype
         .with(ident.clone())
         .with(WorthData::new(archetype.worth))
         .with_opt(archetype.tool_data.clone())
+        .with(WeightData::new(archetype.weight))
+        .with_opt(archetype.durability.map(DurabilityData::new))
+        .with_opt(archetype.container.as_ref().map(|c| InventoryData { items : Vec::new(), inventory_size : Some(c.slots) }))
+        .with_opt(archetype.container.clone())
+        .with_opt(archetype.structure.clone())
+        .with(archetype.attributes.clone())
         .create(world)
 }
 
+pub struct RepairBreakdown {
+    pub recipe : Entity,
+    pub item : Entity,
+    pub materials : Vec<Entity>,
+    pub durability_restored : Breakdown<i32>,
+}
+
+pub fn compute_repair_breakdown(view : &WorldView, crafter : Entity, ingredients : &HashMap<Taxon, Vec<Entity>>, recipe : Entity) -> Result<RepairBreakdown, String> {
+    let recipe_dat = view.data::<Recipe>(recipe);
+    if let RecipeFlavor::Repair { item_ingredient, durability_per_material } = &recipe_dat.flavor {
+        if ! is_recipe_valid_with_ingredients(view, recipe, ingredients) {
+            return Err(strf("Not all ingredients have been supplied"));
+        }
+        can_entity_craft_recipe(view, crafter, recipe)?;
+
+        let item = ingredients.get(item_ingredient).and_then(|i| i.first().cloned()).ok_or_else(|| strf("nothing to repair"))?;
+        let durability = view.data_opt::<DurabilityData>(item).ok_or_else(|| format!("{} cannot be repaired", view.signifier(item)))?;
+        let missing = durability.durability.max_value() - durability.durability.cur_value();
+        if missing <= 0 {
+            return Err(format!("{} is not in need of repair", view.signifier(item)));
+        }
+
+        let materials : Vec<Entity> = ingredients.iter()
+            .filter(|(kind, _)| *kind != item_ingredient)
+            .flat_map(|(_, ents)| ents.iter().cloned())
+            .collect();
+
+        let mut durability_restored = Breakdown::new();
+        durability_restored.add(materials.len() as i32 * durability_per_material, "materials");
+        if durability_restored.total > missing {
+            durability_restored.add(missing - durability_restored.total, "fully repaired");
+        }
+
+        Ok(RepairBreakdown { recipe, item, materials, durability_restored })
+    } else { Err(strf("not a repair recipe")) }
+}
+
+/// repairs the item supplied to a repair recipe, consuming the materials used but leaving the item itself in place
+pub fn repair(world : &mut World, crafter : Entity, ingredients : &HashMap<Taxon, Vec<Entity>>, recipe : Entity) -> Result<Entity, String> {
+    let breakdown = compute_repair_breakdown(world.view(), crafter, ingredients, recipe)?;
+
+    let durability_restored = breakdown.durability_restored.total;
+    world.modify_with_desc(breakdown.item, DurabilityData::durability.recover_by(durability_restored), "repaired");
+    for material in &breakdown.materials {
+        logic::item::destroy_item(world, *material);
+    }
+
+    world.add_event(GameEvent::ItemRepaired { item : breakdown.item, repairer : crafter, durability_restored });
+
+    Ok(breakdown.item)
+}
+
 pub fn craft_without_materials(world : &mut World, archetype : Entity) -> Entity {
     let view = world.view();
     create_item_from_archetype(world, view.data::<ItemArchetype>(archetype), view.data::<IdentityData>(archetype))
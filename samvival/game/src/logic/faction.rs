@@ -1,13 +1,64 @@
+use game::prelude::*;
 use game::WorldView;
 use game::Entity;
 use data::entities::CharacterStore;
+use data::entities::{Relationship, RelationshipData, FactionData};
+use data::events::GameEvent;
+use game::events::CoreEvent;
 
 
+pub fn relationship(view : &WorldView, faction_a : Entity, faction_b : Entity) -> Relationship {
+    match view.world_data_opt::<RelationshipData>() {
+        Some(relationships) => relationships.relationship(faction_a, faction_b),
+        None => if faction_a == faction_b { Relationship::Allied } else { Relationship::Hostile }
+    }
+}
+
+pub fn relationship_between(view : &WorldView, a : Entity, b : Entity) -> Relationship {
+    relationship(view, view.character(a).allegiance.faction, view.character(b).allegiance.faction)
+}
+
 pub fn is_enemy(view : &WorldView, a : Entity, b : Entity) -> bool {
-    view.character(a).allegiance.faction != view.character(b).allegiance.faction
+    relationship_between(view, a, b) == Relationship::Hostile
+}
+
+pub fn is_ally(view : &WorldView, a : Entity, b : Entity) -> bool {
+    relationship_between(view, a, b) == Relationship::Allied
 }
 
 pub fn is_enemy_of_faction(view : &WorldView, faction : Entity, entity : Entity) -> bool {
-    view.character(entity).allegiance.faction != faction
+    relationship(view, faction, view.character(entity).allegiance.faction) == Relationship::Hostile
 }
 
+/// the given faction along with every faction allied to it
+pub fn allied_factions(view : &WorldView, faction : Entity) -> Vec<Entity> {
+    view.entities_with_data::<FactionData>()
+        .map(|(f, _)| *f)
+        .filter(|f| relationship(view, faction, *f) == Relationship::Allied)
+        .collect()
+}
+
+pub fn set_relationship(world : &mut World, faction_a : Entity, faction_b : Entity, relationship : Relationship) {
+    if faction_a == faction_b || self::relationship(world.view(), faction_a, faction_b) == relationship {
+        return;
+    }
+    world.modify_world(RelationshipData::relationships.set_key_to(RelationshipData::key(faction_a, faction_b), relationship), "relationship changed");
+    world.add_event(GameEvent::RelationshipChanged { faction_a, faction_b, relationship });
+}
+
+/// sets how the faction stands towards any other it has no particular relationship with, wildlife that keeps itself to
+/// itself would be neutral, for example
+pub fn set_default_relationship(world : &mut World, faction : Entity, relationship : Relationship) {
+    world.modify_world(RelationshipData::default_relationships.set_key_to(faction, relationship), "default relationship");
+    world.add_event(CoreEvent::Recomputation);
+}
+
+/// an attack on a neutral faction turns them hostile towards the attacker's
+pub fn provoke(world : &mut World, attacker : Entity, defender : Entity) {
+    let view = world.view();
+    if relationship_between(view, attacker, defender) == Relationship::Neutral {
+        let attacker_faction = view.character(attacker).allegiance.faction;
+        let defender_faction = view.character(defender).allegiance.faction;
+        set_relationship(world, attacker_faction, defender_faction, Relationship::Hostile);
+    }
+}
@@ -6,7 +6,10 @@ use logic::breakdown::Breakdown;
 use entities::ItemData;
 use entities::actions::*;
 use entities::InventoryData;
-use entities::{ToolData, WorthData, EntityMetadata};
+use entities::attributes::{AttributeData, AttributeEffect, AttributeDefinitions};
+use entities::{ToolData, WorthData, EntityMetadata, DurabilityData};
+use entities::{EffectApplication, Taxon, taxonomy};
+use std::collections::HashMap;
 
 pub fn harvestables_at(world : &WorldView, coord : AxialCoord) -> Vec<Entity> {
     world.terrain(coord).harvestables.values()
@@ -81,6 +84,16 @@ pub fn compute_harvest_breakdown(world: &World, view: &WorldView, character : En
                 ap_to_harvest.add(-tool.tool_speed_bonus, "tool speed");
                 dice_amount_harvested.add(tool.tool_harvest_dice_bonus.clone(), "tool bonus");
                 fixed_amount_harvested.add(tool.tool_harvest_fixed_bonus, "tool bonus");
+                if let Some(wear_penalty) = tool_opt.and_then(|t| world.data_opt::<DurabilityData>(t)).map(|d| d.wear_penalty()) {
+                    if wear_penalty > 0 {
+                        ap_to_harvest.add(wear_penalty, "worn tool");
+                    }
+                }
+            }
+            if let Some(tool_attributes) = tool_opt.and_then(|t| world.data_opt::<AttributeData>(t)) {
+                for (attribute_name, bonus) in tool_attributes.effect_contributions(view.world_data::<AttributeDefinitions>(), |e| if let AttributeEffect::HarvestAmount(b) = e { Some(*b) } else { None }) {
+                    fixed_amount_harvested.add(bonus, attribute_name);
+                }
             }
 
             let mut harvest_limit = harvestable_data.amount.cur_value().as_i32();
@@ -135,7 +148,10 @@ pub fn harvest(world: &mut World, character : Entity, from : AxialCoord, harvest
 
             // Harvesting can be a multi-turn action, so we first want to figure out if we're actually resolving the harvest, or just making progress
             // toward its eventual completion
-            let ap_so_far = progress.unwrap_or(0);
+            let ap_so_far = match progress {
+                Some(p) => p,
+                None => logic::action::take_interrupted_progress(world, character, &action_type).unwrap_or(0),
+            };
             let ap_required = breakdown.ap_to_harvest.total;
             let ap_remaining = cdata.action_points.cur_value();
             // if our progress so far, plus the ap we can contribute this turn are still less than what's required, update our action-in-progress
@@ -161,7 +177,13 @@ pub fn harvest(world: &mut World, character : Entity, from : AxialCoord, harvest
                     .min(breakdown.harvest_limit)
                     .min(breakdown.inventory_limit.unwrap_or(1000000));
                 world.modify(harvestable, Harvestable::amount.reduce_by(Sext::of(amount_harvested)));
+                if amount_harvested > 0 && harvestable_data.amount.cur_value() <= Sext::of(amount_harvested) {
+                    deplete(world, harvestable, tile_ent.entity);
+                }
 
+                if let Some(tool) = breakdown.tool.filter(|t| world.has_data::<ToolData>(*t)) {
+                    logic::item::wear_item(world, tool, 1);
+                }
 
 
                 let resource = harvestable_data.resource;
@@ -172,6 +194,10 @@ pub fn harvest(world: &mut World, character : Entity, from : AxialCoord, harvest
                     logic::item::put_item_in_inventory(world, new_entity, character);
                 }
 
+                for skill in &harvestable_data.skills_used {
+                    logic::skill::grant_xp(world, character, *skill, 1);
+                }
+
                 world.end_event(GameEvent::EntityHarvested { harvester : character, harvestable, harvested_from : tile_ent.entity, amount : Some(amount_harvested), resource : harvestable_data.resource });
 
                 let completed_action = Action { action_type : action_type.clone(), ap : Progress::new(ap_required, ap_required) };
@@ -185,6 +211,92 @@ pub fn harvest(world: &mut World, character : Entity, from : AxialCoord, harvest
     } else { error!("tried to harvest from non tile {}", from) }
 }
 
+fn key_of(harvestables : &HashMap<String, Entity>, harvestable : Entity) -> Option<String> {
+    harvestables.iter().find(|(_, h)| **h == harvestable).map(|(k, _)| k.clone())
+}
+
+/// applies the depletion behavior of a harvestable that has run out to the tile it was harvested from. What was changed
+/// is recorded on the harvestable so that it can be reverted if the harvestable renews
+pub fn deplete(world : &mut World, harvestable : Entity, tile : Entity) {
+    let view = world.view();
+    let harvestable_data = view.data::<Harvestable>(harvestable);
+    if harvestable_data.depletion.is_some() {
+        return;
+    }
+
+    let vegetation_key = view.data_opt::<VegetationData>(tile).and_then(|v| key_of(&v.harvestables, harvestable));
+    let terrain_key = view.data_opt::<TerrainData>(tile).and_then(|t| key_of(&t.harvestables, harvestable));
+
+    let applied_modifiers = match &harvestable_data.on_depletion {
+        DepletionBehavior::None => Vec::new(),
+        DepletionBehavior::Remove => if let Some(key) = vegetation_key {
+            vec![world.modify_with_desc(tile, VegetationData::harvestables.remove_key(key), "depleted")]
+        } else if let Some(key) = terrain_key {
+            vec![world.modify_with_desc(tile, TerrainData::harvestables.remove_key(key), "depleted")]
+        } else {
+            Vec::new()
+        },
+        DepletionBehavior::RemoveLayer => if vegetation_key.is_some() {
+            vec![
+                world.modify_with_desc(tile, VegetationData::kind.set_to(Taxon::of(&taxonomy::vegetation::Grassland)), "cleared"),
+                world.modify_with_desc(tile, VegetationData::harvestables.set_to(HashMap::new()), "cleared"),
+                world.modify_with_desc(tile, VegetationData::move_cost.set_to(Sext::zero()), "cleared"),
+                world.modify_with_desc(tile, VegetationData::cover.set_to(0), "cleared"),
+            ]
+        } else {
+            warn!("Only the vegetation layer of a tile can be removed on depletion, {:?} is not part of it", view.signifier(harvestable));
+            Vec::new()
+        },
+        DepletionBehavior::Custom(effects) => effects.iter()
+            .flat_map(|effect| effect.resolve(view).clone().apply(world, tile).applied_modifiers)
+            .collect()
+    };
+
+    world.modify(harvestable, Harvestable::depletion.set_to(Some(Depletion { tile, effects : EffectApplication { applied_modifiers } })));
+}
+
+/// regrows every renewable harvestable by a day's worth, according to the current season and the fertility of the
+/// tile it grows on. Harvestables that were depleted have their depletion reverted once they have fully regrown
+pub fn renew_harvestables(world : &mut World) {
+    let view = world.view();
+    let season = logic::time::season(view);
+
+    let mut tile_for_harvestable : HashMap<Entity, Entity> = HashMap::new();
+    for (tile, terrain) in view.entities_with_data::<TerrainData>() {
+        for harvestable in terrain.harvestables.values() {
+            tile_for_harvestable.insert(*harvestable, *tile);
+        }
+    }
+    for (tile, vegetation) in view.entities_with_data::<VegetationData>() {
+        for harvestable in vegetation.harvestables.values() {
+            tile_for_harvestable.insert(*harvestable, *tile);
+        }
+    }
+
+    for (harvestable, harvestable_data) in view.entities_with_data::<Harvestable>() {
+        if let Some(renew_rate) = &harvestable_data.renew_rate {
+            let tile = harvestable_data.depletion.as_ref().map(|d| d.tile).or_else(|| tile_for_harvestable.get(harvestable).cloned());
+            if let Some(tile) = tile {
+                let fertility = view.data_opt::<TerrainData>(tile).map(|t| t.fertility).unwrap_or(0);
+                let amount = renew_rate.amount_per_day(season, fertility);
+                if amount > Sext::zero() && ! harvestable_data.is_fully_grown() {
+                    world.modify_with_desc(*harvestable, Harvestable::amount.recover_by(amount), "renewed");
+
+                    let new_amount = harvestable_data.amount.cur_value() + amount;
+                    if let Some(depletion) = &harvestable_data.depletion {
+                        if new_amount >= harvestable_data.amount.max_value() {
+                            depletion.effects.disable(world, depletion.tile);
+                            world.modify(*harvestable, Harvestable::depletion.set_to(None));
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    world.add_event(GameEvent::HarvestablesRenewed);
+}
+
 pub fn harvest_range(view : &WorldView, character : Entity) -> i32 {
     1
 }
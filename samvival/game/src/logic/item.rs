@@ -10,6 +10,10 @@ use game::reflect::*;
 use data::entities::inventory::EquipmentData;
 //use data::entities::modifiers::UnequipItemMod;
 use data::entities::item::ItemData;
+use data::entities::item::DurabilityData;
+use data::entities::item::WeightData;
+use data::entities::Material;
+use data::entities::movement::MovementData;
 use common::hex::*;
 use data::entities::inventory::*;
 use data::entities::{StackWith, EntityMetadata};
@@ -20,6 +24,12 @@ use data::entities::common_entities::LookupSignifier;
 
 pub fn put_item_in_inventory(world: &mut World, item : Entity, inventory : Entity) -> bool {
     let view = world.view();
+    // containers can go in one another, but never inside themselves, however deeply
+    if item == inventory || is_held_within(view, inventory, item) {
+        trace!("Could not put {} in {}, a container cannot end up inside itself", view.signifier(item), view.signifier(inventory));
+        return false;
+    }
+
     if let Some(stack) = view.data_opt::<StackData>(item) {
         stack.entities.iter().all(|e| put_item_in_inventory(world, item, inventory))
     } else {
@@ -33,6 +43,8 @@ pub fn put_item_in_inventory(world: &mut World, item : Entity, inventory : Entit
             world.modify(item, ItemData::in_inventory_of.set_to(Some(inventory)));
             world.modify(*stack, StackData::entities.append(item));
             world.add_event(GameEvent::AddToInventory { item, to_inventory: inventory });
+            update_holder_encumbrance(world, inventory);
+            logic::recipes::discover_recipes(world, inventory);
             true
         } else {
             let inv_limit = inv_data.inventory_size.unwrap_or(1000000);
@@ -47,6 +59,8 @@ pub fn put_item_in_inventory(world: &mut World, item : Entity, inventory : Entit
                 world.modify(item, ItemData::in_inventory_of.set_to(Some(inventory)));
                 world.modify(inventory, InventoryData::items.append(item_to_add));
                 world.add_event(GameEvent::AddToInventory { item, to_inventory: inventory });
+                update_holder_encumbrance(world, inventory);
+                logic::recipes::discover_recipes(world, inventory);
                 true
             } else {
                 trace!("Could not put in inventory of {}, inventory size was only {}", view.signifier(inventory), inv_limit);
@@ -169,6 +183,130 @@ pub fn remove_item_from_inventory(world: &mut World, item : Entity, inventory :
             }
         }
     }
+    update_holder_encumbrance(world, inventory);
+}
+
+/// the weight of an item, or of all the items in a stack. Items without an explicit weight weigh as much as the
+/// material they are made of is dense
+pub fn item_weight(view : &WorldView, item : Entity) -> i32 {
+    if let Some(stack) = view.data_opt::<StackData>(item) {
+        stack.entities.iter().map(|e| item_weight(view, *e)).sum()
+    } else {
+        let own_weight = if let Some(weight) = view.data_opt::<WeightData>(item) {
+            weight.weight
+        } else if let Some(material) = view.data_opt::<Material>(item) {
+            material.density.max(1)
+        } else {
+            WeightData::default().weight
+        };
+        // containers weigh as much as everything in them
+        own_weight + if view.has_data::<ContainerData>(item) { inventory_weight(view, item) } else { 0 }
+    }
+}
+
+pub fn inventory_weight(view : &WorldView, inventory : Entity) -> i32 {
+    view.data_opt::<InventoryData>(inventory).map(|inv| inv.items.iter().map(|i| item_weight(view, *i)).sum()).unwrap_or(0)
+}
+
+pub const BASE_CARRY_CAPACITY : i32 = 4;
+
+/// how much weight a character can carry before it starts to slow them down, hardier characters can carry more
+pub fn carry_capacity(view : &WorldView, character : Entity) -> i32 {
+    if let Some(cdata) = view.data_opt::<CharacterData>(character) {
+        let container_bonus : i32 = carried_containers(view, character).iter()
+            .map(|c| view.data::<ContainerData>(*c).carry_capacity_bonus)
+            .sum();
+        BASE_CARRY_CAPACITY + cdata.stamina.max_value().as_i32() * 2 + cdata.health.max_value() / 10 + container_bonus
+    } else {
+        0
+    }
+}
+
+pub fn encumbrance(view : &WorldView, character : Entity) -> Encumbrance {
+    view.data_opt::<EncumbranceData>(character).map(|e| e.encumbrance).unwrap_or_default()
+}
+
+/// recomputes how encumbered a character is by what they are carrying, replacing the move speed penalty for their
+/// previous level of encumbrance with the penalty for the new one
+pub fn update_encumbrance(world : &mut World, character : Entity) {
+    let view = world.view();
+    if let (Some(encumbrance_data), true) = (view.data_opt::<EncumbranceData>(character), view.has_data::<CharacterData>(character)) {
+        let new_encumbrance = Encumbrance::for_load(inventory_weight(view, character), carry_capacity(view, character));
+        if new_encumbrance != encumbrance_data.encumbrance {
+            if let Some(prev_modifier) = encumbrance_data.move_speed_modifier {
+                world.disable_modifier(prev_modifier);
+            }
+
+            let penalty = new_encumbrance.move_speed_penalty();
+            let new_modifier = if penalty > Sext::zero() {
+                Some(world.modify_with_desc(character, MovementData::move_speed.sub(penalty), new_encumbrance.name()))
+            } else {
+                None
+            };
+
+            world.modify_with_desc(character, EncumbranceData::encumbrance.set_to(new_encumbrance), None);
+            world.modify_with_desc(character, EncumbranceData::move_speed_modifier.set_to(new_modifier), None);
+            world.add_event(GameEvent::EncumbranceChanged { character, encumbrance : new_encumbrance });
+        }
+    }
+}
+
+/// updates the encumbrance of whoever holds the given inventory, if the inventory is a container being carried then
+/// whoever is carrying it, however many bags deep, is the one whose load has changed
+fn update_holder_encumbrance(world : &mut World, inventory : Entity) {
+    update_encumbrance(world, inventory);
+    let mut holder = world.view().data_opt::<ItemData>(inventory).and_then(|i| i.in_inventory_of);
+    while let Some(current) = holder {
+        update_encumbrance(world, current);
+        holder = world.view().data_opt::<ItemData>(current).and_then(|i| i.in_inventory_of);
+    }
+}
+
+/// whether the given entity is in the holder's inventory, or in a container that is, and so on
+pub fn is_held_within(view : &WorldView, entity : Entity, holder : Entity) -> bool {
+    let mut current = view.data_opt::<ItemData>(entity).and_then(|i| i.in_inventory_of);
+    while let Some(inventory) = current {
+        if inventory == holder {
+            return true;
+        }
+        current = view.data_opt::<ItemData>(inventory).and_then(|i| i.in_inventory_of);
+    }
+    false
+}
+
+/// the containers in the given inventory, followed by those in each of them in turn
+fn containers_within(view : &WorldView, inventory : Entity) -> Vec<Entity> {
+    let mut containers : Vec<Entity> = items_in_inventory(view, inventory).into_iter().filter(|i| view.has_data::<ContainerData>(*i)).collect();
+    let mut index = 0;
+    while index < containers.len() {
+        let nested = items_in_inventory(view, containers[index]).into_iter().filter(|i| view.has_data::<ContainerData>(*i)).collect_vec();
+        containers.extend(nested);
+        index += 1;
+    }
+    containers
+}
+
+/// containers in the character's own inventory, along with any bags packed inside them
+pub fn carried_containers(view : &WorldView, character : Entity) -> Vec<Entity> {
+    if view.has_data::<InventoryData>(character) {
+        containers_within(view, character)
+    } else {
+        Vec::new()
+    }
+}
+
+/// containers that have been set down on the character's hex or one next to it, along with anything packed inside them
+pub fn ground_containers_near(view : &WorldView, character : Entity) -> Vec<Entity> {
+    let pos = view.character(character).position.hex;
+    let mut containers = Vec::new();
+    for hex in vec![pos].extended_by(pos.neighbors_vec()) {
+        if let Some(tile) = view.tile_ent_opt(hex) {
+            if view.has_data::<InventoryData>(tile.entity) {
+                containers.extend(containers_within(view, tile.entity));
+            }
+        }
+    }
+    containers
 }
 
 pub fn equip_item(world: &mut World, item : Entity, character : Entity, trigger_event : bool) {
@@ -317,6 +455,32 @@ pub fn destroy_item(world: &mut World, item : Entity) -> bool {
     } else { error!("Attempted to destroy an item that was not an item: {}", view.signifier(item)); false }
 }
 
+/// wears down the durability of an item through use, breaking it if it has no durability left. Items without durability
+/// never wear out. Returns true if the item broke
+pub fn wear_item(world: &mut World, item : Entity, amount : i32) -> bool {
+    if let Some(durability) = world.view().data_opt::<DurabilityData>(item) {
+        if durability.is_broken() {
+            return false;
+        }
+        let remaining = durability.durability.cur_value() - amount;
+        world.modify_with_desc(item, DurabilityData::durability.reduce_by(amount), "wear");
+        if remaining <= 0 {
+            break_item(world, item);
+            true
+        } else { false }
+    } else { false }
+}
+
+/// destroys an item that has worn out, unequipping it from whoever held it
+pub fn break_item(world: &mut World, item : Entity) {
+    let owner = world.view().data::<ItemData>(item).in_inventory_of;
+    if let Some(owner) = owner {
+        unequip_item(world, item, owner, true);
+    }
+    world.add_event(GameEvent::ItemBroken { item, owner });
+    destroy_item(world, item);
+}
+
 /// returns the item given or, if it is a stack of items, the first item in the stack
 pub fn item_or_first_in_stack(view : &WorldView, item : Entity) -> Entity {
     if let Some(stack_data) = view.data_opt::<StackData>(item) {
@@ -0,0 +1,84 @@
+use common::prelude::*;
+use prelude::*;
+use logic;
+use entities::actions::*;
+use entities::InventoryData;
+use entities::{EntityMetadata, LootTableData, PositionData};
+use rand::Rng;
+
+pub const PICK_UP_AP_COST : i32 = 1;
+
+/// the items lying on the ground at the given location, if any. Anything that has been built there isn't lying about
+/// and is left out
+pub fn ground_items_at(view : &WorldView, at : AxialCoord) -> Vec<Entity> {
+    match view.tile_ent_opt(at) {
+        Some(tile) if view.has_data::<InventoryData>(tile.entity) => logic::item::items_in_inventory(view, tile.entity).into_iter()
+            .filter(|i| ! logic::building::is_built(view, *i))
+            .collect(),
+        _ => Vec::new()
+    }
+}
+
+/// drops everything a character is carrying or has equipped onto the tile they occupy, along with anything
+/// their loot table produces. Intended to be called when a character dies, before they are removed from the world
+pub fn drop_loot(world : &mut World, character : Entity) {
+    let view = world.view();
+    if let Some(pos) = view.data_opt::<PositionData>(character).map(|p| p.hex) {
+        if view.has_data::<InventoryData>(character) {
+            for item in logic::item::items_in_inventory(view, character) {
+                logic::item::remove_item_from_inventory(world, item, character);
+                logic::item::place_item_in_world(world, item, pos);
+            }
+        }
+
+        if let Some(loot_table) = view.data_opt::<LootTableData>(character) {
+            let mut rng = world.random(7411);
+            for drop in &loot_table.drops {
+                if rng.gen_range(0, 100) < drop.chance {
+                    let amount = drop.amount.roll(&mut rng).total_result;
+                    for _ in 0 .. amount {
+                        let new_entity = world.clone_entity(drop.item);
+                        world.attach_data::<EntityMetadata>(new_entity, EntityMetadata { archetype : drop.item });
+                        world.add_event(GameEvent::EntityCreated { entity : new_entity });
+                        logic::item::place_item_in_world(world, new_entity, pos);
+                    }
+                }
+            }
+        }
+    }
+}
+
+pub fn can_pick_up(view : &WorldView, character : Entity, from : AxialCoord) -> (bool, String) {
+    let pos = view.character(character).position.hex;
+    if pos.distance(&from) > 1.0 {
+        (false, strf("too far away"))
+    } else if ground_items_at(view, from).is_empty() {
+        (false, strf("nothing to pick up"))
+    } else if view.character(character).action_points.cur_value() < PICK_UP_AP_COST {
+        (false, strf("not enough action points"))
+    } else {
+        (true, strf("Pick Up"))
+    }
+}
+
+/// moves everything on the ground at the given location into the character's inventory, as much as will fit
+pub fn pick_up(world : &mut World, character : Entity, from : AxialCoord) {
+    let view = world.view();
+    let (can, reason) = can_pick_up(view, character, from);
+    if ! can {
+        warn!("Could not pick up items at {:?}: {}", from, reason);
+        return;
+    }
+
+    if let Some(tile) = view.tile_ent_opt(from) {
+        let action = Action { action_type : ActionType::PickUp { from }, ap : Progress::new(PICK_UP_AP_COST, PICK_UP_AP_COST) };
+        world.start_event(GameEvent::ActionTaken { entity : character, action : action.clone() });
+
+        world.modify(character, CharacterData::action_points.reduce_by(PICK_UP_AP_COST));
+        for item in view.data::<InventoryData>(tile.entity).items.iter().filter(|i| ! logic::building::is_built(view, **i)).cloned().collect_vec() {
+            logic::item::transfer_item(world, item, tile.entity, character);
+        }
+
+        world.end_event(GameEvent::ActionTaken { entity : character, action });
+    }
+}
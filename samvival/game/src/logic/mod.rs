@@ -13,4 +13,16 @@ pub mod harvest;
 pub mod breakdown;
 pub mod turn;
 pub mod action;
-pub mod crafting;
\ No newline at end of file
+pub mod crafting;
+pub mod time;
+pub mod weather;
+pub mod loot;
+pub mod recipes;
+pub mod perks;
+pub mod awareness;
+pub mod morale;
+pub mod objectives;
+pub mod triggers;
+pub mod spawning;
+pub mod needs;
+pub mod building;
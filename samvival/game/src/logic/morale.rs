@@ -0,0 +1,151 @@
+use common::prelude::*;
+use prelude::*;
+use logic;
+use data::entities::{MoraleData, MoraleState, PositionData, AllegianceData, FactionData};
+
+/// how close an ally has to fall for it to shake a character's nerve
+pub const ALLY_DEATH_RADIUS : f32 = 4.0;
+pub const ALLY_DEATH_MORALE_LOSS : i32 = 3;
+/// lost on being hit at all, and again on being brought below half health
+pub const HIT_MORALE_LOSS : i32 = 1;
+pub const BADLY_WOUNDED_MORALE_LOSS : i32 = 3;
+/// how close allies and enemies have to be to count towards the numbers on either side, and for a leader to steady those
+/// around them
+pub const SUPPORT_RADIUS : f32 = 5.0;
+/// the fraction of their full morale at or below which a character breaks and runs
+pub const FLEE_THRESHOLD : f64 = 0.3;
+/// the fraction of their full morale a fleeing character has to get back to before they'll rejoin the fight
+pub const RALLY_THRESHOLD : f64 = 0.6;
+
+
+pub fn morale_state(view : &WorldView, character : Entity) -> MoraleState {
+    view.data_opt::<MoraleData>(character).map(|m| m.state).unwrap_or(MoraleState::Steady)
+}
+
+pub fn is_fleeing(view : &WorldView, character : Entity) -> bool {
+    morale_state(view, character) == MoraleState::Fleeing
+}
+
+pub fn has_surrendered(view : &WorldView, character : Entity) -> bool {
+    morale_state(view, character) == MoraleState::Surrendered
+}
+
+/// only characters the AI controls can lose their nerve. The player decides for themselves when their people should run,
+/// and one that surrendered would be out of the fight while still taking orders
+pub fn is_subject_to_morale(view : &WorldView, character : Entity) -> bool {
+    view.has_data::<MoraleData>(character) && ! view.data_opt::<AllegianceData>(character)
+        .and_then(|a| view.data_opt::<FactionData>(a.faction))
+        .map(|f| f.player_faction)
+        .unwrap_or(false)
+}
+
+pub fn on_damage_taken(world : &mut World, character : Entity, damage : u32) {
+    let view = world.view();
+    if damage == 0 || ! is_subject_to_morale(view, character) {
+        return;
+    }
+    let health = &view.data::<CharacterData>(character).health;
+    let health_before = health.cur_value() + damage as i32;
+    let half = health.max_value() / 2;
+
+    if health_before > half && health.cur_value() <= half {
+        adjust_morale(world, character, -(HIT_MORALE_LOSS + BADLY_WOUNDED_MORALE_LOSS), "badly wounded");
+    } else {
+        adjust_morale(world, character, -HIT_MORALE_LOSS, "wounded");
+    }
+}
+
+/// shakes the allies of a character that has just died, if they were close enough to see it happen
+pub fn on_death(world : &mut World, character : Entity, at : AxialCoord) {
+    let view = world.view();
+    let faction = match view.data_opt::<AllegianceData>(character) {
+        Some(allegiance) => allegiance.faction,
+        None => return
+    };
+
+    let witnesses = allies_within(view, faction, at, ALLY_DEATH_RADIUS).into_iter().filter(|a| *a != character).collect_vec();
+    for witness in witnesses {
+        adjust_morale(world, witness, -ALLY_DEATH_MORALE_LOSS, "ally killed");
+    }
+}
+
+/// at the start of their faction's turn, characters regain some nerve, more with a leader close at hand or numbers on
+/// their side
+pub fn recover_morale(world : &mut World, faction : Entity) {
+    let view = world.view();
+    let members = view.entities_with_data::<MoraleData>()
+        .filter(|(c, m)| m.state != MoraleState::Surrendered && view.data::<CharacterData>(**c).is_alive())
+        .filter(|(c, _)| view.data_opt::<AllegianceData>(**c).map(|a| a.faction == faction).unwrap_or(false))
+        .map(|(c, _)| *c)
+        .collect_vec();
+
+    for character in members {
+        let morale = view.data::<MoraleData>(character);
+        if morale.morale.cur_value() >= morale.morale.max_value() {
+            continue;
+        }
+
+        let at = view.data::<PositionData>(character).hex;
+        let allies = allies_within(view, faction, at, SUPPORT_RADIUS);
+        let leadership = allies.iter().filter_map(|a| view.data_opt::<MoraleData>(*a)).map(|m| m.leadership).max().unwrap_or(0);
+        let enemies = view.entities_with_data::<CharacterData>()
+            .filter(|(e, c)| c.is_alive() && logic::faction::is_enemy_of_faction(view, faction, **e) && ! has_surrendered(view, **e))
+            .filter(|(e, _)| view.data::<PositionData>(**e).hex.distance(&at) <= SUPPORT_RADIUS)
+            .count() as i32;
+        let numbers = (allies.len() as i32 - enemies).max(-1).min(2);
+
+        let recovery = (1 + leadership + numbers).max(0);
+        if recovery > 0 {
+            adjust_morale(world, character, recovery, "regaining composure");
+        }
+    }
+}
+
+/// changes the character's morale and checks whether that breaks or rallies them, reporting the check if it does
+pub fn adjust_morale(world : &mut World, character : Entity, delta : i32, reason : Str) {
+    let view = world.view();
+    if ! is_subject_to_morale(view, character) {
+        return;
+    }
+    let morale_data = view.data::<MoraleData>(character).clone();
+    if morale_data.state == MoraleState::Surrendered {
+        return;
+    }
+
+    let max = morale_data.morale.max_value();
+    let new_morale = (morale_data.morale.cur_value() + delta).max(0).min(max);
+    let fraction = new_morale as f64 / max.max(1) as f64;
+
+    let new_state = if new_morale <= 0 {
+        MoraleState::Surrendered
+    } else if fraction <= FLEE_THRESHOLD {
+        MoraleState::Fleeing
+    } else if morale_data.state == MoraleState::Fleeing && fraction < RALLY_THRESHOLD {
+        MoraleState::Fleeing
+    } else {
+        MoraleState::Steady
+    };
+
+    let change = new_morale - morale_data.morale.cur_value();
+    if change < 0 {
+        world.modify_with_desc(character, MoraleData::morale.reduce_by(-change), reason);
+    } else if change > 0 {
+        world.modify_with_desc(character, MoraleData::morale.recover_by(change), reason);
+    }
+    if new_state != morale_data.state {
+        world.modify_with_desc(character, MoraleData::state.set_to(new_state), reason);
+        if new_state == MoraleState::Surrendered {
+            logic::action::interrupt_action(world, character);
+        }
+        world.add_event(GameEvent::MoraleCheck { entity : character, morale : new_morale, state : new_state, reason : strf(reason) });
+    }
+}
+
+fn allies_within(view : &WorldView, faction : Entity, at : AxialCoord, radius : f32) -> Vec<Entity> {
+    view.entities_with_data::<CharacterData>()
+        .filter(|(_, c)| c.is_alive())
+        .filter(|(a, _)| view.data_opt::<AllegianceData>(**a).map(|al| al.faction == faction).unwrap_or(false))
+        .filter(|(a, _)| view.data::<PositionData>(**a).hex.distance(&at) <= radius)
+        .map(|(a, _)| *a)
+        .collect()
+}
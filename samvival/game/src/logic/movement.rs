@@ -17,6 +17,7 @@ use game::Entity;
 use game::world::World;
 use game::world::WorldView;
 use logic::movement;
+use logic;
 use data::events::GameEvent;
 use game::SettableField;
 use game::reflect::*;
@@ -88,8 +89,8 @@ pub fn hexes_in_range(world_view : &WorldView, mover : Entity, range : Sext) ->
     let tile_accessor = TileAccessor::new(world_view);
     flood_search(start_position, range.as_f64(), move |from, to| {
         let cost = if let Some(tile) = tile_accessor.tile_opt(*to) {
-            if tile.occupied_by.is_none() {
-                tile_accessor.terrain(&tile).move_cost.as_f32() + tile_accessor.vegetation(&tile).move_cost.as_f32()
+            if tile.occupied_by.is_none() && tile_accessor.is_passable(&tile) {
+                tile_accessor.move_cost(&tile).as_f32()
             } else { 10000.0 }
         } else { 10000.0 };
         cost as f64
@@ -189,11 +190,12 @@ pub fn handle_move(world : &mut World, mover : Entity, path : &[AxialCoord]) {
     let start_pos = view.character(mover).position.hex;
     let mut prev_hex = start_pos;
     let mut prev_hex_ent = view.entity_by_key(&start_pos).expect("hex must exist");
+    let accessor = TileAccessor::new(view);
     for hex in path {
         let hex = *hex;
         if hex != start_pos {
             let hex_ent = view.entity_by_key(&hex).expect("hex must exist");
-            let hex_cost = view.terrain(hex).move_cost + view.vegetation(hex).move_cost;
+            let hex_cost = accessor.move_cost(&accessor.tile_opt(hex).expect("hex must exist"));
             // how many ap must be changed to move points in order to enter the given hex
             let ap_required = movement::hex_ap_cost(world.view(), mover, hex) as i32;
             if ap_required <= view.character(mover).action_points.cur_value() {
@@ -204,6 +206,10 @@ pub fn handle_move(world : &mut World, mover : Entity, path : &[AxialCoord]) {
                 world.modify_with_desc(mover, PositionData::hex.set_to(hex), None);
                 world.modify_with_desc(prev_hex_ent, TileData::occupied_by.set_to(None), None);
                 world.modify_with_desc(hex_ent, TileData::occupied_by.set_to(Some(mover)), None);
+                let stamina_cost = logic::item::encumbrance(view, mover).stamina_cost_per_move();
+                if stamina_cost > Sext::zero() {
+                    world.modify_with_desc(mover, CharacterData::stamina.reduce_by(stamina_cost), "encumbrance");
+                }
 
 //                modify(world, mover, SkillXPMod(Skill::ForestSurvival, 1));
                 // advance the event clock
@@ -265,8 +271,8 @@ pub fn path_any(world : &WorldView, mover : Entity, from: AxialCoord, to: &HashS
 
 pub fn move_cost_to(accessor : &TileAccessor, mover : Entity, to : &AxialCoord) -> Sext {
     if let Some(tile) = accessor.tile_opt(*to) {
-        if tile.occupied_by.is_none() {
-            accessor.vegetation(&tile).move_cost + accessor.terrain(&tile).move_cost
+        if tile.occupied_by.is_none() && accessor.is_passable(&tile) {
+            accessor.move_cost(&tile)
         } else {
             Sext::of(100000)
         }
@@ -277,8 +283,8 @@ pub fn move_cost_to(accessor : &TileAccessor, mover : Entity, to : &AxialCoord)
 
 pub fn move_cost_to_f32(accessor : &TileAccessor , mover : &Character, to: &AxialCoord) -> f32 {
     if let Some(tile) = accessor.tile_opt(*to) {
-        if tile.occupied_by.is_none() {
-            (accessor.vegetation(&tile).move_cost + accessor.terrain(&tile).move_cost).as_f32()
+        if tile.occupied_by.is_none() && accessor.is_passable(&tile) {
+            accessor.move_cost(&tile).as_f32()
         } else {
             10000000.0
         }
@@ -0,0 +1,198 @@
+use common::prelude::*;
+use common::hex::*;
+use prelude::*;
+use logic;
+use entities::taxonomy;
+use entities::{NeedsData, Need, NeedSeverity, FoodInfo, IdentityData, PositionData, TerrainData, Season, TimeOfDay, Weather, Taxon};
+
+pub const CONSUME_AP_COST : i32 = 1;
+/// how close a character has to be to a campfire to be warmed by it, and how much it warms them each turn
+pub const FIRE_RADIUS : i32 = 2;
+pub const FIRE_WARMTH : i32 = 4;
+/// warmth gained each turn from standing in the same hex as a shelter
+pub const SHELTER_WARMTH : i32 = 2;
+/// hydration regained each turn spent in or next to fresh water
+pub const WATER_HYDRATION : i32 = 8;
+
+
+pub fn severity(view : &WorldView, character : Entity, need : Need) -> NeedSeverity {
+    view.data_opt::<NeedsData>(character).map(|n| n.severity(need)).unwrap_or(NeedSeverity::Satisfied)
+}
+
+/// amount by which the character's per-turn stamina recovery is reduced, every unmet need adds to it
+pub fn stamina_recovery_penalty(view : &WorldView, character : Entity) -> Sext {
+    match view.data_opt::<NeedsData>(character) {
+        Some(needs) => Need::all().iter().fold(Sext::zero(), |acc, n| acc + needs.severity(*n).stamina_recovery_penalty()),
+        None => Sext::zero()
+    }
+}
+
+/// satiation lost each turn, more in the winter and through the night, when just keeping warm burns through what a body has
+pub fn hunger_rate(view : &WorldView) -> i32 {
+    let winter = if logic::time::season(view) == Season::Winter { 1 } else { 0 };
+    let night = if logic::time::time_of_day(view) == TimeOfDay::Night { 1 } else { 0 };
+    1 + winter + night
+}
+
+/// hydration lost each turn, more in a heat wave, though even a heat wave lets up once the sun is down
+pub fn thirst_rate(view : &WorldView) -> i32 {
+    let heat = logic::weather::current_weather(view) == Weather::Heat && logic::time::time_of_day(view) != TimeOfDay::Night;
+    if heat { 2 } else { 1 }
+}
+
+/// how much warmth a character at the given location gains (or, more often, loses) each turn, the nights and the winter
+/// are cold, and fires and shelter are the only real answer to them
+pub fn warmth_change(view : &WorldView, at : AxialCoord) -> i32 {
+    let time_of_day = match logic::time::time_of_day(view) {
+        TimeOfDay::Daylight => 1,
+        TimeOfDay::Dawn | TimeOfDay::Dusk => 0,
+        TimeOfDay::Night => -2,
+    };
+    let season = match logic::time::season(view) {
+        Season::Summer => 1,
+        Season::Spring | Season::Autumn => 0,
+        Season::Winter => -2,
+    };
+    let weather = match logic::weather::current_weather(view) {
+        Weather::Snow => -2,
+        Weather::Rain => -1,
+        Weather::Heat => 1,
+        _ => 0
+    };
+    let fire = if is_near_fire(view, at) { FIRE_WARMTH } else { 0 };
+    let shelter = if is_sheltered(view, at) { SHELTER_WARMTH } else { 0 };
+
+    time_of_day + season + weather + fire + shelter
+}
+
+pub fn is_near_fire(view : &WorldView, at : AxialCoord) -> bool {
+    let nearby = (1 ..= FIRE_RADIUS).flat_map(|r| CubeCoord::ring(at.as_cube_coord(), r as u32).collect_vec()).map(|c| c.as_axial_coord());
+    ::std::iter::once(at).chain(nearby).any(|hex| any_structure_of_kind(view, hex, &taxonomy::Campfire))
+}
+
+pub fn is_sheltered(view : &WorldView, at : AxialCoord) -> bool {
+    any_structure_of_kind(view, at, &taxonomy::Shelter)
+}
+
+/// whether there's fresh water to drink in or next to the given hex
+pub fn is_near_water(view : &WorldView, at : AxialCoord) -> bool {
+    ::std::iter::once(at).chain(at.neighbors_vec()).any(|hex| view.tile_ent_opt(hex)
+        .and_then(|t| view.data_opt::<TerrainData>(t.entity))
+        .map(|t| t.kind.is_a(view, &taxonomy::terrain::Water))
+        .unwrap_or(false))
+}
+
+/// fires and shelters only do any good once they've been built, a lean-to still bundled up on the ground keeps no one dry
+fn any_structure_of_kind(view : &WorldView, at : AxialCoord, kind : &Taxon) -> bool {
+    logic::building::structures_at(view, at).iter()
+        .filter_map(|i| view.data_opt::<IdentityData>(*i))
+        .any(|ident| ident.kinds.iter().any(|k| k.is_a(view, kind)))
+}
+
+/// runs down the character's needs for a new turn, topping them back up from any water or warmth close at hand, and
+/// then makes them pay for whatever is going unmet
+pub fn update_needs(world : &mut World, character : Entity) {
+    let view = world.view();
+    let needs = match view.data_opt::<NeedsData>(character) {
+        Some(needs) => needs.clone(),
+        None => return
+    };
+    let at = view.data::<PositionData>(character).hex;
+
+    let hydration_change = if is_near_water(view, at) { WATER_HYDRATION } else { -thirst_rate(view) };
+    let mut new_needs = needs.clone();
+    adjust_need(world, character, &mut new_needs, Need::Hunger, -hunger_rate(view), "hunger");
+    adjust_need(world, character, &mut new_needs, Need::Thirst, hydration_change, "thirst");
+    adjust_need(world, character, &mut new_needs, Need::Cold, warmth_change(view, at), "cold");
+
+    let ap_penalty : i32 = Need::all().iter().map(|n| new_needs.severity(*n).action_point_penalty()).sum();
+    if ap_penalty > 0 {
+        world.modify_with_desc(character, CharacterData::action_points.reduce_by(ap_penalty), "unmet needs");
+    }
+
+    // wasting away isn't a blow taken in a fight, so it's applied directly rather than as damage, which would interrupt
+    // what the character is doing and shake their nerve as though they'd been attacked
+    let mut lost_health = false;
+    for need in Need::all().iter() {
+        let health_loss = new_needs.severity(*need).health_loss();
+        if health_loss > 0 {
+            let cause = match need {
+                Need::Hunger | Need::Thirst => "starvation",
+                Need::Cold => "exposure",
+            };
+            world.modify_with_desc(character, CharacterData::health.reduce_by(health_loss), cause);
+            lost_health = true;
+        }
+    }
+    if lost_health && ! world.view().character(character).is_alive() {
+        logic::character::handle_death(world, character);
+    }
+}
+
+pub fn can_consume(view : &WorldView, character : Entity, item : Entity) -> (bool, String) {
+    if ! view.has_data::<NeedsData>(character) {
+        (false, strf("has no need to eat"))
+    } else if ! view.has_data::<FoodInfo>(item) {
+        (false, strf("not edible"))
+    } else if ! logic::item::is_item_in_inventory_of(view, item, character) {
+        (false, strf("not carried"))
+    } else if view.character(character).action_points.cur_value() < CONSUME_AP_COST {
+        (false, strf("not enough action points"))
+    } else {
+        (true, strf("Eat"))
+    }
+}
+
+/// eats or drinks the given item, using it up
+pub fn consume(world : &mut World, character : Entity, item : Entity) -> bool {
+    let view = world.view();
+    let item = logic::item::item_or_first_in_stack(view, item);
+    let (can, reason) = can_consume(view, character, item);
+    if ! can {
+        warn!("Could not consume {}: {}", view.signifier(item), reason);
+        return false;
+    }
+
+    let food = view.data::<FoodInfo>(item).clone();
+    let mut needs = view.data::<NeedsData>(character).clone();
+    world.modify_with_desc(character, CharacterData::action_points.reduce_by(CONSUME_AP_COST), "eating");
+    adjust_need(world, character, &mut needs, Need::Hunger, food.satiation, "eating");
+    adjust_need(world, character, &mut needs, Need::Thirst, food.hydration, "drinking");
+
+    world.add_event(GameEvent::ItemConsumed { consumer : character, item });
+    logic::item::destroy_item(world, item);
+    true
+}
+
+/// changes how well met one of the character's needs is, tracking the change in the given copy of their needs since
+/// modifications don't show up in the view straight away, and reporting when that makes the need better or worse
+fn adjust_need(world : &mut World, character : Entity, needs : &mut NeedsData, need : Need, delta : i32, reason : Str) {
+    let before = needs.severity(need);
+    let (current, max) = (needs.level(need).cur_value(), needs.level(need).max_value());
+    let change = (current + delta).max(0).min(max) - current;
+    if change == 0 {
+        return;
+    }
+
+    let field = match need {
+        Need::Hunger => &NeedsData::satiation,
+        Need::Thirst => &NeedsData::hydration,
+        Need::Cold => &NeedsData::warmth,
+    };
+    if change < 0 {
+        world.modify_with_desc(character, field.reduce_by(-change), reason);
+    } else {
+        world.modify_with_desc(character, field.recover_by(change), reason);
+    }
+
+    match need {
+        Need::Hunger => needs.satiation.reduce_by(-change),
+        Need::Thirst => needs.hydration.reduce_by(-change),
+        Need::Cold => needs.warmth.reduce_by(-change),
+    }
+
+    let after = needs.severity(need);
+    if after != before {
+        world.add_event(GameEvent::NeedSeverityChanged { character, need, severity : after });
+    }
+}
@@ -0,0 +1,121 @@
+use common::prelude::*;
+use prelude::*;
+use logic;
+use entity_util::has_name;
+use game::events::CoreEvent;
+use data::entities::{ObjectiveData, Objective, ObjectiveCondition, Outcome, FactionData, PositionData, AllegianceData, TurnData};
+
+
+/// Checks every faction's objectives after anything happens that could bring one closer, ending the game for a faction
+/// once all of its victory objectives are met or any of its defeat objectives is. Player factions that were never given
+/// objectives are held to the standard ones, win by wiping out the enemy, lose by being wiped out
+pub fn register(world: &mut World) {
+    let unassigned = world.view().entities_with_data::<FactionData>()
+        .filter(|(faction, data)| data.player_faction && ! world.view().has_data::<ObjectiveData>(**faction))
+        .map(|(faction, _)| *faction)
+        .collect_vec();
+    for faction in &unassigned {
+        world.attach_data(*faction, ObjectiveData::standard());
+    }
+    if unassigned.non_empty() {
+        world.add_event(CoreEvent::Recomputation);
+    }
+
+    world.add_callback(|world, event_w| {
+        if ! event_w.is_ended() {
+            return;
+        }
+        match event_w.event {
+            GameEvent::Move { .. } | GameEvent::EntityDied { .. } | GameEvent::FactionTurn { .. } | GameEvent::MoraleCheck { .. } |
+            GameEvent::RelationshipChanged { .. } | GameEvent::ItemCrafted { .. } | GameEvent::EntityHarvested { .. } => {
+                let factions = world.view().entities_with_data::<ObjectiveData>().map(|(faction, _)| *faction).collect_vec();
+                for faction in factions {
+                    update_objectives(world, faction, &event_w.event);
+                }
+            },
+            _ => ()
+        }
+    });
+}
+
+/// how the game has ended for the faction, if it has
+pub fn outcome(view : &WorldView, faction : Entity) -> Option<Outcome> {
+    view.data_opt::<ObjectiveData>(faction).and_then(|o| o.outcome)
+}
+
+/// marks off any of the faction's objectives that have now been met, counting the given event towards those that are
+/// about doing something rather than some state of the world
+pub fn update_objectives(world : &mut World, faction : Entity, event : &GameEvent) {
+    let view = world.view();
+    let mut objectives = match view.data_opt::<ObjectiveData>(faction) {
+        Some(data) if data.outcome.is_none() => data.objectives.clone(),
+        _ => return
+    };
+
+    let mut changed = false;
+    let mut newly_completed = Vec::new();
+    for objective in objectives.iter_mut().filter(|o| ! o.completed) {
+        let progress = progress_from_event(view, faction, objective, event);
+        if progress > 0 {
+            objective.progress += progress;
+            changed = true;
+        }
+        if is_met(view, faction, objective) {
+            objective.completed = true;
+            newly_completed.push(objective.clone());
+        }
+    }
+    if ! changed && newly_completed.is_empty() {
+        return;
+    }
+
+    world.modify_with_desc(faction, ObjectiveData::objectives.set_to(objectives.clone()), "objectives progressed");
+    for objective in newly_completed {
+        world.add_event(GameEvent::ObjectiveCompleted { faction, description : objective.description, outcome : objective.outcome });
+    }
+
+    let new_outcome = if objectives.iter().any(|o| o.outcome == Outcome::Defeat && o.completed) {
+        Some(Outcome::Defeat)
+    } else if objectives.iter().any(|o| o.outcome == Outcome::Victory) && objectives.iter().filter(|o| o.outcome == Outcome::Victory).all(|o| o.completed) {
+        Some(Outcome::Victory)
+    } else {
+        None
+    };
+    if let Some(new_outcome) = new_outcome {
+        world.modify_with_desc(faction, ObjectiveData::outcome.set_to(Some(new_outcome)), "game ended");
+        world.add_event(GameEvent::GameEnded { faction, outcome : new_outcome });
+    }
+}
+
+fn is_met(view : &WorldView, faction : Entity, objective : &Objective) -> bool {
+    match objective.condition {
+        ObjectiveCondition::KillAllEnemies => ! view.entities_with_data::<CharacterData>()
+            .any(|(c, data)| data.is_alive() && logic::faction::is_enemy_of_faction(view, faction, *c) && ! logic::morale::has_surrendered(view, *c)),
+        ObjectiveCondition::AllCharactersLost => ! members(view, faction).iter().any(|c| ! logic::morale::has_surrendered(view, *c)),
+        ObjectiveCondition::SurviveTurns(turns) => view.world_data::<TurnData>().turn_number >= turns,
+        ObjectiveCondition::ReachHex(hex) => members(view, faction).iter().any(|c| view.data::<PositionData>(*c).hex == hex),
+        ObjectiveCondition::CraftItem(_) => objective.progress > 0,
+        ObjectiveCondition::GatherResource { amount, .. } => objective.progress >= amount,
+    }
+}
+
+fn progress_from_event(view : &WorldView, faction : Entity, objective : &Objective, event : &GameEvent) -> i32 {
+    match (&objective.condition, event) {
+        (ObjectiveCondition::CraftItem(kind), GameEvent::ItemCrafted { item, crafter, .. }) if is_member(view, faction, *crafter) && has_name(*item, view, kind) => 1,
+        (ObjectiveCondition::GatherResource { resource, .. }, GameEvent::EntityHarvested { harvester, resource : harvested, amount : Some(amount), .. })
+            if is_member(view, faction, *harvester) && has_name(*harvested, view, resource) => *amount,
+        _ => 0
+    }
+}
+
+/// the living members of the faction
+fn members(view : &WorldView, faction : Entity) -> Vec<Entity> {
+    view.entities_with_data::<CharacterData>()
+        .filter(|(c, data)| data.is_alive() && is_member(view, faction, **c))
+        .map(|(c, _)| *c)
+        .collect()
+}
+
+fn is_member(view : &WorldView, faction : Entity, character : Entity) -> bool {
+    view.data_opt::<AllegianceData>(character).map(|a| a.faction == faction).unwrap_or(false)
+}
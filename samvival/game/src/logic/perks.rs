@@ -0,0 +1,76 @@
+use common::prelude::*;
+use prelude::*;
+use logic;
+use entities::perks::*;
+use entities::{CombatData, DerivedAttackData, SkillData};
+use entities::selectors::EntitySelector;
+
+pub fn has_perk(view : &WorldView, character : Entity, perk : Perk) -> bool {
+    view.data_opt::<PerkData>(character).map(|p| p.has_perk(perk)).unwrap_or(false)
+}
+
+pub fn owned_perks(view : &WorldView, character : Entity) -> Vec<Perk> {
+    perks::AllPerks.iter().cloned().filter(|p| has_perk(view, character, *p)).collect()
+}
+
+/// the perks the character has yet to earn, for any skill they have some xp or bonus in, soonest first
+pub fn upcoming_perks(view : &WorldView, character : Entity) -> Vec<Perk> {
+    let mut res : Vec<Perk> = logic::skill::skill_levels(view, character).into_iter()
+        .flat_map(|(skill, _)| perks::perks_for_skill(skill))
+        .filter(|p| ! has_perk(view, character, *p))
+        .collect();
+    res.sort_by_key(|p| p.level);
+    res
+}
+
+/// grants any perks the character's current skill levels entitle them to that they don't already have. Safe to call repeatedly
+pub fn grant_earned_perks(world : &mut World, character : Entity) {
+    let view = world.view();
+    if ! view.has_data::<SkillData>(character) {
+        return;
+    }
+
+    for perk in perks::AllPerks.iter().cloned() {
+        if ! has_perk(view, character, perk) && logic::skill::skill_level(view, character, perk.skill) >= perk.level {
+            grant_perk(world, character, perk);
+        }
+    }
+}
+
+pub fn grant_perk(world : &mut World, character : Entity, perk : Perk) {
+    let view = world.view();
+    if ! view.has_data::<PerkData>(character) {
+        world.attach_data(character, PerkData::default());
+    }
+    world.modify_with_desc(character, PerkData::perks.append(strf(perk.name)), perk.name);
+
+    if view.has_data::<CombatData>(character) {
+        match perk.effect {
+            PerkEffect::DerivedAttack(ref kind, attack_condition) => {
+                let special_attack = EntityBuilder::new()
+                    .with(DerivedAttackData {
+                        character_condition : EntitySelector::Any,
+                        weapon_condition : EntitySelector::Any,
+                        attack_condition : attack_condition(),
+                        kind : kind.clone(),
+                    }).create(world);
+                world.modify_with_desc(character, CombatData::special_attacks.append(special_attack), perk.name);
+            },
+            PerkEffect::ExtraCounters(amount) => { world.modify_with_desc(character, CombatData::counters_per_event.add(amount), perk.name); },
+            PerkEffect::RangedAccuracy(amount) => { world.modify_with_desc(character, CombatData::ranged_accuracy_bonus.add(amount), perk.name); },
+            PerkEffect::Dodge(amount) => { world.modify_with_desc(character, CombatData::dodge_bonus.add(amount), perk.name); },
+            // reflex saves are checked when an attack is resolved, nothing to apply up front
+            PerkEffect::ReflexSave(_) | PerkEffect::None => {},
+        }
+    }
+
+    world.add_event(GameEvent::PerkGained { entity : character, perk : strf(perk.name) });
+}
+
+/// the bonus to avoid an attack that covers an area, if the character has learned to throw themselves clear of them
+pub fn reflex_save_bonus(view : &WorldView, character : Entity) -> i32 {
+    owned_perks(view, character).iter().map(|p| match p.effect {
+        PerkEffect::ReflexSave(bonus) => bonus,
+        _ => 0
+    }).sum()
+}
@@ -0,0 +1,90 @@
+use common::prelude::*;
+use prelude::*;
+use logic;
+use entities::recipes::*;
+use entities::{ItemData, WeightData, InventoryData};
+use data::archetype::EntityArchetype;
+
+/// the recipe at the root of the given recipe's family, knowing a root recipe means knowing all of its variants
+pub fn root_recipe_of(view : &WorldView, recipe : Entity) -> Entity {
+    let mut cur = recipe;
+    while let Some(parent) = view.data::<Recipe>(cur).parent_recipe {
+        cur = parent;
+    }
+    cur
+}
+
+/// the recipe that produces the given item archetype, if there is one
+pub fn recipe_for(view : &WorldView, archetype : Entity) -> Option<Entity> {
+    view.entities_with_data::<Recipe>()
+        .find(|(_, r)| r.result == EntityArchetype::Archetype(archetype))
+        .map(|(e, _)| *e)
+}
+
+pub fn knows_recipe(view : &WorldView, character : Entity, recipe : Entity) -> bool {
+    let root = root_recipe_of(view, recipe);
+    view.data::<Recipe>(root).is_known_by_default() ||
+        view.data_opt::<RecipeKnowledgeData>(character).map(|k| k.known_recipes.contains(&root)).unwrap_or(false)
+}
+
+pub fn known_root_recipes(view : &WorldView, character : Entity) -> Vec<Entity> {
+    RecipeCatalogView::of(view).root_recipes().iter().cloned().filter(|r| knows_recipe(view, character, *r)).collect()
+}
+
+pub fn learn_recipe(world : &mut World, character : Entity, recipe : Entity) {
+    let view = world.view();
+    let root = root_recipe_of(view, recipe);
+    if view.has_data::<RecipeKnowledgeData>(character) && ! knows_recipe(view, character, root) {
+        world.modify_with_desc(character, RecipeKnowledgeData::known_recipes.append(root), "recipe discovered");
+        world.add_event(GameEvent::RecipeDiscovered { character, recipe : root });
+    }
+}
+
+/// checks every recipe the character doesn't yet know against what they are carrying and how skilled they are, learning
+/// any that they now have the means to figure out. Should be called whenever either of those changes
+pub fn discover_recipes(world : &mut World, character : Entity) {
+    let view = world.view();
+    if ! view.has_data::<RecipeKnowledgeData>(character) {
+        return;
+    }
+
+    let held_items = if view.has_data::<InventoryData>(character) { logic::item::items_in_inventory(view, character) } else { Vec::new() };
+    let from_schematics : Vec<Entity> = held_items.iter()
+        .filter_map(|i| view.data_opt::<SchematicData>(*i))
+        .flat_map(|s| s.recipes.iter().cloned())
+        .collect();
+
+    for recipe in RecipeCatalogView::of(view).root_recipes().clone() {
+        if knows_recipe(view, character, recipe) {
+            continue;
+        }
+
+        let discovered = from_schematics.iter().any(|r| root_recipe_of(view, *r) == recipe) ||
+            view.data::<Recipe>(recipe).discovered_by.iter().any(|discovery| match discovery {
+                RecipeDiscovery::Examine(selector) => held_items.iter().any(|i| selector.matches(view, *i)),
+                RecipeDiscovery::SkillLevel(skill, level) => logic::skill::skill_level(view, character, *skill) >= *level,
+                // recipes known from the start were skipped above, and schematics are checked on their own
+                RecipeDiscovery::Known | RecipeDiscovery::Schematic => false,
+            });
+
+        if discovered {
+            learn_recipe(world, character, recipe);
+        }
+    }
+}
+
+/// creates a schematic item that teaches the given recipe to whoever picks it up
+pub fn create_schematic(world : &mut World, recipe : Entity) -> Entity {
+    let view = world.view();
+    let name = match view.data::<Recipe>(recipe).result {
+        EntityArchetype::Archetype(arch) | EntityArchetype::CopyEnitity(arch) => format!("{} schematic", view.identity(arch).effective_name()),
+        EntityArchetype::Sentinel => strf("schematic"),
+    };
+
+    EntityBuilder::new()
+        .with(ItemData { stack_limit : 1, ..Default::default() })
+        .with(IdentityData::new(name, &taxonomy::Schematic))
+        .with(WeightData::new(1))
+        .with(SchematicData { recipes : vec![recipe] })
+        .create(world)
+}
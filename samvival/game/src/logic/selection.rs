@@ -31,11 +31,15 @@ impl SelectorMatches for EntitySelector {
             Friend { of } =>
                 IsCharacter.matches(world, *of) &&
                     IsCharacter.matches(world, entity) &&
-                    world.character(*of).allegiance.faction == world.character(entity).allegiance.faction,
+                    logic::faction::relationship_between(world, *of, entity) == Relationship::Allied,
             Enemy { of } =>
                 IsCharacter.matches(world, *of) &&
                     IsCharacter.matches(world, entity) &&
-                    world.character(*of).allegiance.faction == world.character(entity).allegiance.faction,
+                    logic::faction::relationship_between(world, *of, entity) == Relationship::Hostile,
+            Neutral { of } =>
+                IsCharacter.matches(world, *of) &&
+                    IsCharacter.matches(world, entity) &&
+                    logic::faction::relationship_between(world, *of, entity) == Relationship::Neutral,
             InMoveRange { hex_range, of } => {
                 if let Some(end_point) = position_of(entity, world) {
                     if let Some((_, cost)) = logic::movement::path_to(world, *of, end_point) {
@@ -4,6 +4,8 @@ use prelude::*;
 use entities::SkillData;
 use entities::Skill;
 use data::entities::common_entities::LookupSignifier;
+use data::entities::attributes::{AttributeData, AttributeEffect, AttributeDefinitions};
+use logic;
 
 pub fn skill_level(view : &WorldView, entity : Entity, skill : Skill) -> i32 {
     if let Some(skill_data) = view.data_opt::<SkillData>(entity) {
@@ -25,6 +27,36 @@ pub fn skill_levels(view : &WorldView, entity: Entity) -> Vec<(Skill, i32)> {
     } else { warn!("Requesting all skills for non-skilled entity: {:?}", view.signifier(entity)); Vec::new() }
 }
 
+/// the single place skill xp should be granted from. Emits a level up event for every level crossed and gives the
+/// character a chance to work out any recipes their new skill has opened up
+pub fn grant_xp(world : &mut World, entity : Entity, skill : Skill, xp : i32) {
+    let view = world.view();
+    if xp <= 0 || ! view.has_data::<SkillData>(entity) {
+        return;
+    }
+
+    let skill_data = view.data::<SkillData>(entity);
+    let level_before = skill_level_from(skill_data, skill);
+    let level_after = skill_data.skill_bonuses.get(&skill).unwrap_or(&0) + level_for_xp(skill_data.cur_skill_xp(skill) + xp);
+    world.modify_with_desc(entity, SkillData::skill_xp.add_to_key(skill, xp), None);
+
+    for level in level_before + 1 ..= level_after {
+        world.add_event(GameEvent::SkillLevelUp { entity, skill, level });
+    }
+
+    if level_after > level_before {
+        logic::perks::grant_earned_perks(world, entity);
+        logic::recipes::discover_recipes(world, entity);
+    }
+}
+
+/// the xp bonus for practicing with the given weapon from its attributes, training weapons teach more than the real thing
+pub fn weapon_xp_bonus(view : &WorldView, weapon : Entity) -> i32 {
+    view.data_opt::<AttributeData>(weapon)
+        .map(|a| a.effect_contributions(view.world_data::<AttributeDefinitions>(), |e| if let AttributeEffect::Xp(xp) = e { Some(*xp) } else { None }).iter().map(|(_, xp)| xp).sum())
+        .unwrap_or(0)
+}
+
 pub fn xp_required_for_level(lvl : i32) -> i32 {
     let lvl = (lvl + 1) as f64; // shift over by 1 so that getting to level 1 doesn't cost 0 xp
     ((0.5 * lvl.powf(2.0) - 0.5 * lvl) * 10.0) as i32
@@ -0,0 +1,145 @@
+use common::prelude::*;
+use common::hex::*;
+use prelude::*;
+use data::entities::*;
+use logic;
+use rand::Rng;
+use rand::StdRng;
+use noisy_float::types::{R32, r32};
+use game::DebugData;
+use archetypes::characters::character_archetypes;
+
+
+/// a cap on how many creatures a single wave can bring, however large its budget has grown
+pub const MAX_WAVE_SIZE : usize = 12;
+
+/// Brings in whatever waves the spawner's spawns call for on the given turn. A spawn goes ahead on its schedule only if
+/// all of its conditions hold, and then brings as many creatures as its threat budget for the turn allows, scattered
+/// across the free hexes within its radius of the spawner
+pub fn spawn_waves(world : &mut World, spawner : Entity, turn_number : i32) {
+    let view = world.view();
+    let spawner_data = match view.data_opt::<MonsterSpawnerData>(spawner) {
+        Some(data) => data.clone(),
+        None => return
+    };
+    let spawner_pos = match view.data_opt::<PositionData>(spawner) {
+        Some(pos) => pos.hex,
+        None => return
+    };
+    let faction = view.data::<AllegianceData>(spawner).faction;
+
+    for spawn in &spawner_data.spawns {
+        let turns_since_start = turn_number - spawn.start_spawn_turn;
+        if turns_since_start < 0 || turns_since_start % (spawn.turns_between_spawns + 1) != 0 {
+            continue;
+        }
+        if ! spawn.conditions.iter().all(|c| condition_holds(world.view(), spawner, spawner_pos, c)) {
+            continue;
+        }
+
+        let mut rng = world.random(144);
+        let mut wave = wave_for(spawn, turns_since_start, &mut rng);
+        if let Some(room) = room_left(world.view(), spawner, spawn) {
+            wave.truncate(room);
+        }
+
+        let mut spawn_points = free_hexes_within(world.view(), spawner_pos, spawn.spawn_radius);
+        for archetype in wave {
+            if spawn_points.is_empty() {
+                warn!("No valid spawn locations");
+                break;
+            }
+            let hex = spawn_points.remove(rng.gen_range(0, spawn_points.len()));
+            let spawned = character_archetypes().with_name(archetype.as_str()).clone()
+                .with(AllegianceData { faction })
+                .with(DebugData { name : format!("spawned creature: {:?}", archetype) })
+                .with(PositionData { hex })
+                .create(world);
+            logic::movement::place_entity_in_world(world, spawned, hex);
+            world.modify(spawner, MonsterSpawnerData::spawned.append(spawned));
+        }
+    }
+}
+
+/// the archetypes making up a wave of the spawn, the given number of turns after it started
+pub fn wave_for(spawn : &Spawn, turns_since_start : i32, rng : &mut StdRng) -> Vec<String> {
+    let options = match spawn.entity {
+        SpawnEntity::Character(ref archetype) => vec![WeightedSpawn { archetype : archetype.clone(), weight : 1.0, threat : 1 }],
+        SpawnEntity::Pool(ref pool) => pool.clone(),
+    };
+
+    match spawn.threat_budget {
+        None => pick_weighted(&options.iter().collect_vec(), rng).map(|s| s.archetype.clone()).into_iter().collect(),
+        Some(budget) => {
+            let mut remaining = budget.at_turn(turns_since_start);
+            let mut wave = Vec::new();
+            while wave.len() < MAX_WAVE_SIZE {
+                let affordable = options.iter().filter(|s| s.threat > 0 && s.threat <= remaining).collect_vec();
+                match pick_weighted(&affordable, rng) {
+                    Some(picked) => {
+                        remaining -= picked.threat;
+                        wave.push(picked.archetype.clone());
+                    },
+                    None => break
+                }
+            }
+            wave
+        }
+    }
+}
+
+fn pick_weighted<'a>(options : &Vec<&'a WeightedSpawn>, rng : &mut StdRng) -> Option<&'a WeightedSpawn> {
+    let total : f32 = options.iter().map(|s| s.weight.max(0.0)).sum();
+    if total <= 0.0 {
+        return None;
+    }
+    let mut roll = rng.gen::<f32>() * total;
+    for option in options {
+        roll -= option.weight.max(0.0);
+        if roll <= 0.0 {
+            return Some(option);
+        }
+    }
+    options.last().cloned()
+}
+
+fn condition_holds(view : &WorldView, spawner : Entity, spawner_pos : AxialCoord, condition : &SpawnCondition) -> bool {
+    match condition {
+        SpawnCondition::TimeOfDay(times) => times.contains(&logic::time::time_of_day(view)),
+        SpawnCondition::Season(seasons) => seasons.contains(&logic::time::season(view)),
+        SpawnCondition::PlayerWithin(distance) => closest_player_distance(view, spawner_pos).map(|d| d <= r32(*distance)).unwrap_or(false),
+        SpawnCondition::PlayerBeyond(distance) => closest_player_distance(view, spawner_pos).map(|d| d > r32(*distance)).unwrap_or(true),
+        SpawnCondition::MaxAlive(max) => living_spawned(view, spawner) < *max,
+    }
+}
+
+/// how many more creatures the spawn can bring before running into its limit on how many may be alive at once
+fn room_left(view : &WorldView, spawner : Entity, spawn : &Spawn) -> Option<usize> {
+    let alive = living_spawned(view, spawner);
+    spawn.conditions.iter().filter_map(|c| match c {
+        SpawnCondition::MaxAlive(max) => Some((max - alive).max(0) as usize),
+        _ => None
+    }).min()
+}
+
+fn living_spawned(view : &WorldView, spawner : Entity) -> i32 {
+    view.data::<MonsterSpawnerData>(spawner).spawned.iter()
+        .filter(|s| view.data_opt::<CharacterData>(**s).map(|c| c.is_alive()).unwrap_or(false))
+        .count() as i32
+}
+
+fn closest_player_distance(view : &WorldView, from : AxialCoord) -> Option<R32> {
+    view.entities_with_data::<CharacterData>()
+        .filter(|(_, c)| c.is_alive())
+        .filter(|(c, _)| view.data_opt::<AllegianceData>(**c).and_then(|a| view.data_opt::<FactionData>(a.faction)).map(|f| f.player_faction).unwrap_or(false))
+        .map(|(c, _)| view.data::<PositionData>(*c).hex.distance(&from))
+        .min()
+}
+
+fn free_hexes_within(view : &WorldView, center : AxialCoord, radius : i32) -> Vec<AxialCoord> {
+    (1 ..= radius.max(1))
+        .flat_map(|r| CubeCoord::ring(center.as_cube_coord(), r as u32).collect_vec())
+        .map(|c| c.as_axial_coord())
+        .filter(|h| view.tile_ent_opt(*h).map(|t| t.occupied_by.is_none()).unwrap_or(false))
+        .collect()
+}
@@ -0,0 +1,70 @@
+use common::prelude::*;
+use prelude::*;
+
+use archetypes::*;
+use logic::test::testbed::in_testbed;
+use spectral::prelude::*;
+use logic;
+use common::Color;
+use game::events::CoreEvent;
+use data::entities::{AllegianceData, FactionData, PositionData, Visibility, VisibilityData};
+use std::collections::HashSet;
+
+
+#[test]
+pub fn test_factions_remember_where_they_last_saw_enemies() {
+    in_testbed(|world, testbed| {
+        let view = world.view();
+
+        let watchers = EntityBuilder::new().with(FactionData { name : strf("Watchers"), color : Color::new(1.0, 1.0, 1.0, 1.0), player_faction : false }).create(world);
+        let prowlers = EntityBuilder::new().with(FactionData { name : strf("Prowlers"), color : Color::new(0.0, 0.0, 0.0, 1.0), player_faction : false }).create(world);
+
+        let watcher = character_archetypes().with_name("human").clone()
+            .with(AllegianceData { faction : watchers })
+            .with(PositionData { hex : AxialCoord::new(0, 0) })
+            .create(world);
+        let prowler = character_archetypes().with_name("mud monster").clone()
+            .with(AllegianceData { faction : prowlers })
+            .with(PositionData { hex : AxialCoord::new(3, 0) })
+            .create(world);
+
+        // the watchers can see two hexes around the origin and nothing more
+        let visible_hexes : HashSet<AxialCoord> = (-2 ..= 2).flat_map(|q| (-2 ..= 2).map(move |r| AxialCoord::new(q, r)))
+            .filter(|h| h.distance(&AxialCoord::new(0, 0)) <= 2.0)
+            .collect();
+        world.modify_world(VisibilityData::visibility_by_faction.set_key_to(watchers, Visibility { revealed_hexes : visible_hexes.clone(), visible_hexes }), None);
+        world.add_event(CoreEvent::TimePassed);
+
+        logic::awareness::update_awareness(world, watchers);
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&logic::awareness::known_enemy_positions(view, watchers)).is_empty();
+
+        world.modify(prowler, PositionData::hex.set_to(AxialCoord::new(1, 0)));
+        world.add_event(CoreEvent::TimePassed);
+        logic::awareness::update_awareness(world, watchers);
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&logic::awareness::visible_enemies(view, watchers)).is_equal_to(vec![prowler]);
+
+        // once out of sight it's still remembered where it was
+        world.modify(prowler, PositionData::hex.set_to(AxialCoord::new(4, 0)));
+        world.add_event(CoreEvent::TimePassed);
+        logic::awareness::update_awareness(world, watchers);
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&logic::awareness::visible_enemies(view, watchers)).is_empty();
+        assert_that(&logic::awareness::known_enemy_positions(view, watchers)).is_equal_to(vec![(prowler, AxialCoord::new(1, 0))]);
+
+        // standing next to the spot isn't the same as looking there
+        world.modify(watcher, PositionData::hex.set_to(AxialCoord::new(1, -1)));
+        world.add_event(CoreEvent::TimePassed);
+        logic::awareness::update_awareness(world, watchers);
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&logic::awareness::known_enemy_positions(view, watchers)).is_equal_to(vec![(prowler, AxialCoord::new(1, 0))]);
+
+        // until someone goes to look and finds nothing there
+        world.modify(watcher, PositionData::hex.set_to(AxialCoord::new(1, 0)));
+        world.add_event(CoreEvent::TimePassed);
+        logic::awareness::update_awareness(world, watchers);
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&logic::awareness::known_enemy_positions(view, watchers)).is_empty();
+    })
+}
@@ -2,6 +2,7 @@ use common::prelude::*;
 use prelude::*;
 
 use archetypes::*;
+use logic::test::testbed;
 use logic::test::testbed::in_testbed;
 use spectral::prelude::*;
 use logic;
@@ -132,4 +133,287 @@ pub fn test_derived_attack() {
             }
         }
     });
-}
\ No newline at end of file
+}
+
+#[test]
+pub fn test_worn_item_can_be_repaired_and_breaks() {
+    use data::entities::DurabilityData;
+    use data::entities::Resources;
+    use data::entities::recipes::{Recipe, RecipeFlavor};
+    use std::collections::HashMap;
+
+    in_testbed(|world, testbed| {
+        let view = world.view();
+        let character = character_archetypes().with_name("human").create(world);
+
+        let item_catalog = Catalog::of::<ItemArchetype>(world.view(), Entity::sentinel());
+        let hatchet = logic::crafting::craft_without_materials(world, item_catalog.entity_with_name("hatchet"));
+        logic::item::put_item_in_inventory(world, hatchet, character);
+        logic::item::equip_item(world, hatchet, character, true);
+
+        let max_durability = view.data::<DurabilityData>(hatchet).durability.max_value();
+        logic::item::wear_item(world, hatchet, max_durability / 2 + 1);
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&view.data::<DurabilityData>(hatchet).wear_penalty()).is_greater_than(&0);
+
+        let repair_recipe = view.entities_with_data::<Recipe>()
+            .find(|(_, r)| if let RecipeFlavor::Repair { .. } = r.flavor { true } else { false })
+            .map(|(e, _)| *e)
+            .expect("a repair recipe should exist");
+
+        let wood = world.clone_entity(view.world_data::<Resources>().main.wood);
+        let mut ingredients = HashMap::new();
+        ingredients.insert(Taxon::of(&taxonomy::ingredient_types::RepairTarget), vec![hatchet]);
+        ingredients.insert(Taxon::of(&taxonomy::ingredient_types::RepairMaterial), vec![wood]);
+        assert_that(&logic::crafting::craft(world, character, &ingredients, repair_recipe)).is_ok();
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&view.data::<DurabilityData>(hatchet).durability.cur_value()).is_equal_to(&(max_durability - (max_durability / 2 + 1) + 5));
+
+        // wearing it out completely breaks it, taking it out of the character's hands
+        assert_that(&logic::item::wear_item(world, hatchet, max_durability)).is_true();
+        assert_that(&logic::item::equipped_items(view, character).contains(&hatchet)).is_false();
+        assert_that(&logic::item::wear_item(world, hatchet, 1)).is_false();
+    })
+}
+
+#[test]
+pub fn test_dead_monster_drops_loot_that_can_be_picked_up() {
+    use data::entities::{Resources, CharacterData, PositionData, EntityMetadata};
+
+    in_testbed(|world, testbed| {
+        let view = world.view();
+        let monster_pos = AxialCoord::new(0, 0);
+        let monster = character_archetypes().with_name("mud monster").clone().with(PositionData { hex : monster_pos }).create(world);
+        let character = character_archetypes().with_name("human").clone()
+            .with(CharacterData { action_points : Reduceable::new(8), health : Reduceable::new(20), ..Default::default() })
+            .with(PositionData { hex : AxialCoord::new(1, 0) })
+            .create(world);
+
+        assert_that(&logic::loot::ground_items_at(view, monster_pos)).is_empty();
+        logic::character::apply_damage_to_character(world, monster, 100, &[]);
+        world.add_event(CoreEvent::TimePassed);
+
+        // mud monsters always leave some clay behind
+        let clay = view.world_data::<Resources>().main.clay;
+        let dropped = logic::loot::ground_items_at(view, monster_pos);
+        assert_that(&dropped.iter().any(|i| view.data_opt::<EntityMetadata>(*i).map(|m| m.archetype == clay).unwrap_or(false))).is_true();
+
+        logic::loot::pick_up(world, character, monster_pos);
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&logic::loot::ground_items_at(view, monster_pos)).is_empty();
+        for item in dropped {
+            assert_that(&logic::item::is_item_in_inventory_of(view, item, character)).is_true();
+        }
+        assert_that(&view.data::<CharacterData>(character).action_points.cur_value()).is_equal_to(&(8 - logic::loot::PICK_UP_AP_COST));
+    })
+}
+
+#[test]
+pub fn test_skilled_crafters_make_better_items() {
+    use data::entities::{Resources, SkillData, Skill, QualityData, ItemQuality};
+    use data::entities::recipes::{Recipe, RecipeFlavor};
+    use data::archetype::EntityArchetype;
+    use std::collections::HashMap;
+
+    in_testbed(|world, testbed| {
+        let view = world.view();
+        let mut skill_bonuses = HashMap::new();
+        skill_bonuses.insert(Skill::Crafting, 10);
+        let crafter = character_archetypes().with_name("human").clone().with(SkillData { skill_bonuses, ..Default::default() }).create(world);
+
+        let item_catalog = Catalog::of::<ItemArchetype>(view, Entity::sentinel());
+        let hatchet_arch = item_catalog.entity_with_name("hatchet");
+        let hatchet_recipe = view.entities_with_data::<Recipe>()
+            .find(|(_, r)| r.result == EntityArchetype::Archetype(hatchet_arch))
+            .map(|(e, _)| *e)
+            .expect("a hatchet recipe should exist");
+
+        let resources = view.world_data::<Resources>().main.clone();
+        let mut ingredients = HashMap::new();
+        ingredients.insert(Taxon::of(&taxonomy::ingredient_types::Haft), vec![world.clone_entity(resources.wood), world.clone_entity(resources.wood)]);
+        ingredients.insert(Taxon::of(&taxonomy::ingredient_types::Axehead), vec![world.clone_entity(resources.quarried_stone), world.clone_entity(resources.quarried_stone)]);
+
+        let hatchet = logic::crafting::craft(world, crafter, &ingredients, hatchet_recipe).expect("crafting should succeed");
+        world.add_event(CoreEvent::TimePassed);
+
+        // a skill bonus that large outweighs even the worst roll on a recipe this simple
+        let quality = view.data::<QualityData>(hatchet).quality;
+        assert_that(&quality).is_greater_than_or_equal_to(&ItemQuality::Fine);
+        assert_that(&view.data::<IdentityData>(hatchet).effective_name().starts_with(quality.name())).is_true();
+        assert_that(&view.data::<SkillData>(crafter).cur_skill_xp(Skill::Crafting)).is_greater_than(&0);
+    })
+}
+
+#[test]
+pub fn test_interrupted_crafting_keeps_its_progress() {
+    use data::entities::{Resources, CharacterData, ActionData};
+    use data::entities::recipes::Recipe;
+    use data::archetype::EntityArchetype;
+    use std::collections::HashMap;
+
+    in_testbed(|world, testbed| {
+        let view = world.view();
+        let crafter = character_archetypes().with_name("human").clone()
+            .with(CharacterData { action_points : Reduceable::new(8), health : Reduceable::new(20), ..Default::default() })
+            .create(world);
+
+        let item_catalog = Catalog::of::<ItemArchetype>(view, Entity::sentinel());
+        let hatchet_arch = item_catalog.entity_with_name("hatchet");
+        let hatchet_recipe = view.entities_with_data::<Recipe>()
+            .find(|(_, r)| r.result == EntityArchetype::Archetype(hatchet_arch))
+            .map(|(e, _)| *e)
+            .expect("a hatchet recipe should exist");
+
+        let resources = view.world_data::<Resources>().main.clone();
+        let mut ingredients = HashMap::new();
+        ingredients.insert(Taxon::of(&taxonomy::ingredient_types::Haft), vec![world.clone_entity(resources.wood), world.clone_entity(resources.wood)]);
+        ingredients.insert(Taxon::of(&taxonomy::ingredient_types::Axehead), vec![world.clone_entity(resources.quarried_stone), world.clone_entity(resources.quarried_stone)]);
+
+        // a hatchet takes more than a single turn's worth of action points
+        assert_that(&logic::crafting::craft_over_time(world, crafter, &ingredients, hatchet_recipe, None)).is_equal_to(&Ok(None));
+        assert_that(&view.data::<ActionData>(crafter).active_action.is_some()).is_true();
+
+        logic::character::apply_damage_to_character(world, crafter, 1, &[]);
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&view.data::<ActionData>(crafter).active_action.is_none()).is_true();
+        assert_that(&view.data::<ActionData>(crafter).interrupted_action.as_ref().map(|a| a.ap.current)).is_equal_to(&Some(8));
+
+        // starting the same craft again picks up where it left off, so a second turn's action points finish it
+        world.modify(crafter, CharacterData::action_points.reset());
+        world.add_event(CoreEvent::TimePassed);
+        let crafted = logic::crafting::craft_over_time(world, crafter, &ingredients, hatchet_recipe, None);
+        assert_that(&crafted.map(|c| c.is_some())).is_equal_to(&Ok(true));
+        assert_that(&view.data::<ActionData>(crafter).interrupted_action.is_none()).is_true();
+    })
+}
+
+#[test]
+pub fn test_granting_xp_levels_up_skills_and_unlocks_recipes() {
+    use data::entities::{SkillData, Skill};
+
+    in_testbed(|world, testbed| {
+        let view = world.view();
+        let item_catalog = Catalog::of::<ItemArchetype>(view, Entity::sentinel());
+        let longsword_recipe = logic::recipes::recipe_for(view, item_catalog.entity_with_name("longsword")).expect("a longsword recipe should exist");
+
+        let apprentice = character_archetypes().with_name("human").clone().create(world);
+        assert_that(&logic::skill::skill_level(view, apprentice, Skill::Crafting)).is_equal_to(&0);
+
+        logic::skill::grant_xp(world, apprentice, Skill::Crafting, logic::skill::xp_required_for_level(2));
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&logic::skill::skill_level(view, apprentice, Skill::Crafting)).is_equal_to(&2);
+        assert_that(&view.data::<SkillData>(apprentice).cur_skill_xp(Skill::Crafting)).is_equal_to(&logic::skill::xp_required_for_level(2));
+        // the longsword recipe is worked out through practice, reaching the required level should be enough
+        assert_that(&logic::recipes::knows_recipe(view, apprentice, longsword_recipe)).is_true();
+
+        // non-positive grants are ignored rather than draining xp
+        logic::skill::grant_xp(world, apprentice, Skill::Crafting, -5);
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&logic::skill::skill_level(view, apprentice, Skill::Crafting)).is_equal_to(&2);
+    })
+}
+
+#[test]
+pub fn test_reaching_skill_levels_grants_perks() {
+    use data::entities::{Skill, perks};
+
+    in_testbed(|world, testbed| {
+        let view = world.view();
+        let fighter = character_archetypes().with_name("human").clone().create(world);
+        let starting_dodge = view.data::<CombatData>(fighter).dodge_bonus;
+
+        assert_that(&logic::perks::upcoming_perks(view, fighter).is_empty()).is_true();
+
+        logic::skill::grant_xp(world, fighter, Skill::Melee, logic::skill::xp_required_for_level(3));
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&logic::perks::has_perk(view, fighter, perks::PowerAttack)).is_true();
+        assert_that(&logic::perks::has_perk(view, fighter, perks::Riposte)).is_false();
+        assert_that(&view.data::<CombatData>(fighter).special_attacks.len()).is_equal_to(1);
+        assert_that(&logic::perks::upcoming_perks(view, fighter)).is_equal_to(vec![perks::Riposte]);
+
+        logic::skill::grant_xp(world, fighter, Skill::Dodge, logic::skill::xp_required_for_level(3));
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&view.data::<CombatData>(fighter).dodge_bonus).is_equal_to(starting_dodge + 1);
+
+        // perks are only ever granted once, however many times the check is made
+        logic::perks::grant_earned_perks(world, fighter);
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&view.data::<CombatData>(fighter).special_attacks.len()).is_equal_to(1);
+    })
+}
+
+#[test]
+pub fn test_losing_allies_and_health_breaks_morale() {
+    use data::entities::{FactionData, MoraleData, MoraleState};
+
+    in_testbed(|world, testbed| {
+        let view = world.view();
+        let faction = EntityBuilder::new().with(FactionData { name : strf("Bandits"), ..Default::default() }).create(world);
+
+        let unlucky = testbed::human(faction, AxialCoord::new(0, 0)).create(world);
+        let survivor = testbed::human(faction, AxialCoord::new(2, 0)).create(world);
+        let far_away = testbed::human(faction, AxialCoord::new(8, 0)).create(world);
+        let player_character = testbed::human(testbed.player_faction, AxialCoord::new(0, 1)).create(world);
+
+        logic::character::apply_damage_to_character(world, unlucky, 100, &[]);
+        world.add_event(CoreEvent::TimePassed);
+        // only those close enough to see it are shaken
+        assert_that(&view.data::<MoraleData>(survivor).morale.cur_value()).is_equal_to(7);
+        assert_that(&view.data::<MoraleData>(far_away).morale.cur_value()).is_equal_to(10);
+
+        // the player's own people never lose their nerve, however badly things go
+        logic::character::apply_damage_to_character(world, player_character, 15, &[]);
+        logic::morale::adjust_morale(world, player_character, -10, "test");
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&view.data::<MoraleData>(player_character).morale.cur_value()).is_equal_to(10);
+        assert_that(&logic::morale::morale_state(view, player_character)).is_equal_to(MoraleState::Steady);
+
+        // being brought below half health on top of that is enough to make them run
+        logic::character::apply_damage_to_character(world, survivor, 11, &[]);
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&logic::morale::morale_state(view, survivor)).is_equal_to(MoraleState::Fleeing);
+
+        // and with nothing left they give up
+        logic::morale::adjust_morale(world, survivor, -10, "test");
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&logic::morale::has_surrendered(view, survivor)).is_true();
+    })
+}
+
+#[test]
+pub fn test_neutral_factions_turn_hostile_when_attacked() {
+    use data::entities::{FactionData, Relationship};
+
+    in_testbed(|world, testbed| {
+        let view = world.view();
+        let hunters = EntityBuilder::new().with(FactionData { name : strf("Hunters"), ..Default::default() }).create(world);
+        let wildlife = EntityBuilder::new().with(FactionData { name : strf("Wildlife"), ..Default::default() }).create(world);
+        let camp = EntityBuilder::new().with(FactionData { name : strf("Camp"), ..Default::default() }).create(world);
+        logic::faction::set_default_relationship(world, wildlife, Relationship::Neutral);
+        logic::faction::set_relationship(world, hunters, camp, Relationship::Allied);
+        world.add_event(CoreEvent::TimePassed);
+
+        let hunter = testbed::human(hunters, AxialCoord::new(0, 0)).create(world);
+        let beast = testbed::human(wildlife, AxialCoord::new(1, 0)).create(world);
+        let camper = testbed::human(camp, AxialCoord::new(0, 1)).create(world);
+
+        assert_that(&logic::faction::is_ally(view, hunter, camper)).is_true();
+        assert_that(&logic::faction::is_enemy(view, hunter, beast)).is_false();
+        assert_that(&logic::faction::is_enemy(view, camper, beast)).is_false();
+
+        let attack = logic::combat::default_attack(view, hunter);
+        logic::combat::handle_attack(world, hunter, beast, &attack);
+        world.add_event(CoreEvent::TimePassed);
+
+        // the hunters have provoked the wildlife, but it has no quarrel with their allies
+        assert_that(&logic::faction::is_enemy(view, hunter, beast)).is_true();
+        assert_that(&logic::faction::is_enemy(view, camper, beast)).is_false();
+
+        // a neutral faction can't keep the peace with one that is out for blood
+        let raiders = EntityBuilder::new().with(FactionData { name : strf("Raiders"), ..Default::default() }).create(world);
+        logic::faction::set_default_relationship(world, raiders, Relationship::Hostile);
+        let raider = testbed::human(raiders, AxialCoord::new(-1, 0)).create(world);
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&logic::faction::is_enemy(view, raider, beast)).is_true();
+    })
+}
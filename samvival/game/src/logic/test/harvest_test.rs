@@ -1,6 +1,10 @@
 use common::prelude::*;
 use prelude::*;
 use logic::test::testbed::*;
+use spectral::prelude::*;
+use logic;
+use data::entities::*;
+use std::collections::HashMap;
 
 
 #[test]
@@ -8,4 +12,47 @@ pub fn test_harvesting_basics() {
     in_testbed(|world, testbed| {
 
     });
-}
\ No newline at end of file
+}
+
+#[test]
+pub fn test_depleted_forest_regrows() {
+    in_testbed(|world, _testbed| {
+        let view = world.view();
+
+        let wood = EntityBuilder::new().with(Harvestable {
+            action_name : strf("chop wood"),
+            amount : Reduceable::new(Sext::of(2)),
+            renew_rate : Some(RenewRate::new(Sext::of(1)).fertility_dependent()),
+            on_depletion : DepletionBehavior::RemoveLayer,
+            .. Default::default()
+        }).create(world);
+
+        let mut harvestables = HashMap::new();
+        harvestables.insert(strf("wood"), wood);
+        let tile = EntityBuilder::new()
+            .with(TileData::default())
+            .with(TerrainData { fertility : BASELINE_FERTILITY, .. Default::default() })
+            .with(VegetationData {
+                harvestables,
+                move_cost : Sext::of_parts(0,3),
+                cover : 2,
+                kind : Taxon::of(&taxonomy::vegetation::DeciduousForest)
+            })
+            .create(world);
+
+        world.modify(wood, Harvestable::amount.reduce_by(Sext::of(2)));
+        logic::harvest::deplete(world, wood, tile);
+        world.add_event(GameEvent::HarvestablesRenewed);
+        assert_that(&view.data::<VegetationData>(tile).kind).is_equal_to(&Taxon::of(&taxonomy::vegetation::Grassland));
+        assert_that(&view.data::<VegetationData>(tile).harvestables.is_empty()).is_true();
+
+        logic::harvest::renew_harvestables(world);
+        assert_that(&view.data::<Harvestable>(wood).amount.cur_value()).is_equal_to(&Sext::of(1));
+        assert_that(&view.data::<VegetationData>(tile).kind).is_equal_to(&Taxon::of(&taxonomy::vegetation::Grassland));
+
+        logic::harvest::renew_harvestables(world);
+        assert_that(&view.data::<Harvestable>(wood).amount.cur_value()).is_equal_to(&Sext::of(2));
+        assert_that(&view.data::<VegetationData>(tile).kind).is_equal_to(&Taxon::of(&taxonomy::vegetation::DeciduousForest));
+        assert_that(&view.data::<Harvestable>(wood).depletion.is_none()).is_true();
+    });
+}
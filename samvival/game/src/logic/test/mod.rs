@@ -1,4 +1,14 @@
 #[cfg(test)] mod combat_test;
-#[cfg(test)] mod testbed;
+#[cfg(any(test, feature = "testbed"))] pub mod testbed;
 #[cfg(test)] mod movement;
-#[cfg(test)] mod harvest_test;
\ No newline at end of file
+#[cfg(test)] mod harvest_test;
+#[cfg(test)] mod time_test;
+#[cfg(test)] mod terrain_test;
+#[cfg(test)] mod awareness_test;
+#[cfg(test)] mod objectives_test;
+#[cfg(test)] mod triggers_test;
+#[cfg(test)] mod spawning_test;
+#[cfg(test)] mod needs_test;
+#[cfg(test)] mod weather_test;
+#[cfg(test)] mod recipes_test;
+#[cfg(test)] mod skill_test;
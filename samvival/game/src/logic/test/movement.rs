@@ -6,6 +6,7 @@ use archetypes::*;
 use logic;
 use std::time::Instant;
 use std::time::Duration;
+use spectral::prelude::*;
 
 
 
@@ -25,4 +26,81 @@ pub fn flood_search_performance_test() {
 
         println!("Took {:?} to identify {} hexes", duration, hexes.len());
     });
-}
\ No newline at end of file
+}
+#[test]
+pub fn test_heavy_load_slows_character() {
+    use data::entities::{Resources, Encumbrance, CharacterData};
+    use data::entities::movement::MovementData;
+    use game::events::CoreEvent;
+
+    in_testbed(|world, testbed| {
+        let view = world.view();
+        let character = character_archetypes().with_name("human").clone().with(CharacterData { health : Reduceable::new(45), ..Default::default() }).create(world);
+        let base_move_speed = view.data::<MovementData>(character).move_speed;
+
+        let stone = view.world_data::<Resources>().main.quarried_stone;
+        let mut stones = Vec::new();
+        for _ in 0 .. 5 {
+            let new_stone = world.clone_entity(stone);
+            logic::item::put_item_in_inventory(world, new_stone, character);
+            stones.push(new_stone);
+        }
+
+        assert_that(&logic::item::encumbrance(view, character)).is_equal_to(&Encumbrance::Burdened);
+        assert_that(&view.data::<MovementData>(character).move_speed).is_equal_to(&(base_move_speed - Encumbrance::Burdened.move_speed_penalty()));
+
+        // dropping the load removes the penalty again
+        for stone in stones {
+            logic::item::remove_item_from_inventory(world, stone, character);
+        }
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&logic::item::encumbrance(view, character)).is_equal_to(&Encumbrance::Unencumbered);
+        assert_that(&view.data::<MovementData>(character).move_speed).is_equal_to(&base_move_speed);
+    });
+}
+
+#[test]
+pub fn test_bags_extend_carry_capacity_and_hold_items() {
+    use data::entities::{Resources, CharacterData, ItemArchetype, Catalog};
+    use game::events::CoreEvent;
+
+    in_testbed(|world, testbed| {
+        let view = world.view();
+        let character = character_archetypes().with_name("human").clone().with(CharacterData { health : Reduceable::new(45), ..Default::default() }).create(world);
+        let base_capacity = logic::item::carry_capacity(view, character);
+
+        let item_catalog = Catalog::of::<ItemArchetype>(view, Entity::sentinel());
+        let bag = logic::crafting::craft_without_materials(world, item_catalog.entity_with_name("bag"));
+        let chest = logic::crafting::craft_without_materials(world, item_catalog.entity_with_name("chest"));
+        assert_that(&logic::item::put_item_in_inventory(world, bag, character)).is_true();
+        assert_that(&logic::item::carry_capacity(view, character)).is_greater_than(&base_capacity);
+
+        // what goes in the bag still counts toward the character's load
+        let weight_before = logic::item::inventory_weight(view, character);
+        let stone = world.clone_entity(view.world_data::<Resources>().main.quarried_stone);
+        assert_that(&logic::item::put_item_in_inventory(world, stone, bag)).is_true();
+        assert_that(&logic::item::inventory_weight(view, character)).is_equal_to(&(weight_before + logic::item::item_weight(view, stone)));
+
+        // a bag packed inside another bag still spreads the load
+        let inner_bag = logic::crafting::craft_without_materials(world, item_catalog.entity_with_name("bag"));
+        assert_that(&logic::item::put_item_in_inventory(world, inner_bag, bag)).is_true();
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&logic::item::carried_containers(view, character)).is_equal_to(&vec![bag, inner_bag]);
+        assert_that(&logic::item::carry_capacity(view, character)).is_equal_to(&(base_capacity + 8));
+
+        // and moving what's in the outer bag into the inner one doesn't change what the character is hauling
+        let weight_before = logic::item::inventory_weight(view, character);
+        assert_that(&logic::item::transfer_item(world, stone, bag, inner_bag)).is_equal_to(&logic::item::TransferResult::All);
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&logic::item::is_held_within(view, stone, character)).is_true();
+        assert_that(&logic::item::inventory_weight(view, character)).is_equal_to(&weight_before);
+
+        // but no container can end up inside itself
+        assert_that(&logic::item::put_item_in_inventory(world, bag, bag)).is_false();
+        assert_that(&logic::item::put_item_in_inventory(world, bag, inner_bag)).is_false();
+        // while a chest will happily take a bag
+        assert_that(&logic::item::put_item_in_inventory(world, chest, character)).is_true();
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&logic::item::transfer_item(world, bag, character, chest)).is_equal_to(&logic::item::TransferResult::All);
+    });
+}
@@ -0,0 +1,117 @@
+use common::prelude::*;
+use prelude::*;
+
+use logic::test::testbed;
+use logic::test::testbed::in_testbed;
+use spectral::prelude::*;
+use logic;
+use game::events::CoreEvent;
+use data::entities::{NeedsData, Need, NeedSeverity, Resources, Catalog, ItemArchetype, TimeData, TimeOfDay};
+
+
+#[test]
+pub fn test_needs_run_down_and_are_restored() {
+    in_testbed(|world, testbed| {
+        let view = world.view();
+
+        let character = testbed::human(testbed.player_faction, AxialCoord::new(0, 0)).create(world);
+        world.add_event(CoreEvent::TimePassed);
+
+        // going hungry long enough starts costing action points
+        world.modify(character, NeedsData::satiation.reduce_by(60));
+        world.add_event(CoreEvent::TimePassed);
+        logic::needs::update_needs(world, character);
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&view.data::<NeedsData>(character).satiation.cur_value()).is_equal_to(3);
+        assert_that(&logic::needs::severity(view, character, Need::Hunger)).is_equal_to(NeedSeverity::Suffering);
+        assert_that(&view.data::<CharacterData>(character).action_points.cur_value()).is_equal_to(7);
+
+        // and eating something fixes that
+        let fruit = world.clone_entity(view.world_data::<Resources>().main.fruit);
+        logic::item::put_item_in_inventory(world, fruit, character);
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&logic::needs::consume(world, character, fruit)).is_true();
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&view.data::<NeedsData>(character).satiation.cur_value()).is_equal_to(9);
+        assert_that(&logic::item::items_in_inventory(view, character).contains(&fruit)).is_false();
+
+        // left with nothing at all, a character starts to waste away
+        world.modify(character, NeedsData::satiation.reduce_by(9));
+        world.add_event(CoreEvent::TimePassed);
+        logic::needs::update_needs(world, character);
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&view.data::<CharacterData>(character).health.cur_value()).is_equal_to(18);
+    })
+}
+
+#[test]
+pub fn test_the_night_makes_hungry_work() {
+    in_testbed(|world, _testbed| {
+        let view = world.view();
+        assert_that(&logic::needs::hunger_rate(view)).is_equal_to(1);
+
+        world.ensure_world_data::<TimeData>();
+        world.add_event(CoreEvent::TimePassed);
+        while logic::time::time_of_day(view) != TimeOfDay::Night {
+            logic::time::advance_time(world, 1);
+            world.add_event(CoreEvent::TimePassed);
+        }
+        // staying warm through the dark costs food, but whatever the weather did in the day, the night is cool enough
+        assert_that(&logic::needs::hunger_rate(view)).is_equal_to(2);
+        assert_that(&logic::needs::thirst_rate(view)).is_equal_to(1);
+    })
+}
+
+#[test]
+pub fn test_campfires_warm_those_near_them() {
+    in_testbed(|world, testbed| {
+        let view = world.view();
+
+        let character = testbed::human(testbed.player_faction, AxialCoord::new(0, 0)).create(world);
+        let item_catalog = Catalog::of::<ItemArchetype>(world.view(), Entity::sentinel());
+        let campfire = logic::crafting::craft_without_materials(world, item_catalog.entity_with_name("campfire"));
+        logic::item::put_item_in_inventory(world, campfire, character);
+        world.add_event(CoreEvent::TimePassed);
+
+        let near = AxialCoord::new(2, 0);
+        let far = AxialCoord::new(-6, 0);
+        // a campfire still being carried about warms no one
+        assert_that(&logic::needs::is_near_fire(view, near)).is_false();
+
+        logic::building::build(world, character, campfire, AxialCoord::new(1, 0));
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&logic::needs::is_near_fire(view, near)).is_true();
+        assert_that(&logic::needs::is_near_fire(view, far)).is_false();
+        assert_that(&(logic::needs::warmth_change(view, near) - logic::needs::warmth_change(view, far))).is_equal_to(logic::needs::FIRE_WARMTH);
+    })
+}
+
+#[test]
+pub fn test_shelters_must_be_built_to_be_of_use() {
+    in_testbed(|world, testbed| {
+        let view = world.view();
+
+        let here = AxialCoord::new(0, 0);
+        let character = testbed::human(testbed.player_faction, here).create(world);
+        let item_catalog = Catalog::of::<ItemArchetype>(world.view(), Entity::sentinel());
+        let lean_to = logic::crafting::craft_without_materials(world, item_catalog.entity_with_name("lean-to"));
+        logic::item::put_item_in_inventory(world, lean_to, character);
+        world.add_event(CoreEvent::TimePassed);
+
+        // carried about it's just a bundle of poles and thatch
+        assert_that(&logic::needs::is_sheltered(view, here)).is_false();
+        assert_that(&logic::building::unbuilt_structures(view, character)).is_equal_to(vec![lean_to]);
+        assert_that(&logic::building::can_build(view, character, lean_to, AxialCoord::new(3, 0)).0).is_false();
+
+        let ap_before = view.data::<CharacterData>(character).action_points.cur_value();
+        logic::building::build(world, character, lean_to, here);
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&logic::needs::is_sheltered(view, here)).is_true();
+        assert_that(&logic::building::structures_at(view, here)).is_equal_to(vec![lean_to]);
+        assert_that(&view.data::<CharacterData>(character).action_points.cur_value()).is_less_than(ap_before);
+
+        // once up, it stays where it was built
+        assert_that(&logic::loot::can_pick_up(view, character, here).0).is_false();
+        assert_that(&logic::item::items_in_inventory(view, character).contains(&lean_to)).is_false();
+    })
+}
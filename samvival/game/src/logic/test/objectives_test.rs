@@ -0,0 +1,46 @@
+use common::prelude::*;
+use prelude::*;
+
+use logic::test::testbed;
+use logic::test::testbed::in_testbed;
+use spectral::prelude::*;
+use logic;
+use game::events::CoreEvent;
+use data::entities::{FactionData, PositionData, ObjectiveData, Objective, ObjectiveCondition, Outcome, TurnData};
+
+
+#[test]
+pub fn test_objectives_end_the_game_once_met() {
+    in_testbed(|world, testbed| {
+        let view = world.view();
+
+        let scouts = EntityBuilder::new()
+            .with(FactionData { name : strf("Scouts"), ..Default::default() })
+            .with(ObjectiveData::new(vec![
+                Objective::new("Reach the ridge", ObjectiveCondition::ReachHex(AxialCoord::new(3, 0)), Outcome::Victory),
+                Objective::new("Hold out until nightfall", ObjectiveCondition::SurviveTurns(2), Outcome::Victory),
+                Objective::new("Lose everyone", ObjectiveCondition::AllCharactersLost, Outcome::Defeat),
+            ]))
+            .create(world);
+        let scout = testbed::human(scouts, AxialCoord::new(0, 0)).create(world);
+
+        logic::objectives::update_objectives(world, scouts, &GameEvent::WorldStart);
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&view.data::<ObjectiveData>(scouts).objectives.iter().any(|o| o.completed)).is_false();
+
+        world.modify(scout, PositionData::hex.set_to(AxialCoord::new(3, 0)));
+        world.add_event(CoreEvent::TimePassed);
+        logic::objectives::update_objectives(world, scouts, &GameEvent::WorldStart);
+        world.add_event(CoreEvent::TimePassed);
+
+        // the ridge stays reached, but there's still the wait for nightfall
+        assert_that(&view.data::<ObjectiveData>(scouts).objectives[0].completed).is_true();
+        assert_that(&logic::objectives::outcome(view, scouts)).is_none();
+
+        world.modify_world(TurnData::turn_number.set_to(2), None);
+        world.add_event(CoreEvent::TimePassed);
+        logic::objectives::update_objectives(world, scouts, &GameEvent::WorldStart);
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&logic::objectives::outcome(view, scouts)).is_equal_to(Some(Outcome::Victory));
+    })
+}
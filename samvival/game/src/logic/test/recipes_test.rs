@@ -0,0 +1,40 @@
+use common::prelude::*;
+use prelude::*;
+
+use archetypes::*;
+use logic::test::testbed::in_testbed;
+use spectral::prelude::*;
+use logic;
+use game::events::CoreEvent;
+use data::entities::Catalog;
+use data::entities::ItemArchetype;
+
+
+#[test]
+pub fn test_recipes_are_discovered_from_schematics_and_skill() {
+    use data::entities::{SkillData, Skill};
+
+    in_testbed(|world, testbed| {
+        let view = world.view();
+        let item_catalog = Catalog::of::<ItemArchetype>(view, Entity::sentinel());
+        let longsword_recipe = logic::recipes::recipe_for(view, item_catalog.entity_with_name("longsword")).expect("a longsword recipe should exist");
+        let hatchet_recipe = logic::recipes::recipe_for(view, item_catalog.entity_with_name("hatchet")).expect("a hatchet recipe should exist");
+
+        let reader = character_archetypes().with_name("human").clone().create(world);
+        assert_that(&logic::recipes::knows_recipe(view, reader, hatchet_recipe)).is_true();
+        assert_that(&logic::recipes::knows_recipe(view, reader, longsword_recipe)).is_false();
+        assert_that(&logic::crafting::can_entity_craft_recipe(view, reader, longsword_recipe).is_err()).is_true();
+
+        let schematic = logic::recipes::create_schematic(world, longsword_recipe);
+        logic::item::put_item_in_inventory(world, schematic, reader);
+        assert_that(&logic::recipes::knows_recipe(view, reader, longsword_recipe)).is_true();
+        assert_that(&logic::recipes::known_root_recipes(view, reader).contains(&longsword_recipe)).is_true();
+
+        // practice gets there too, without needing anyone's notes
+        let practiced = character_archetypes().with_name("human").clone().create(world);
+        world.modify(practiced, SkillData::skill_bonuses.add_to_key(Skill::Crafting, 2));
+        world.add_event(CoreEvent::TimePassed);
+        logic::recipes::discover_recipes(world, practiced);
+        assert_that(&logic::recipes::knows_recipe(view, practiced, longsword_recipe)).is_true();
+    })
+}
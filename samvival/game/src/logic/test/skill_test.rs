@@ -0,0 +1,133 @@
+use common::prelude::*;
+use prelude::*;
+
+use logic::test::testbed;
+use logic::test::testbed::{in_testbed, Testbed};
+use spectral::prelude::*;
+use logic;
+use game::events::CoreEvent;
+use data::entities::*;
+use data::entities::attributes::{attributes, increase_attribute};
+use std::collections::HashMap;
+
+
+/// a human sturdy enough to take a few blows over the course of a test
+fn create_fighter(world : &mut World, testbed : &Testbed, hex : AxialCoord) -> Entity {
+    testbed::human(testbed.player_faction, hex)
+        .with(CharacterData { health : Reduceable::new(100), action_points : Reduceable::new(8), ..Default::default() })
+        .create(world)
+}
+
+fn weapon_xp(view : &WorldView, character : Entity) -> i32 {
+    let skills = view.data::<SkillData>(character);
+    skills.cur_skill_xp(Skill::Melee) + skills.cur_skill_xp(Skill::Ranged)
+}
+
+#[test]
+pub fn test_training_weapons_grant_bonus_xp() {
+    in_testbed(|world, testbed| {
+        let view = world.view();
+        let item_catalog = Catalog::of::<ItemArchetype>(view, Entity::sentinel());
+
+        let attacker = create_fighter(world, &testbed, AxialCoord::new(0, 0));
+        let defender = create_fighter(world, &testbed, AxialCoord::new(1, 0));
+
+        let hatchet = logic::crafting::craft_without_materials(world, item_catalog.entity_with_name("hatchet"));
+        logic::item::put_item_in_inventory(world, hatchet, attacker);
+        logic::item::equip_item(world, hatchet, attacker, true);
+        world.add_event(CoreEvent::TimePassed);
+
+        // an ordinary weapon teaches the usual single point per attack
+        let attack = logic::combat::default_attack(view, attacker);
+        logic::combat::handle_attack(world, attacker, defender, &attack);
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&weapon_xp(view, attacker)).is_equal_to(&1);
+
+        // a training weapon adds its bonus on top of that, per point of the attribute
+        increase_attribute(world, hatchet, &attributes::TrainingWeapon, 2);
+        world.modify(attacker, CharacterData::action_points.reset());
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&logic::skill::weapon_xp_bonus(view, hatchet)).is_equal_to(&2);
+        let attack = logic::combat::default_attack(view, attacker);
+        logic::combat::handle_attack(world, attacker, defender, &attack);
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&weapon_xp(view, attacker)).is_equal_to(&(1 + 1 + 2));
+    })
+}
+
+#[test]
+pub fn test_dodging_an_attack_grants_dodge_xp() {
+    in_testbed(|world, testbed| {
+        let view = world.view();
+        let attacker = create_fighter(world, &testbed, AxialCoord::new(0, 0));
+        let defender = create_fighter(world, &testbed, AxialCoord::new(1, 0));
+        // no roll of the dice can get past a dodge bonus this large
+        world.modify(defender, CombatData::dodge_bonus.set_to(30));
+        world.add_event(CoreEvent::TimePassed);
+
+        let attack = logic::combat::default_attack(view, attacker);
+        logic::combat::handle_attack(world, attacker, defender, &attack);
+        world.add_event(CoreEvent::TimePassed);
+
+        assert_that(&view.data::<SkillData>(defender).cur_skill_xp(Skill::Dodge)).is_greater_than(&0);
+        assert_that(&view.data::<CharacterData>(defender).health.cur_value()).is_equal_to(&100);
+    })
+}
+
+#[test]
+pub fn test_harvesting_grants_xp_in_the_skills_used() {
+    in_testbed(|world, testbed| {
+        let view = world.view();
+        let harvester = create_fighter(world, &testbed, AxialCoord::new(0, 0));
+        let wood = view.world_data::<Resources>().main.wood;
+
+        let trees = EntityBuilder::new().with(Harvestable {
+            action_name : strf("chop wood"),
+            amount : Reduceable::new(Sext::of(5)),
+            fixed_amount_per_harvest : 1,
+            resource : wood,
+            skills_used : vec![Skill::ForestSurvival, Skill::Axe],
+            .. Default::default()
+        }).create(world);
+        world.add_event(CoreEvent::TimePassed);
+
+        logic::harvest::harvest(world, harvester, AxialCoord::new(0, 0), trees, false, None);
+        world.add_event(CoreEvent::TimePassed);
+
+        let skills = view.data::<SkillData>(harvester);
+        assert_that(&skills.cur_skill_xp(Skill::ForestSurvival)).is_equal_to(&1);
+        assert_that(&skills.cur_skill_xp(Skill::Axe)).is_equal_to(&1);
+        assert_that(&skills.cur_skill_xp(Skill::Mining)).is_equal_to(&0);
+    })
+}
+
+#[test]
+pub fn test_crafting_grants_crafting_xp() {
+    use data::entities::recipes::Recipe;
+
+    in_testbed(|world, testbed| {
+        let view = world.view();
+        let crafter = create_fighter(world, &testbed, AxialCoord::new(0, 0));
+        let item_catalog = Catalog::of::<ItemArchetype>(view, Entity::sentinel());
+        let hatchet_recipe = logic::recipes::recipe_for(view, item_catalog.entity_with_name("hatchet")).expect("a hatchet recipe should exist");
+        let uses_crafting = view.data::<Recipe>(hatchet_recipe).skills_used.iter().any(|s| s.skill == Skill::Crafting);
+        assert_that(&uses_crafting).is_true();
+
+        let resources = view.world_data::<Resources>().main.clone();
+        let craft_hatchet = |world : &mut World| {
+            let mut ingredients = HashMap::new();
+            ingredients.insert(Taxon::of(&taxonomy::ingredient_types::Haft), vec![world.clone_entity(resources.wood), world.clone_entity(resources.wood)]);
+            ingredients.insert(Taxon::of(&taxonomy::ingredient_types::Axehead), vec![world.clone_entity(resources.quarried_stone), world.clone_entity(resources.quarried_stone)]);
+            logic::crafting::craft(world, crafter, &ingredients, hatchet_recipe).expect("crafting should succeed");
+            world.add_event(CoreEvent::TimePassed);
+        };
+
+        craft_hatchet(world);
+        let xp_from_one = view.data::<SkillData>(crafter).cur_skill_xp(Skill::Crafting);
+        assert_that(&xp_from_one).is_greater_than(&0);
+
+        // the same craft again teaches just as much again
+        craft_hatchet(world);
+        assert_that(&view.data::<SkillData>(crafter).cur_skill_xp(Skill::Crafting)).is_equal_to(&(xp_from_one * 2));
+    })
+}
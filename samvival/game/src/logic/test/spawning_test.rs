@@ -0,0 +1,72 @@
+use common::prelude::*;
+use prelude::*;
+
+use logic::test::testbed::in_testbed;
+use spectral::prelude::*;
+use logic;
+use game::events::CoreEvent;
+use noisy_float::types::r32;
+use data::entities::{AllegianceData, PositionData, MonsterSpawnerData, Spawn, SpawnEntity, SpawnCondition};
+
+
+#[test]
+pub fn test_spawn_waves_grow_with_budget_and_respect_max_alive() {
+    in_testbed(|world, testbed| {
+        let view = world.view();
+
+        let spawn = Spawn::new(SpawnEntity::Character(strf("mud monster")), 2, 1)
+            .with_threat_budget(3.0, 0.0)
+            .with_condition(SpawnCondition::MaxAlive(4));
+        let spawner = EntityBuilder::new()
+            .with(AllegianceData { faction : testbed.player_faction })
+            .with(PositionData { hex : AxialCoord::new(0, 0) })
+            .with(MonsterSpawnerData { spawns : vec![spawn], ..Default::default() })
+            .create(world);
+        world.add_event(CoreEvent::TimePassed);
+
+        let spawned_count = || view.data::<MonsterSpawnerData>(spawner).spawned.len();
+
+        // nothing before the spawn has started
+        logic::spawning::spawn_waves(world, spawner, 1);
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&spawned_count()).is_equal_to(0);
+
+        // the first wave uses up the whole budget
+        logic::spawning::spawn_waves(world, spawner, 2);
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&spawned_count()).is_equal_to(3);
+        for spawned in &view.data::<MonsterSpawnerData>(spawner).spawned {
+            assert_that(&view.data::<PositionData>(*spawned).hex.distance(&AxialCoord::new(0, 0))).is_equal_to(r32(1.0));
+        }
+
+        // the turn between waves is quiet
+        logic::spawning::spawn_waves(world, spawner, 3);
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&spawned_count()).is_equal_to(3);
+
+        // and the next wave only tops up to the most that may be alive at once
+        logic::spawning::spawn_waves(world, spawner, 4);
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&spawned_count()).is_equal_to(4);
+
+        // a budget that grows each turn makes every wave bigger than the one before it
+        let growing = Spawn::new(SpawnEntity::Character(strf("mud monster")), 1, 1)
+            .with_threat_budget(1.0, 1.0)
+            .with_spawn_radius(2);
+        let growing_spawner = EntityBuilder::new()
+            .with(AllegianceData { faction : testbed.player_faction })
+            .with(PositionData { hex : AxialCoord::new(-6, 0) })
+            .with(MonsterSpawnerData { spawns : vec![growing], ..Default::default() })
+            .create(world);
+        world.add_event(CoreEvent::TimePassed);
+
+        let growing_count = || view.data::<MonsterSpawnerData>(growing_spawner).spawned.len();
+        logic::spawning::spawn_waves(world, growing_spawner, 1);
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&growing_count()).is_equal_to(1);
+
+        logic::spawning::spawn_waves(world, growing_spawner, 3);
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&growing_count()).is_equal_to(1 + 3);
+    })
+}
@@ -0,0 +1,73 @@
+use common::prelude::*;
+use prelude::*;
+use spectral::prelude::*;
+use terrain;
+use samvival_core::create_world;
+use data::entities::*;
+use logic;
+use logic::test::testbed::{in_testbed, human};
+use game::events::CoreEvent;
+
+
+fn generated_terrain(config : &TerrainGenConfig) -> Vec<(AxialCoord, String, i8)> {
+    let mut world = create_world();
+    terrain::generator::generate(&mut world, config).into_iter()
+        .map(|tile| {
+            let tile = tile.create(&mut world);
+            let terrain = world.data::<TerrainData>(tile);
+            (world.data::<TileData>(tile).position, terrain.kind.name().to_string(), terrain.fertility)
+        })
+        .collect()
+}
+
+#[test]
+pub fn test_same_config_generates_same_terrain() {
+    let config = TerrainGenConfig::with_seed(1234).with_shape(MapShape::Hexagon { radius : 20 });
+    let first = generated_terrain(&config);
+    let second = generated_terrain(&config);
+    assert_that(&first).is_equal_to(&second);
+
+    let other = generated_terrain(&TerrainGenConfig::with_seed(4321).with_shape(MapShape::Hexagon { radius : 20 }));
+    assert_that(&first).is_not_equal_to(&other);
+}
+
+#[test]
+pub fn test_generation_config_is_recorded() {
+    let mut world = create_world();
+    let config = TerrainGenConfig::with_seed(99).with_shape(MapShape::Rectangle { width : 12, height : 8 });
+    let tiles = terrain::generator::generate(&mut world, &config);
+    assert_that(&tiles.len()).is_equal_to(&(12 * 8));
+
+    let recorded = terrain::generator::recorded_config(world.view());
+    assert_that(&recorded.map(|c| c.seed)).is_equal_to(&Some(99));
+}
+
+#[test]
+pub fn test_rivers_and_lakes_form_in_the_lowlands() {
+    // with every mountain a source there are rivers aplenty, and the odd seed without a basin to pool in doesn't matter
+    let tiles = (1 .. 6).flat_map(|seed| generated_terrain(&TerrainGenConfig { river_rarity : 1, .. TerrainGenConfig::with_seed(seed).with_shape(MapShape::Hexagon { radius : 30 }) }))
+        .collect_vec();
+    let water = tiles.iter().filter(|(_, kind, _)| kind == "river" || kind == "lake").collect_vec();
+
+    assert_that(&water.iter().any(|(_, kind, _)| kind == "river")).is_true();
+    assert_that(&water.iter().any(|(_, kind, _)| kind == "lake")).is_true();
+    // nothing grows on open water
+    assert_that(&water.iter().all(|(_, _, fertility)| *fertility == 0)).is_true();
+}
+
+#[test]
+pub fn test_lakes_cannot_be_entered() {
+    in_testbed(|world, testbed| {
+        let view = world.view();
+        let lake_hex = AxialCoord::new(1, 0);
+        let lake = view.entity_by_key(&lake_hex).expect("the testbed should have a tile next to the origin");
+        world.modify(lake, TerrainData::kind.set_to(Taxon::of(&taxonomy::terrain::Lake)));
+        let swimmer = human(testbed.player_faction, AxialCoord::new(0, 0)).create(world);
+        world.add_event(CoreEvent::TimePassed);
+
+        let reachable = logic::movement::hexes_in_range(view, swimmer, Sext::of(20));
+        assert_that(&reachable.contains_key(&lake_hex)).is_false();
+        assert_that(&reachable.contains_key(&AxialCoord::new(-1, 0))).is_true();
+        assert_that(&logic::movement::path_to(view, swimmer, lake_hex).map(|(_, cost)| cost < 1000.0).unwrap_or(false)).is_false();
+    })
+}
@@ -4,22 +4,32 @@ use terrain;
 use common::Color;
 use data::entities::faction::FactionData;
 use data::entities::time::TurnData;
-use data::entities::tile::TileData;
+use data::entities::tile::{TileData, Resources};
+use data::entities::effects::Effects;
+use data::entities::map::{TerrainGenConfig, MapShape};
+use data::entities::{AllegianceData, PositionData};
+use data::entities::attributes::{attributes, AttributeDefinitions};
 use game::DebugData;
 use archetypes::weapons::create_weapon_archetypes;
+use archetypes::containers::create_container_archetypes;
+use archetypes::recipes::create_recipes;
+use archetypes::character_archetypes;
+use std::path::Path;
 
 
 pub struct Testbed {
-    player_faction : Entity
+    pub player_faction : Entity
 }
 
 pub struct TestbedConfig {
-    pub map_radius : i32
+    pub map_radius : i32,
+    pub seed : u32
 }
 impl Default for TestbedConfig {
     fn default() -> Self {
         TestbedConfig {
-            map_radius : 10
+            map_radius : 10,
+            seed : TerrainGenConfig::default().seed
         }
     }
 }
@@ -30,10 +40,21 @@ pub fn in_testbed<F : Fn(&mut World, Testbed)>(func : F) {
 
 pub fn in_custom_testbed<F : Fn(&mut World, Testbed)>(config : TestbedConfig, func : F) {
     let mut world = create_world();
+    // the terrain generator registers harvesting effects, so the effect and resource registries have to be in place, but
+    // the rest of initialize_world reads from the assets folder and has no business varying how tests behave
+    Resources::init_resources(&mut world);
+    Effects::init_effects(&mut world);
+    // attributes on the other hand only exist as data, so the definitions file is read from where it sits in the source tree
+    AttributeDefinitions::init_attributes(&mut world);
+    let attribute_definitions = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../assets").join(attributes::ATTRIBUTE_DEFINITIONS_PATH);
+    attributes::load_attribute_definitions(&mut world, attribute_definitions).expect("attribute definitions should load");
 
     create_weapon_archetypes(&mut world);
+    create_container_archetypes(&mut world);
+    create_recipes(&mut world);
 
-    for tile in terrain::generator::generate(&mut world, config.map_radius) {
+    let terrain_config = TerrainGenConfig::with_seed(config.seed).with_shape(MapShape::Hexagon { radius : config.map_radius });
+    for tile in terrain::generator::generate(&mut world, &terrain_config) {
         let tile = tile.with(DebugData { name : strf("world tile") }).create(&mut world);
         let pos = world.data::<TileData>(tile).position;
         world.index_entity(tile, pos);
@@ -64,4 +85,13 @@ pub fn in_custom_testbed<F : Fn(&mut World, Testbed)>(config : TestbedConfig, fu
         .create(&mut world);
 
     func(&mut world, Testbed { player_faction });
-}
\ No newline at end of file
+}
+
+/// a human of the given faction standing on the given hex, with enough health and action points to act for a turn.
+/// Tests that care about anything else can layer it on with further `with` calls before creating them
+pub fn human(faction : Entity, hex : AxialCoord) -> EntityBuilder {
+    character_archetypes().with_name("human").clone()
+        .with(CharacterData { health : Reduceable::new(20), action_points : Reduceable::new(8), ..Default::default() })
+        .with(AllegianceData { faction })
+        .with(PositionData { hex })
+}
@@ -0,0 +1,33 @@
+use common::prelude::*;
+use prelude::*;
+use logic::test::testbed::*;
+use spectral::prelude::*;
+use logic;
+use data::entities::time::*;
+use data::entities::weather::*;
+
+
+#[test]
+pub fn test_time_of_day_advances_with_moments() {
+    in_testbed(|world, testbed| {
+        let view = world.view();
+
+        // worlds start out at the beginning of the first day's daylight
+        assert_that(&logic::time::time_of_day(view)).is_equal_to(&TimeOfDay::Daylight);
+
+        logic::time::advance_time(world, 6);
+        assert_that(&logic::time::time_of_day(view)).is_equal_to(&TimeOfDay::Dusk);
+
+        logic::time::advance_time(world, 2);
+        assert_that(&logic::time::time_of_day(view)).is_equal_to(&TimeOfDay::Night);
+
+        logic::time::advance_time(world, 6);
+        assert_that(&logic::time::time_of_day(view)).is_equal_to(&TimeOfDay::Dawn);
+        assert_that(&view.world_data::<TimeData>().days_since_world_start).is_equal_to(&1);
+
+        let transitions = view.events::<GameEvent>()
+            .filter(|e| if let GameEvent::TimeOfDayChanged { .. } = e.event { true } else { false })
+            .count();
+        assert_that(&transitions).is_equal_to(&3);
+    });
+}
@@ -0,0 +1,47 @@
+use common::prelude::*;
+use prelude::*;
+
+use logic::test::testbed;
+use logic::test::testbed::in_testbed;
+use spectral::prelude::*;
+use logic;
+use game::events::CoreEvent;
+use data::entities::{AllegianceData, FactionData, PositionData, Relationship, TriggerData, TriggerCondition, ScriptedAction, ScenarioTrigger};
+
+
+#[test]
+pub fn test_triggers_run_their_actions_once_set_off() {
+    in_testbed(|world, testbed| {
+        let view = world.view();
+
+        let hermits = EntityBuilder::new().with(FactionData { name : strf("Hermits"), ..Default::default() }).create(world);
+        let wanderer = testbed::human(testbed.player_faction, AxialCoord::new(0, 0)).create(world);
+
+        world.modify_world(TriggerData::triggers.set_to(vec![
+            ScenarioTrigger::new(TriggerCondition::OnEnteringRegion { faction : Some(strf("Player")), hexes : vec![AxialCoord::new(2, 0)] }, vec![
+                ScriptedAction::SetRelationship { faction_a : strf("Player"), faction_b : strf("Hermits"), relationship : Relationship::Allied },
+                ScriptedAction::SpawnCharacter { archetype : strf("human"), faction : strf("Hermits"), at : AxialCoord::new(2, 0) },
+            ])
+        ]), None);
+        world.add_event(CoreEvent::TimePassed);
+
+        // moving somewhere else doesn't set it off
+        let elsewhere = GameEvent::Move { character : wanderer, from : AxialCoord::new(0, 0), to : AxialCoord::new(1, 0), cost : Sext::of(1) };
+        logic::triggers::fire_triggers(world, &elsewhere);
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&logic::faction::relationship(view, testbed.player_faction, hermits)).is_equal_to(Relationship::Hostile);
+
+        world.modify(wanderer, PositionData::hex.set_to(AxialCoord::new(2, 0)));
+        let into_region = GameEvent::Move { character : wanderer, from : AxialCoord::new(1, 0), to : AxialCoord::new(2, 0), cost : Sext::of(1) };
+        logic::triggers::fire_triggers(world, &into_region);
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&logic::faction::relationship(view, testbed.player_faction, hermits)).is_equal_to(Relationship::Allied);
+        let hermit_count = || view.entities_with_data::<AllegianceData>().filter(|(_, a)| a.faction == hermits).count();
+        assert_that(&hermit_count()).is_equal_to(1);
+
+        // and it only goes off the once
+        logic::triggers::fire_triggers(world, &into_region);
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&hermit_count()).is_equal_to(1);
+    })
+}
@@ -0,0 +1,116 @@
+use common::prelude::*;
+use prelude::*;
+
+use logic::test::testbed::{in_testbed, in_custom_testbed, human, TestbedConfig};
+use spectral::prelude::*;
+use logic;
+use game::events::CoreEvent;
+use logic::combat::AttackTargets;
+use noisy_float::types::r32;
+use logic::visibility::VisibilityComputor;
+use data::entities::{ObserverData, TileAccessor, TimeData, TurnData, FactionData, Weather, WeatherData, Attack, DamageType};
+use std::cell::RefCell;
+
+
+fn set_weather(world : &mut World, weather : Weather) {
+    world.ensure_world_data::<WeatherData>();
+    world.modify_world(WeatherData::weather.set_to(weather), None);
+    world.add_event(CoreEvent::TimePassed);
+}
+
+#[test]
+pub fn test_fog_shortens_vision() {
+    in_testbed(|world, _testbed| {
+        let view = world.view();
+        let faction = EntityBuilder::new().with(FactionData { name : strf("Travellers"), ..Default::default() }).create(world);
+        human(faction, AxialCoord::new(0, 0))
+            .with(ObserverData { vision_range : 6, low_light_vision_range : 6, dark_vision_range : 6 })
+            .create(world);
+        world.add_event(CoreEvent::TimePassed);
+
+        let clear_hexes = VisibilityComputor::new().recompute_visible_hexes(view, faction, None);
+        set_weather(world, Weather::Fog);
+        let fog_hexes = VisibilityComputor::new().recompute_visible_hexes(view, faction, None);
+
+        assert_that(&fog_hexes.len()).is_less_than(&clear_hexes.len());
+        let fog_range = r32((6 - Weather::Fog.vision_penalty()) as f32);
+        assert_that(&fog_hexes.iter().all(|h| h.distance(&AxialCoord::new(0, 0)) <= fog_range)).is_true();
+    })
+}
+
+#[test]
+pub fn test_snow_makes_every_hex_costlier_to_enter() {
+    in_testbed(|world, _testbed| {
+        let view = world.view();
+        let hex = AxialCoord::new(1, 0);
+
+        let clear_cost = { let accessor = TileAccessor::new(view); accessor.move_cost(&accessor.tile_opt(hex).expect("hex must exist")) };
+        set_weather(world, Weather::Snow);
+        let snow_cost = { let accessor = TileAccessor::new(view); accessor.move_cost(&accessor.tile_opt(hex).expect("hex must exist")) };
+
+        assert_that(&(snow_cost - clear_cost)).is_equal_to(Weather::Snow.move_cost_penalty());
+    })
+}
+
+#[test]
+pub fn test_heat_slows_stamina_recovery() {
+    in_testbed(|world, _testbed| {
+        let view = world.view();
+        let faction = EntityBuilder::new().with(FactionData { name : strf("Travellers"), ..Default::default() }).create(world);
+        let character = human(faction, AxialCoord::new(0, 0))
+            .with(CharacterData { name : strf("Walker"), health : Reduceable::new(20), action_points : Reduceable::new(8), stamina : Reduceable::new(Sext::of(6)), ..Default::default() })
+            .create(world);
+        world.modify(character, CharacterData::stamina.reduce_by(Sext::of(2)));
+        set_weather(world, Weather::Heat);
+
+        // one end of turn for each faction brings things back around to a new turn
+        let faction_count = view.entities_with_data::<FactionData>().count();
+        for _ in 0 .. faction_count {
+            logic::turn::end_faction_turn(world);
+        }
+        world.add_event(CoreEvent::TimePassed);
+        assert_that(&view.world_data::<TurnData>().turn_number).is_equal_to(1);
+
+        let expected = Sext::of(4) + Sext::of(1) - Weather::Heat.stamina_recovery_penalty();
+        assert_that(&view.data::<CharacterData>(character).stamina.cur_value()).is_equal_to(expected);
+    })
+}
+
+#[test]
+pub fn test_rain_dampens_fire_damage() {
+    in_testbed(|world, _testbed| {
+        let faction = EntityBuilder::new().with(FactionData { name : strf("Travellers"), ..Default::default() }).create(world);
+        let attacker = human(faction, AxialCoord::new(0, 0)).create(world);
+        let defender = human(faction, AxialCoord::new(1, 0)).create(world);
+        world.add_event(CoreEvent::TimePassed);
+
+        let attack = Attack { primary_damage_type : DamageType::Fire, ..Default::default() };
+        let targets = AttackTargets { hexes : vec![AxialCoord::new(1, 0)], characters : vec![defender] };
+        let damage_bonus = |world : &World| logic::combat::compute_strike_breakdown(world, world.view(), attacker, defender, &attack, Entity::sentinel(), &targets)
+            .per_target_breakdowns[0].damage_bonus_total();
+
+        let clear_bonus = damage_bonus(world);
+        set_weather(world, Weather::Rain);
+        assert_that(&(damage_bonus(world) - clear_bonus)).is_equal_to(Weather::Rain.fire_damage_bonus());
+    })
+}
+
+#[test]
+pub fn test_weather_is_the_same_for_the_same_map_seed() {
+    let weather_over_days = |seed : u32| {
+        let seen = RefCell::new(Vec::new());
+        in_custom_testbed(TestbedConfig { seed, .. Default::default() }, |world, _testbed| {
+            let view = world.view();
+            for _ in 0 .. 20 {
+                logic::time::advance_time(world, view.world_data::<TimeData>().moments_per_day());
+                seen.borrow_mut().push(logic::weather::current_weather(view));
+            }
+        });
+        seen.into_inner()
+    };
+
+    let first = weather_over_days(1234);
+    assert_that(&first).is_equal_to(weather_over_days(1234));
+    // over twenty days another map is all but certain to see some different weather
+    assert_that(&first).is_not_equal_to(weather_over_days(4321));
+}
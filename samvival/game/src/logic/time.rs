@@ -0,0 +1,54 @@
+use common::prelude::*;
+use prelude::*;
+use entities::{TimeData, TimeOfDay, Season};
+use logic;
+
+
+pub fn time_of_day(view : &WorldView) -> TimeOfDay {
+    view.world_data_opt::<TimeData>().map(|t| t.time_of_day()).unwrap_or_default()
+}
+
+pub fn season(view : &WorldView) -> Season {
+    view.world_data_opt::<TimeData>().map(|t| t.season()).unwrap_or_default()
+}
+
+/// advances the world clock by the given number of moments, rolling over into new days as necessary and
+/// raising events whenever the time of day or the season changes
+pub fn advance_time(world : &mut World, moments : u32) {
+    world.ensure_world_data::<TimeData>();
+
+    // modifications are not visible in the view until the next event, so track the evolving time locally
+    let mut time_data = world.view().world_data::<TimeData>().clone();
+
+    for _ in 0 .. moments {
+        let prev_time_of_day = time_data.time_of_day();
+        let prev_season = time_data.season();
+
+        world.modify_world(TimeData::moments_since_world_start.add(1), None);
+        time_data.moments_since_world_start += 1;
+        let new_day = time_data.moments_since_day_start + 1 >= time_data.moments_per_day();
+        if new_day {
+            world.modify_world(TimeData::moments_since_day_start.set_to(0), "new day");
+            world.modify_world(TimeData::days_since_world_start.add(1), "new day");
+            time_data.moments_since_day_start = 0;
+            time_data.days_since_world_start += 1;
+        } else {
+            world.modify_world(TimeData::moments_since_day_start.add(1), None);
+            time_data.moments_since_day_start += 1;
+        }
+
+        let new_time_of_day = time_data.time_of_day();
+        if new_time_of_day != prev_time_of_day {
+            world.add_event(GameEvent::TimeOfDayChanged { time_of_day : new_time_of_day, day : time_data.days_since_world_start });
+        }
+
+        let new_season = time_data.season();
+        if new_season != prev_season {
+            world.add_event(GameEvent::SeasonChanged { season : new_season });
+        }
+
+        if new_day {
+            logic::weather::advance_weather(world, time_data.days_since_world_start);
+        }
+    }
+}
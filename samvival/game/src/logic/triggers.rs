@@ -0,0 +1,125 @@
+use common::prelude::*;
+use common::string::*;
+use prelude::*;
+use logic;
+use entity_util::{has_name, character_named};
+use game::events::CoreEvent;
+use game::DebugData;
+use archetypes::characters::character_archetypes;
+use data::entities::{TriggerData, TriggerCondition, ScriptedAction, FactionData, PositionData, AllegianceData, Visibility, VisibilityData, TileAccessor};
+use std::collections::HashSet;
+
+
+/// Runs the scenario's scripted triggers as the events they wait on come to pass, this is how scenarios tell their
+/// stories: who speaks when, who turns up where, and who changes sides
+pub fn register(world: &mut World) {
+    world.add_callback(|world, event_w| {
+        if ! event_w.is_ended() {
+            return;
+        }
+        match event_w.event {
+            GameEvent::TurnStart { .. } | GameEvent::Move { .. } | GameEvent::EntityDied { .. } | GameEvent::ItemCrafted { .. } =>
+                fire_triggers(world, &event_w.event),
+            _ => ()
+        }
+    });
+}
+
+/// runs the actions of every trigger that has yet to go off and is set off by the given event
+pub fn fire_triggers(world : &mut World, event : &GameEvent) {
+    let view = world.view();
+    let mut triggers = match view.world_data_opt::<TriggerData>() {
+        Some(data) => data.triggers.clone(),
+        None => return
+    };
+
+    let mut actions = Vec::new();
+    for trigger in triggers.iter_mut().filter(|t| ! t.fired || t.repeat) {
+        if is_set_off(view, &trigger.condition, event) {
+            trigger.fired = true;
+            actions.extend(trigger.actions.iter().cloned());
+        }
+    }
+    if actions.is_empty() {
+        return;
+    }
+
+    world.modify_world(TriggerData::triggers.set_to(triggers), "trigger fired");
+    for action in actions {
+        perform_scripted_action(world, &action);
+    }
+}
+
+fn is_set_off(view : &WorldView, condition : &TriggerCondition, event : &GameEvent) -> bool {
+    match (condition, event) {
+        (TriggerCondition::OnTurn(turn), GameEvent::TurnStart { turn_number }) => turn_number == turn,
+        (TriggerCondition::OnEnteringRegion { faction, hexes }, GameEvent::Move { character, to, .. }) => hexes.contains(to) &&
+            faction.as_ref().map(|f| view.data_opt::<AllegianceData>(*character).map(|a| faction_name(view, a.faction) == Some(f.as_str())).unwrap_or(false)).unwrap_or(true),
+        (TriggerCondition::OnDeath(name), GameEvent::EntityDied { entity }) => view.data_opt::<CharacterData>(*entity).map(|c| &c.name == name).unwrap_or(false),
+        (TriggerCondition::OnItemCrafted(kind), GameEvent::ItemCrafted { item, .. }) => has_name(*item, view, kind),
+        _ => false
+    }
+}
+
+pub fn perform_scripted_action(world : &mut World, action : &ScriptedAction) {
+    let view = world.view();
+    match action {
+        ScriptedAction::Dialog { speaker, text, requires_confirmation } => match character_named(view, speaker) {
+            Some(speaker) => world.add_event(GameEvent::DialogSpoken {
+                speaker,
+                text : RichString::new().with_appended(text.as_str(), RichStringStyle::Plain),
+                requires_confirmation : *requires_confirmation
+            }),
+            None => warn!("Scripted dialog for {} who isn't around to say it", speaker)
+        },
+        ScriptedAction::SpawnCharacter { archetype, faction, at } => {
+            if let Some(faction) = faction_named(view, faction) {
+                match free_hex_near(view, *at) {
+                    Some(hex) => {
+                        let spawned = character_archetypes().with_name(archetype.as_str()).clone()
+                            .with(AllegianceData { faction })
+                            .with(PositionData { hex })
+                            .with(DebugData { name : format!("scripted spawn: {}", archetype) })
+                            .create(world);
+                        logic::movement::place_entity_in_world(world, spawned, hex);
+                    },
+                    None => warn!("No room to spawn {} near {:?}", archetype, at)
+                }
+            }
+        },
+        ScriptedAction::RevealHexes { faction, hexes } => {
+            if let Some(faction) = faction_named(view, faction) {
+                let revealed = Visibility { visible_hexes : HashSet::new(), revealed_hexes : hexes.iter().cloned().collect() };
+                world.modify_world(VisibilityData::visibility_by_faction.add_to_key(faction, revealed), None);
+                world.add_event(CoreEvent::Recomputation);
+            }
+        },
+        ScriptedAction::SetRelationship { faction_a, faction_b, relationship } => {
+            if let (Some(a), Some(b)) = (faction_named(view, faction_a), faction_named(view, faction_b)) {
+                logic::faction::set_relationship(world, a, b, *relationship);
+            }
+        }
+    }
+}
+
+fn faction_name(view : &WorldView, faction : Entity) -> Option<&str> {
+    view.data_opt::<FactionData>(faction).map(|f| f.name.as_str())
+}
+
+fn faction_named(view : &WorldView, name : &str) -> Option<Entity> {
+    let faction = view.entities_with_data::<FactionData>().find(|(_, f)| f.name == name).map(|(ent, _)| *ent);
+    if faction.is_none() {
+        warn!("Scripted action refers to unknown faction {}", name);
+    }
+    faction
+}
+
+/// the given hex if no one is standing there, otherwise the first free neighbor of it
+fn free_hex_near(view : &WorldView, hex : AxialCoord) -> Option<AxialCoord> {
+    let is_free = |h : &AxialCoord| view.tile_ent_opt(*h).map(|t| t.occupied_by.is_none()).unwrap_or(false);
+    if is_free(&hex) {
+        Some(hex)
+    } else {
+        hex.neighbors_vec().into_iter().find(|h| is_free(h))
+    }
+}
@@ -1,7 +1,8 @@
 use common::prelude::*;
 use game::prelude::*;
-use entities::{MovementData, CharacterData, TurnData, FactionData};
+use entities::{MovementData, CharacterData, TurnData, FactionData, NeedsData};
 use prelude::GameEvent;
+use logic;
 
 
 pub fn end_faction_turn(world : &mut World) {
@@ -19,20 +20,34 @@ pub fn end_faction_turn(world : &mut World) {
 
     // if we're back around at the beginning, start a new overall turn
     if next_index == 0 {
+        let weather = logic::weather::current_weather(world_view);
         for (cref, cdat) in world_view.entities_with_data::<CharacterData>() {
             world.modify_with_desc(*cref, MovementData::moves.set_to(Sext::of(0)), None);
             world.modify_with_desc(*cref, CharacterData::action_points.reset(), None);
-            world.modify_with_desc(*cref, CharacterData::stamina.recover_by(cdat.stamina_recovery), None);
+            let stamina_recovery = (cdat.stamina_recovery - weather.stamina_recovery_penalty() - logic::needs::stamina_recovery_penalty(world_view, *cref)).max(Sext::zero());
+            world.modify_with_desc(*cref, CharacterData::stamina.recover_by(stamina_recovery), None);
+        }
+        // needs are seen to after everyone's had their action points back, so going without costs a share of them
+        let needy = world_view.entities_with_data::<NeedsData>()
+            .filter(|(c, _)| world_view.data::<CharacterData>(**c).is_alive())
+            .map(|(c, _)| *c)
+            .collect_vec();
+        for character in needy {
+            logic::needs::update_needs(world, character);
         }
 
         let turn_number = current_turn + 1;
         world.modify_world(TurnData::turn_number.set_to(turn_number), None);
 
+        // each full turn represents a single moment of in-world time
+        logic::time::advance_time(world, 1);
+
         world.add_event(GameEvent::TurnStart { turn_number });
     }
 
 
     world.modify_world(TurnData::active_faction.set_to(next_faction), None);
+    logic::morale::recover_morale(world, next_faction);
     world.end_event(GameEvent::FactionTurn { turn_number : current_turn, faction : prev_faction });
     world.start_event(GameEvent::FactionTurn { turn_number : current_turn, faction : next_faction });
 }
\ No newline at end of file
@@ -12,7 +12,8 @@ use game::EntityData;
 use data::entities::character::ObserverData;
 use data::entities::character::AllegianceData;
 use data::entities::time::TimeOfDay;
-use data::entities::faction::FactionData;
+use data::entities::weather::Weather;
+use data::entities::faction::{FactionData, Relationship};
 use data::entities::tile::TileStore;
 use std::collections::HashMap;
 use std::collections::HashSet;
@@ -55,39 +56,21 @@ impl VisibilityComputor {
                 GameEvent::WorldStart => {
                 },
                 GameEvent::Move { character, from, to, .. } => {
-                    let world_view = world.view();
-                    let faction = world_view.character(character).allegiance.faction;
-                    let vis = {
-                        let computor = world.world_data_mut::<VisibilityComputor>();
-                        computor.recompute_visible_hexes(world_view, faction, None)
-                    };
-
-                    let mut modified = false;
-                    let view = world.view();
-                    if let Some(old_visibility) = view.world_data::<VisibilityData>().visibility_by_faction.get(&faction) {
-                        let newly_revealed_hexes : HashSet<AxialCoord> = vis.iter().filter(|h| !old_visibility.revealed_hexes.contains(h)).cloned().collect();
-                        let newly_visible_hexes : HashSet<AxialCoord> = vis.difference(&old_visibility.visible_hexes).cloned().collect();
-                        let no_longer_visible_hexes : HashSet<AxialCoord> = old_visibility.visible_hexes.difference(&vis).cloned().collect();
-
-                        if !newly_revealed_hexes.is_empty() || !newly_visible_hexes.is_empty() {
-                            let add_vis = Visibility { visible_hexes : newly_visible_hexes, revealed_hexes : newly_revealed_hexes };
-                            world.modify_world(VisibilityData::visibility_by_faction.add_to_key(faction, add_vis), None);
-                            modified = true;
-                        }
-                        if !no_longer_visible_hexes.is_empty() {
-                            let sub_vis = Visibility { visible_hexes : no_longer_visible_hexes, revealed_hexes : HashSet::new() };
-                            world.modify_world(VisibilityData::visibility_by_faction.sub_from_key(faction, sub_vis), None);
-                            modified = true;
-                        }
-                    } else {
-                        let mut visibility = Visibility::new();
-                        visibility.visible_hexes = vis.clone();
-                        visibility.revealed_hexes = vis;
-                        world.modify_world(VisibilityData::visibility_by_faction.set_key_to(faction, visibility), None);
-                        modified = true;
+                    // allies share what they see, so everyone allied to the mover may see more or less now
+                    let faction = world.view().character(character).allegiance.faction;
+                    for allied_faction in logic::faction::allied_factions(world.view(), faction) {
+                        VisibilityComputor::update_visibility_for_faction(world, allied_faction);
                     }
-                    if modified {
-                        world.add_event(CoreEvent::Recomputation);
+                },
+                GameEvent::RelationshipChanged { faction_a, faction_b, .. } => {
+                    VisibilityComputor::update_visibility_for_faction(world, faction_a);
+                    VisibilityComputor::update_visibility_for_faction(world, faction_b);
+                },
+                GameEvent::TimeOfDayChanged { .. } | GameEvent::WeatherChanged { .. } => {
+                    // light levels or weather have changed, so every faction's observers may be able to see more or less than they could
+                    let factions = world.view().entities_with_data::<FactionData>().map(|(faction,_)| *faction).collect_vec();
+                    for faction in factions {
+                        VisibilityComputor::update_visibility_for_faction(world, faction);
                     }
                 },
                 _ => ()
@@ -95,6 +78,42 @@ impl VisibilityComputor {
         });
     }
 
+    fn update_visibility_for_faction(world: &mut World, faction : Entity) {
+        let world_view = world.view();
+        let vis = {
+            let computor = world.world_data_mut::<VisibilityComputor>();
+            computor.recompute_visible_hexes(world_view, faction, None)
+        };
+
+        let mut modified = false;
+        let view = world.view();
+        if let Some(old_visibility) = view.world_data::<VisibilityData>().visibility_by_faction.get(&faction) {
+            let newly_revealed_hexes : HashSet<AxialCoord> = vis.iter().filter(|h| !old_visibility.revealed_hexes.contains(h)).cloned().collect();
+            let newly_visible_hexes : HashSet<AxialCoord> = vis.difference(&old_visibility.visible_hexes).cloned().collect();
+            let no_longer_visible_hexes : HashSet<AxialCoord> = old_visibility.visible_hexes.difference(&vis).cloned().collect();
+
+            if !newly_revealed_hexes.is_empty() || !newly_visible_hexes.is_empty() {
+                let add_vis = Visibility { visible_hexes : newly_visible_hexes, revealed_hexes : newly_revealed_hexes };
+                world.modify_world(VisibilityData::visibility_by_faction.add_to_key(faction, add_vis), None);
+                modified = true;
+            }
+            if !no_longer_visible_hexes.is_empty() {
+                let sub_vis = Visibility { visible_hexes : no_longer_visible_hexes, revealed_hexes : HashSet::new() };
+                world.modify_world(VisibilityData::visibility_by_faction.sub_from_key(faction, sub_vis), None);
+                modified = true;
+            }
+        } else {
+            let mut visibility = Visibility::new();
+            visibility.visible_hexes = vis.clone();
+            visibility.revealed_hexes = vis;
+            world.modify_world(VisibilityData::visibility_by_faction.set_key_to(faction, visibility), None);
+            modified = true;
+        }
+        if modified {
+            world.add_event(CoreEvent::Recomputation);
+        }
+    }
+
     pub fn recompute_visible_hexes(&self, world : &WorldView, faction : Entity, moved_entities_ : Option<Vec<Entity>>) -> HashSet<AxialCoord> {
 //        let current_visibility = world.world_data::<VisibilityData>().visibility_by_faction.get(faction).unwrap_or_else(|| Visibility::new());
 
@@ -102,10 +121,13 @@ impl VisibilityComputor {
 
         let mut visible_hexes = HashSet::new();
 
+        let time_of_day = logic::time::time_of_day(world);
+        let weather = logic::weather::current_weather(world);
+
         for (ent,cdata) in world.entities_with_data::<ObserverData>() {
             let allegiance = world.data::<AllegianceData>(*ent);
-            if allegiance.faction == faction {
-                self.compute_observer_visibility(world, *ent, &mut visible_hexes);
+            if logic::faction::relationship(world, allegiance.faction, faction) == Relationship::Allied {
+                self.compute_observer_visibility(world, *ent, time_of_day, weather, &mut visible_hexes);
             }
         }
 
@@ -113,7 +135,7 @@ impl VisibilityComputor {
     }
 
 
-    fn compute_observer_visibility(&self, world : &WorldView, ent : Entity, visible_hexes : &mut HashSet<AxialCoord>) {
+    fn compute_observer_visibility(&self, world : &WorldView, ent : Entity, time_of_day : TimeOfDay, weather : Weather, visible_hexes : &mut HashSet<AxialCoord>) {
         let center : AxialCoord = world.data::<PositionData>(ent).hex;
         let observer = world.data::<ObserverData>(ent);
         let center_cube : CubeCoord = center.as_cube_coord();
@@ -125,7 +147,7 @@ impl VisibilityComputor {
 
         let start_elevation = accessor.terrain_at(center).elevation;
 
-        let max_r = observer.vision_range_at_time(TimeOfDay::Daylight) + 1;
+        let max_r = (observer.vision_range_at_time(time_of_day) - weather.vision_penalty()).max(1) + 1;
 
         for edge in CubeCoord::ring(center_cube, max_r as u32) {
             let edge_f = v3(edge.x as f32 + 1e-6, edge.y as f32 + 2e-6, edge.z as f32 - 3e-6);
@@ -0,0 +1,55 @@
+use common::prelude::*;
+use prelude::*;
+use entities::{Weather, WeatherData};
+use logic;
+use terrain;
+use rand::Rng;
+use rand::SeedableRng;
+use rand::StdRng;
+
+
+pub fn current_weather(view : &WorldView) -> Weather {
+    view.world_data_opt::<WeatherData>().map(|w| w.weather).unwrap_or_default()
+}
+
+/// rolls the weather for a new day. Weather tends to persist for a few days at a time, with an increasing chance of
+/// breaking the longer it has held, after which the next weather is chosen according to the likelihoods for the season.
+/// The roll depends only on the map's seed and the day, so the same map always has the same weather
+pub fn advance_weather(world : &mut World, day : u32) {
+    world.ensure_world_data::<WeatherData>();
+
+    let view = world.view();
+    let season = logic::time::season(view);
+    let weather_data = view.world_data::<WeatherData>();
+
+    let likelihoods = Weather::likelihoods(season);
+    let in_season = likelihoods.iter().any(|(w, _)| *w == weather_data.weather);
+
+    let map_seed = terrain::generator::recorded_config(view).map(|c| c.seed).unwrap_or(0);
+    let mut rng : StdRng = SeedableRng::from_seed(&[map_seed as usize, day as usize, 8761][..]);
+    let persists = in_season && rng.gen_range(0, 4) >= weather_data.days_of_current_weather;
+
+    let new_weather = if persists {
+        weather_data.weather
+    } else {
+        let total : u32 = likelihoods.iter().map(|(_, l)| *l).sum();
+        let mut roll = rng.gen_range(0, total);
+        let mut chosen = Weather::Clear;
+        for (weather, likelihood) in likelihoods {
+            if roll < likelihood {
+                chosen = weather;
+                break;
+            }
+            roll -= likelihood;
+        }
+        chosen
+    };
+
+    if new_weather == weather_data.weather {
+        world.modify_world(WeatherData::days_of_current_weather.add(1), None);
+    } else {
+        world.modify_world(WeatherData::weather.set_to(new_weather), "weather changed");
+        world.modify_world(WeatherData::days_of_current_weather.set_to(0), None);
+        world.add_event(GameEvent::WeatherChanged { weather : new_weather });
+    }
+}
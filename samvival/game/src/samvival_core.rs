@@ -17,6 +17,10 @@ pub fn create_world() -> World {
         max_tile_bound: AxialCoord::new(30, 30),
     });
     world.attach_world_data(VisibilityData::default());
+    world.attach_world_data(RelationshipData::default());
+    world.attach_world_data(TriggerData::default());
+    world.attach_world_data(TimeData::default());
+    world.attach_world_data(WeatherData::default());
 
     world
 }
@@ -51,18 +55,39 @@ pub fn register_world_data(world : &mut World) {
     world.register::<TerrainData>();
     world.register::<Material>();
     world.register::<WorthData>();
+    world.register::<DurabilityData>();
+    world.register::<WeightData>();
+    world.register::<LootTableData>();
+    world.register::<ContainerData>();
+    world.register::<StructureData>();
+    world.register::<QualityData>();
+    world.register::<EncumbranceData>();
     world.register::<ItemArchetype>();
     world.register::<EntityMetadata>();
     world.register::<Recipe>();
+    world.register::<RecipeKnowledgeData>();
+    world.register::<SchematicData>();
+    world.register::<PerkData>();
+    world.register::<AIData>();
+    world.register::<AwarenessData>();
+    world.register::<MoraleData>();
+    world.register::<RelationshipData>();
+    world.register::<ObjectiveData>();
+    world.register::<TriggerData>();
+    world.register::<NeedsData>();
+    world.register::<FoodInfo>();
 
     register_custom_ability_data(world);
     // -------- world data ---------------
     world.register::<MapData>();
+    world.register::<TerrainGenConfig>();
     world.register::<TurnData>();
     world.register::<TimeData>();
+    world.register::<WeatherData>();
     world.register::<VisibilityData>();
     world.register::<Effects>();
     world.register::<Resources>();
+    world.register::<AttributeDefinitions>();
     world.register::<RuntimeTaxonData>();
 
     println!("Registering axial coord index");
@@ -72,6 +97,11 @@ pub fn register_world_data(world : &mut World) {
 }
 
 pub fn initialize_world(world : &mut World) {
+    AttributeDefinitions::init_attributes(world);
+    match attributes::load_attribute_definitions_from_assets(world) {
+        Ok(count) => info!("Loaded {} attribute definitions", count),
+        Err(err) => error!("Could not load attribute definitions, item attributes will have no effect: {}", err),
+    }
     Resources::init_resources(world);
     Effects::init_effects(world);
 }
\ No newline at end of file
@@ -0,0 +1,445 @@
+use common::prelude::*;
+use common::string::*;
+use game::prelude::*;
+use game::core::Reduceable;
+use scenario::Scenario;
+use samvival_core::create_world;
+use terrain;
+use common::Color;
+use archetypes::*;
+use entities::*;
+use game::EntityBuilder;
+use logic;
+use prelude::GameEvent;
+use entities::reactions::ReactionTypeRef;
+use game::DebugData;
+use archetypes::weapons::create_weapon_archetypes;
+use archetypes::containers::create_container_archetypes;
+use archetypes::recipes::create_recipes;
+
+use std::collections::HashMap;
+use std::fs;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+use std::path::PathBuf;
+use std::env::current_exe;
+use ron;
+use find_folder;
+
+
+/// where designers keep scenario files, relative to the assets folder
+pub const SCENARIOS_PATH : &str = "survival/scenarios";
+
+/// A scenario described in data rather than code: the map, who is on it and on what terms, what they carry, and what is
+/// said at the start. Everything refers to factions and characters by name, and to creatures and items by the names of
+/// their archetypes
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ScenarioDefinition {
+    pub name : String,
+    #[serde(default)]
+    pub description : String,
+    pub map : ScenarioMap,
+    pub factions : Vec<ScenarioFaction>,
+    #[serde(default)]
+    pub characters : Vec<ScenarioCharacter>,
+    #[serde(default)]
+    pub spawners : Vec<ScenarioSpawner>,
+    /// items left lying on the map, by archetype name
+    #[serde(default)]
+    pub ground_items : Vec<(String, AxialCoord)>,
+    #[serde(default)]
+    pub dialog : Vec<ScenarioDialog>,
+    /// scripted events that play out as the scenario goes on
+    #[serde(default)]
+    pub triggers : Vec<ScenarioTrigger>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum ScenarioMap {
+    Generated(TerrainGenConfig),
+    /// a hand laid out map, every hex of the shape is the default terrain unless listed otherwise. Fixed maps have no
+    /// resources placed on them, only generated ones do
+    Fixed { shape : MapShape, default_terrain : FixedTerrain, tiles : Vec<(AxialCoord, FixedTerrain)> },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FixedTerrain {
+    Plains,
+    Grassland,
+    Forest,
+    Hills,
+    Mountains,
+    Marsh,
+    Desert,
+    Tundra,
+    Lake,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ScenarioFaction {
+    pub name : String,
+    pub color : (f32, f32, f32),
+    #[serde(default)]
+    pub player_faction : bool,
+    /// how this faction stands with the other named factions, anything not listed is hostile
+    #[serde(default)]
+    pub relationships : Vec<(String, Relationship)>,
+    /// what the faction has to do to win, and what loses it the game. Player factions without any are held to the
+    /// standard ones of wiping out the enemy before being wiped out
+    #[serde(default)]
+    pub objectives : Vec<Objective>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ScenarioCharacter {
+    pub archetype : String,
+    pub faction : String,
+    pub position : AxialCoord,
+    /// the name the character goes by, those without one are known only by what they are
+    #[serde(default)]
+    pub name : Option<String>,
+    #[serde(default)]
+    pub sprite : Option<String>,
+    #[serde(default)]
+    pub health : Option<i32>,
+    #[serde(default)]
+    pub action_points : Option<i32>,
+    #[serde(default)]
+    pub reaction : Option<ReactionTypeRef>,
+    /// items the character starts with in hand, by archetype name
+    #[serde(default)]
+    pub equipped : Vec<String>,
+    /// items the character starts with in their pack, by archetype name
+    #[serde(default)]
+    pub inventory : Vec<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ScenarioSpawner {
+    pub name : String,
+    pub faction : String,
+    pub position : AxialCoord,
+    #[serde(default = "default_spawner_sprite")]
+    pub sprite : String,
+    #[serde(default = "default_spawner_health")]
+    pub health : i32,
+    pub spawns : Vec<Spawn>,
+}
+
+fn default_spawner_sprite() -> String { strf("void/summoner_monolith") }
+fn default_spawner_health() -> i32 { 100 }
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ScenarioDialog {
+    /// the name of the character speaking
+    pub speaker : String,
+    pub text : String,
+    #[serde(default)]
+    pub requires_confirmation : bool,
+}
+
+/// reads a scenario from a RON file
+pub fn load_scenario<P : AsRef<Path>>(path : P) -> Result<ScenarioDefinition, String> {
+    let file = File::open(path.as_ref()).map_err(|e| format!("could not open scenario {:?}: {}", path.as_ref(), e))?;
+    ron::de::from_reader(BufReader::new(file)).map_err(|e| format!("could not parse scenario {:?}: {}", path.as_ref(), e))
+}
+
+/// every scenario that can be loaded from the assets folder, sorted by name. Files that fail to load are logged and left
+/// out rather than keeping the rest from being offered
+pub fn available_scenarios() -> Vec<DataScenario> {
+    let scenario_dir = match assets_path() {
+        Ok(assets) => assets.join(SCENARIOS_PATH),
+        Err(err) => {
+            info!("No scenarios available: {}", err);
+            return Vec::new();
+        }
+    };
+    let entries = match fs::read_dir(&scenario_dir) {
+        Ok(entries) => entries,
+        Err(err) => {
+            info!("No scenarios available, could not read {:?}: {}", scenario_dir, err);
+            return Vec::new();
+        }
+    };
+
+    let mut scenarios = entries.filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().map(|ext| ext == "ron").unwrap_or(false))
+        .filter_map(|p| match load_scenario(&p) {
+            Ok(definition) => Some(DataScenario { definition }),
+            Err(err) => {
+                warn!("Skipping scenario: {}", err);
+                None
+            }
+        })
+        .collect_vec();
+    scenarios.sort_by(|a, b| a.definition.name.cmp(&b.definition.name));
+    scenarios
+}
+
+/// the game's assets folder, found by searching outward from wherever the executable lives
+pub fn assets_path() -> Result<PathBuf, String> {
+    let mut cur_dir = current_exe().map_err(|e| format!("could not determine executable location: {}", e))?;
+    cur_dir.pop();
+    find_folder::SearchFolder {
+        start: cur_dir,
+        direction: find_folder::Search::ParentsThenKids(4, 4),
+    }.for_folder("assets").map_err(|e| format!("could not find assets folder: {}", e))
+}
+
+
+#[derive(Clone, Debug)]
+pub struct DataScenario {
+    pub definition : ScenarioDefinition,
+}
+
+impl Scenario for DataScenario {
+    fn initialize_scenario_world(&self) -> World {
+        let definition = &self.definition;
+        let mut raw_world = create_world();
+        {
+            let world = &mut raw_world;
+            ::samvival_core::initialize_world(world);
+
+            create_weapon_archetypes(world);
+            create_container_archetypes(world);
+            create_recipes(world);
+            let item_catalog = Catalog::of::<ItemArchetype>(world.view(), Entity::sentinel());
+            let create_item = |world : &mut World, name : &str| -> Option<Entity> {
+                match item_catalog.entities.get(name) {
+                    Some(archetype) => Some(logic::crafting::craft_without_materials(world, *archetype)),
+                    None => {
+                        warn!("Scenario {} refers to unknown item {}", definition.name, name);
+                        None
+                    }
+                }
+            };
+
+            match definition.map {
+                ScenarioMap::Generated(ref config) => {
+                    for tile in terrain::generator::generate(world, config) {
+                        let tile = tile.with(DebugData { name: strf("world tile") }).create(world);
+                        let pos = world.data::<TileData>(tile).position;
+                        world.index_entity(tile, pos);
+                    }
+                },
+                ScenarioMap::Fixed { ref shape, default_terrain, ref tiles } => {
+                    let overrides : HashMap<AxialCoord, FixedTerrain> = tiles.iter().cloned().collect();
+                    for coord in shape.hexes() {
+                        let kind = overrides.get(&coord).cloned().unwrap_or(default_terrain);
+                        let tile = fixed_tile(coord, kind).with(DebugData { name: strf("world tile") }).create(world);
+                        world.index_entity(tile, coord);
+                    }
+                }
+            }
+
+            let mut factions = HashMap::new();
+            for faction_def in &definition.factions {
+                let (r, g, b) = faction_def.color;
+                let faction = EntityBuilder::new()
+                    .with(FactionData {
+                        name: faction_def.name.clone(),
+                        color: Color::new(r, g, b, 1.0),
+                        player_faction: faction_def.player_faction,
+                    })
+                    .with(DebugData { name: format!("{} faction", faction_def.name) })
+                    .with_opt(if faction_def.objectives.non_empty() {
+                        Some(ObjectiveData::new(faction_def.objectives.clone()))
+                    } else if faction_def.player_faction {
+                        Some(ObjectiveData::standard())
+                    } else {
+                        None
+                    })
+                    .create(world);
+                factions.insert(faction_def.name.clone(), faction);
+            }
+
+            let first_faction = definition.factions.iter().find(|f| f.player_faction).or(definition.factions.first())
+                .and_then(|f| factions.get(&f.name).cloned())
+                .unwrap_or_else(|| {
+                    warn!("Scenario {} has no factions", definition.name);
+                    Entity::sentinel()
+                });
+            world.attach_world_data(TurnData {
+                turn_number: 0,
+                active_faction: first_faction,
+            });
+
+            let faction_named = |name : &str| -> Option<Entity> {
+                let faction = factions.get(name).cloned();
+                if faction.is_none() {
+                    warn!("Scenario {} refers to unknown faction {}", definition.name, name);
+                }
+                faction
+            };
+
+            for faction_def in &definition.factions {
+                let faction = factions[&faction_def.name];
+                for (other, relationship) in &faction_def.relationships {
+                    if let Some(other) = faction_named(other) {
+                        logic::faction::set_relationship(world, faction, other, *relationship);
+                    }
+                }
+            }
+
+            let character_archetypes = character_archetypes();
+            let mut characters_by_name = HashMap::new();
+            for char_def in &definition.characters {
+                let faction = match faction_named(&char_def.faction) {
+                    Some(faction) => faction,
+                    None => continue
+                };
+
+                let mut builder = character_archetypes.with_name(char_def.archetype.as_str()).clone()
+                    .with(AllegianceData { faction })
+                    .with(DebugData { name: char_def.name.clone().unwrap_or_else(|| char_def.archetype.clone()) });
+                if let Some(reaction) = char_def.reaction {
+                    builder = builder.with(ActionData { active_reaction: reaction, ..Default::default() });
+                }
+                let character = builder.create(world);
+
+                // the archetype fills in everything the scenario leaves unsaid, not every archetype has character data of its own
+                if world.view().data_opt::<CharacterData>(character).is_none() {
+                    world.attach_data(character, CharacterData::default());
+                }
+                if let Some(ref name) = char_def.name {
+                    if world.view().data_opt::<IdentityData>(character).is_none() {
+                        world.attach_data(character, IdentityData::new(name.as_str(), &taxonomy::Person));
+                    } else {
+                        world.modify(character, IdentityData::name.set_to(Some(name.clone())));
+                    }
+                    world.modify(character, CharacterData::name.set_to(name.clone()));
+                }
+                if let Some(ref sprite) = char_def.sprite { world.modify(character, CharacterData::sprite.set_to(sprite.clone())); }
+                if let Some(health) = char_def.health { world.modify(character, CharacterData::health.set_to(Reduceable::new(health))); }
+                if let Some(ap) = char_def.action_points { world.modify(character, CharacterData::action_points.set_to(Reduceable::new(ap))); }
+
+                for item_name in &char_def.equipped {
+                    if let Some(item) = create_item(world, item_name) {
+                        logic::item::put_item_in_inventory(world, item, character);
+                        logic::item::equip_item(world, item, character, true);
+                    }
+                }
+                for item_name in &char_def.inventory {
+                    if let Some(item) = create_item(world, item_name) {
+                        logic::item::put_item_in_inventory(world, item, character);
+                    }
+                }
+
+                logic::movement::place_entity_in_world(world, character, char_def.position);
+                if let Some(ref name) = char_def.name {
+                    characters_by_name.insert(name.clone(), character);
+                }
+            }
+
+            for (item_name, position) in &definition.ground_items {
+                if let Some(item) = create_item(world, item_name) {
+                    logic::item::place_item_in_world(world, item, *position);
+                }
+            }
+
+            for spawner_def in &definition.spawners {
+                let faction = match faction_named(&spawner_def.faction) {
+                    Some(faction) => faction,
+                    None => continue
+                };
+                let spawner_name = spawner_def.name.clone();
+                let spawner = EntityBuilder::new()
+                    .with(CharacterData {
+                        sprite: spawner_def.sprite.clone(),
+                        name: spawner_def.name.clone(),
+                        action_points: Reduceable::new(1),
+                        health: Reduceable::new(spawner_def.health),
+                        ..Default::default()
+                    })
+                    .with(MovementData { move_speed: Sext::of(0), ..Default::default() })
+                    .with(AllegianceData { faction })
+                    .with(PositionData::default())
+                    .with(CombatData { dodge_bonus: -10, ..Default::default() })
+                    .with(MonsterSpawnerData { spawns: spawner_def.spawns.clone(), ..Default::default() })
+                    .with(DebugData { name: spawner_def.name.to_lowercase() })
+                    .with_creator(move |world| IdentityData::of_kind(Taxon::new(world, spawner_name.to_lowercase().as_str(), &taxonomy::Monster)))
+                    .create(world);
+                logic::movement::place_entity_in_world(world, spawner, spawner_def.position);
+            }
+
+            if definition.triggers.non_empty() {
+                world.modify_world(TriggerData::triggers.set_to(definition.triggers.clone()), None);
+            }
+
+            world.add_event(GameEvent::WorldStart);
+
+            for line in &definition.dialog {
+                match characters_by_name.get(&line.speaker) {
+                    Some(speaker) => world.add_event(GameEvent::DialogSpoken {
+                        speaker : *speaker,
+                        text : RichString::new().with_appended(line.text.as_str(), RichStringStyle::Plain),
+                        requires_confirmation : line.requires_confirmation
+                    }),
+                    None => warn!("Scenario {} has dialog for unknown speaker {}", definition.name, line.speaker)
+                }
+            }
+        }
+
+        raw_world
+    }
+}
+
+/// a bare tile of the given kind, with the same movement costs, elevation and fertility the generator gives it
+fn fixed_tile(coord : AxialCoord, kind : FixedTerrain) -> EntityBuilder {
+    let bare_terrain = |kind : &Taxon, move_cost : Sext, elevation : i8, fertility : i8| TerrainData {
+        kind : kind.clone(),
+        move_cost,
+        elevation,
+        fertility,
+        ..Default::default()
+    };
+    let vegetation = |kind : &Taxon, move_cost : Sext, cover : i8| Some(VegetationData {
+        kind : kind.clone(),
+        move_cost,
+        cover,
+        harvestables : HashMap::new(),
+    });
+
+    let (terrain_data, vegetation_data) = match kind {
+        FixedTerrain::Plains => (bare_terrain(&taxonomy::terrain::Plains, Sext::of(1), 0, 4), None),
+        FixedTerrain::Grassland => (bare_terrain(&taxonomy::terrain::Plains, Sext::of(1), 0, 4), vegetation(&taxonomy::vegetation::Grassland, Sext::of(0), 0)),
+        FixedTerrain::Forest => (bare_terrain(&taxonomy::terrain::Plains, Sext::of(1), 0, 4), vegetation(&taxonomy::vegetation::DeciduousForest, Sext::of_parts(0,3), 2)),
+        FixedTerrain::Hills => (bare_terrain(&taxonomy::terrain::Hills, Sext::of(2), 1, 2), None),
+        FixedTerrain::Mountains => (bare_terrain(&taxonomy::terrain::Mountains, Sext::of(3), 2, 0), None),
+        FixedTerrain::Marsh => (bare_terrain(&taxonomy::terrain::Marsh, Sext::of(2), 0, 3), vegetation(&taxonomy::vegetation::Reedbed, Sext::of(0), 1)),
+        FixedTerrain::Desert => (bare_terrain(&taxonomy::terrain::Desert, Sext::of_parts(1, 2), 0, 0), None),
+        FixedTerrain::Tundra => (bare_terrain(&taxonomy::terrain::Tundra, Sext::of(1), 0, 1), None),
+        FixedTerrain::Lake => (bare_terrain(&taxonomy::terrain::Lake, Sext::of(3), 0, 0), None),
+    };
+
+    EntityBuilder::new()
+        .with(TileData { position : coord, occupied_by : None, name_modifiers : Vec::new() })
+        .with(terrain_data)
+        .with_opt(vegetation_data)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use spectral::prelude::*;
+
+    #[test]
+    pub fn test_scenario_files_load_and_populate_the_world() {
+        let definition = load_scenario("../../assets/survival/scenarios/river_crossing.ron").expect("scenario should parse");
+        let world = DataScenario { definition }.initialize_scenario_world();
+        let view = world.view();
+
+        let faction_named = |name : &str| view.entities_with_data::<FactionData>().find(|(_, f)| f.name == name).map(|(e, _)| *e).expect("faction should exist");
+        let player = faction_named("Player");
+        assert_that(&logic::faction::relationship(view, player, faction_named("Ferryfolk"))).is_equal_to(Relationship::Allied);
+        assert_that(&logic::faction::relationship(view, player, faction_named("Marsh"))).is_equal_to(Relationship::Hostile);
+
+        let (spearman, spearman_data) = view.entities_with_data::<CharacterData>().find(|(_, c)| c.name == "Haftdar").expect("named character should exist");
+        assert_that(&spearman_data.health.max_value()).is_equal_to(45);
+        assert_that(&view.data::<AllegianceData>(*spearman).faction).is_equal_to(player);
+        assert_that(&view.data::<PositionData>(*spearman).hex).is_equal_to(AxialCoord::new(1, -1));
+        assert_that(&view.entities_with_data::<MonsterSpawnerData>().count()).is_equal_to(1);
+    }
+}
@@ -2,6 +2,8 @@
 
 pub mod test_scenarios;
 
+pub mod data_scenario;
+
 
 use game::World;
 pub trait Scenario {
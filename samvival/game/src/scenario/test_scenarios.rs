@@ -3,6 +3,7 @@ use common::string::*;
 use game::prelude::*;
 use game::core::Reduceable;
 use scenario::Scenario;
+use scenario::data_scenario::assets_path;
 use samvival_core::create_world;
 use terrain;
 use common::Color;
@@ -14,8 +15,12 @@ use prelude::GameEvent;
 use entities::reactions::ReactionTypeRef;
 use game::DebugData;
 use archetypes::weapons::create_weapon_archetypes;
+use archetypes::containers::create_container_archetypes;
+use archetypes::recipes::create_recipes;
 
 
+pub const TERRAIN_CONFIG_PATH : &str = "survival/terrain_gen.ron";
+
 #[derive(Clone)]
 pub struct FirstEverScenario {}
 impl Scenario for FirstEverScenario {
@@ -26,9 +31,16 @@ impl Scenario for FirstEverScenario {
             ::samvival_core::initialize_world(world);
 
             create_weapon_archetypes(world);
+            create_container_archetypes(world);
+            create_recipes(world);
             let item_catalog = Catalog::of::<ItemArchetype>(world.view(), Entity::sentinel());
 
-            for tile in terrain::generator::generate(world, 70) {
+            // designers can drop a terrain config into the assets folder to try out other maps without recompiling
+            let terrain_config = assets_path().and_then(|assets| TerrainGenConfig::load(assets.join(TERRAIN_CONFIG_PATH))).unwrap_or_else(|err| {
+                info!("Using default terrain generation: {}", err);
+                TerrainGenConfig::default()
+            });
+            for tile in terrain::generator::generate(world, &terrain_config) {
                 let tile = tile.with(DebugData { name: strf("world tile") }).create(world);
                 let pos = world.data::<TileData>(tile).position;
                 world.index_entity(tile, pos);
@@ -41,6 +53,7 @@ impl Scenario for FirstEverScenario {
                     player_faction: true,
                 })
                 .with(DebugData { name: strf("player faction") })
+                .with(ObjectiveData::standard())
                 .create(world);
 
             world.attach_world_data(TurnData {
@@ -58,6 +71,17 @@ impl Scenario for FirstEverScenario {
                 .with(DebugData { name: strf("enemy faction") })
                 .create(world);
 
+            // a camp of villagers who'll fight alongside the player, and share what they see
+            let villager_faction = EntityBuilder::new()
+                .with(FactionData {
+                    name: String::from("Villagers"),
+                    color: Color::new(0.3, 0.9, 0.3, 1.0),
+                    player_faction: false,
+                })
+                .with(DebugData { name: strf("villager faction") })
+                .create(world);
+            logic::faction::set_relationship(world, player_faction, villager_faction, Relationship::Allied);
+
 //            let weapon_archetypes = weapon_archetypes();
 
             let character_archetypes = character_archetypes();
@@ -101,6 +125,12 @@ impl Scenario for FirstEverScenario {
 
             logic::movement::place_entity_in_world(world, archer, AxialCoord::new(0, 0));
 
+            // someone left their notes behind, a reward for the curious
+            if let Some(longsword_recipe) = logic::recipes::recipe_for(world.view(), item_catalog.entity_with_name("longsword")) {
+                let schematic = logic::recipes::create_schematic(world, longsword_recipe);
+                logic::item::place_item_in_world(world, schematic, AxialCoord::new(1, 0));
+            }
+
 
             let spearman = char_base("haftdar")
                 .with(CharacterData {
@@ -173,6 +203,17 @@ impl Scenario for FirstEverScenario {
                 monster
             };
 
+            let villager = char_base("Villager")
+                .with(CharacterData {
+                    sprite: String::from("human/peasant"),
+                    name: String::from("Villager"),
+                    health: Reduceable::new(20),
+                    ..Default::default()
+                })
+                .with(AllegianceData { faction: villager_faction })
+                .create(world);
+            logic::movement::place_entity_in_world(world, villager, AxialCoord::new(-3, 1));
+
             let monster1 = create_monster_at(world, AxialCoord::new(4, 0));
             let monster2 = create_monster_at(world, AxialCoord::new(0, 4));
 
@@ -190,11 +231,11 @@ impl Scenario for FirstEverScenario {
                 .with(CombatData { dodge_bonus: -10, ..Default::default() })
                 .with(MonsterSpawnerData {
                     spawns: vec![
-                        Spawn {
-                            entity: SpawnEntity::Character(strf("mud monster")),
-                            start_spawn_turn: 1,
-                            turns_between_spawns: 4,
-                        }]
+                        Spawn::new(SpawnEntity::Character(strf("mud monster")), 1, 4)
+                            .with_threat_budget(1.0, 0.25)
+                            .with_condition(SpawnCondition::MaxAlive(6))
+                            .with_spawn_radius(2)],
+                    ..Default::default()
                 })
                 .with_creator(|world|IdentityData::of_kind(Taxon::new(world, "summoning stone", &taxonomy::Monster)))
                 .create(world);
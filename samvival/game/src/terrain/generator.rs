@@ -8,7 +8,6 @@ use data::entities::TileData;
 use noise::RidgedMulti;
 use noise::OpenSimplex;
 use noise::Worley;
-use cgmath::InnerSpace;
 use noise::NoiseFn;
 
 use entities::VegetationData;
@@ -21,24 +20,43 @@ use entities::Skill;
 use entities::Harvestable;
 use entities::DepletionBehavior;
 use entities::ToolUse;
+use entities::RenewRate;
+use entities::Season;
 
 use std::collections::HashMap;
+use std::cmp::Ordering;
 use rand::distributions::Sample;
+use rand::Rng;
+use rand::StdRng;
+use rand::SeedableRng;
+use noise::Seedable;
+use entities::TerrainGenConfig;
 
-pub fn generate(world : &mut World, radius: i32) -> Vec<EntityBuilder> {
-    let r = radius;
+/// the config the terrain of the given world was generated from, generating a new world from it reproduces the same map
+pub fn recorded_config(view : &WorldView) -> Option<TerrainGenConfig> {
+    view.world_data_opt::<TerrainGenConfig>().cloned()
+}
+
+pub fn generate(world : &mut World, config : &TerrainGenConfig) -> Vec<EntityBuilder> {
+    if world.world_data_opt::<TerrainGenConfig>().is_some() {
+        warn!("Generating terrain for a world that already has some, only the original generation config will be recorded");
+    } else {
+        world.attach_world_data(config.clone());
+    }
 
     let mut ret = Vec::new();
 
-    let mut height_noise = RidgedMulti::new();
-    height_noise.octaves = 3;
-    height_noise.frequency = 0.7;
-    height_noise.persistence = 1.1;
+    let mut height_noise = RidgedMulti::new().set_seed(config.seed);
+    height_noise.octaves = config.height_octaves;
+    height_noise.frequency = config.height_frequency;
+    height_noise.persistence = config.height_persistence;
 
-    let forest_noise = OpenSimplex::new();
-    let forest_worley = Worley::new();
+    let forest_noise = OpenSimplex::new().set_seed(config.seed);
+    let forest_worley = Worley::new().set_seed(config.seed);
 
-    let mut rng = world.random(2344109);
+    let mut rng : StdRng = SeedableRng::from_seed(&[config.seed as usize][..]);
+    let density = |amount : i32| (amount as f64 * config.resource_density).round() as i32;
+    let weights = &config.biome_weights;
     let mut normal_dist = ::rand::distributions::Normal::new(0.5,0.15);
 
     let main_resources = &world.view().world_data::<Resources>().main;
@@ -84,93 +102,314 @@ pub fn generate(world : &mut World, radius: i32) -> Vec<EntityBuilder> {
         skills_used : vec![Skill::Mining],
         .. Default::default()
     }).create(world);
+    let grass_harvest = |world : &mut World| EntityBuilder::new().with(Harvestable {
+        action_name: strf("cut grass"),
+        amount: Reduceable::new(Sext::of(3)),
+        ap_per_harvest: 4,
+        fixed_amount_per_harvest: 2,
+        renew_rate: Some(RenewRate::new(Sext::of_parts(0, 3))
+            .fertility_dependent()
+            .in_season(Season::Spring, 1.5)
+            .in_season(Season::Autumn, 0.5)
+            .in_season(Season::Winter, 0.0)),
+        resource: main_resources.straw,
+        tool_use: ToolUse::DifficultWithout { amount_limit : Some(1), ap_increase : None },
+        tool: EntitySelector::is_a(&taxonomy::tools::SharpTool)
+            .or(EntitySelector::is_a(&taxonomy::weapons::BladedWeapon)),
+        skills_used: vec![Skill::Farming],
+        .. Default::default()
+    }).create(world);
+    let fruit_harvest = |world : &mut World| EntityBuilder::new().with(Harvestable {
+        action_name: strf("gather fruit"),
+        amount: Reduceable::new(Sext::of(2)),
+        ap_per_harvest: 4,
+        fixed_amount_per_harvest: 1,
+        renew_rate: Some(RenewRate::new(Sext::of_parts(0, 2))
+            .fertility_dependent()
+            .in_season(Season::Spring, 0.0)
+            .in_season(Season::Summer, 1.5)
+            .in_season(Season::Winter, 0.0)),
+        resource: main_resources.fruit,
+        tool_use: ToolUse::None,
+        skills_used: vec![Skill::ForestSurvival],
+        .. Default::default()
+    }).create(world);
+    let fish_harvest = |world : &mut World, amount : i32| EntityBuilder::new().with(Harvestable {
+        action_name: strf("catch fish"),
+        amount: Reduceable::new(Sext::of(amount)),
+        ap_per_harvest: 8,
+        dice_amount_per_harvest: DicePool::of(1, 2),
+        renew_rate: Some(RenewRate::new(Sext::of_parts(0, 3)).in_season(Season::Winter, 0.5)),
+        resource: main_resources.fish,
+        tool_use: ToolUse::DifficultWithout { amount_limit : Some(1), ap_increase : Some(8) },
+        tool: EntitySelector::is_a(&taxonomy::weapons::StabbingWeapon)
+            .or(EntitySelector::is_a(&taxonomy::tools::Rod)),
+        skills_used: vec![Skill::Spear],
+        .. Default::default()
+    }).create(world);
+    let reeds_harvest = |world : &mut World| EntityBuilder::new().with(Harvestable {
+        action_name: strf("cut reeds"),
+        amount: Reduceable::new(Sext::of(4)),
+        ap_per_harvest: 4,
+        fixed_amount_per_harvest: 2,
+        renew_rate: Some(RenewRate::new(Sext::of_parts(0, 3))
+            .fertility_dependent()
+            .in_season(Season::Winter, 0.0)),
+        resource: main_resources.reeds,
+        tool_use: ToolUse::DifficultWithout { amount_limit : Some(1), ap_increase : None },
+        tool: EntitySelector::is_a(&taxonomy::tools::SharpTool)
+            .or(EntitySelector::is_a(&taxonomy::weapons::BladedWeapon)),
+        skills_used: vec![Skill::Farming],
+        .. Default::default()
+    }).create(world);
+    let clay_harvest = |world : &mut World, amount : i32| EntityBuilder::new().with(Harvestable {
+        action_name: strf("dig clay"),
+        amount: Reduceable::new(Sext::of(amount)),
+        ap_per_harvest: 8,
+        dice_amount_per_harvest: DicePool::of(1, 2),
+        resource: main_resources.clay,
+        tool_use: ToolUse::DifficultWithout { amount_limit : Some(1), ap_increase : Some(4) },
+        tool: EntitySelector::is_a(&taxonomy::tools::Shovel),
+        .. Default::default()
+    }).create(world);
 
-    for x in -r .. r {
-        for y in -r.. r {
-            let coord = AxialCoord::new(x, y);
-            if coord.as_cart_vec().magnitude2() < 60.0 * 60.0 {
-                let mut vegetation_data : Option<VegetationData> = None;
-                let mut terrain_data = TerrainData::default();
+    // sample the fields that drive generation for every hex up front, rivers and lakes need to look at the
+    // height of neighboring hexes before any tile can be created
+    let coords = config.shape.hexes();
+    let mut samples = HashMap::new();
+    for coord in &coords {
+        let (x, y) = (coord.q as f64, coord.r as f64);
+        let raw = forest_noise.get([x * config.forest_scale, y * config.forest_scale]);
+        let forest = if forest_worley.get([x * 0.5, y * 0.5]) > -0.2 {
+            raw
+        } else {
+            -1.0
+        };
+        let height = height_noise.get([x * config.height_scale, y * config.height_scale]);
+        // temperature and moisture vary slowly across the map, with the high ground running colder
+        let temperature = forest_noise.get([x * config.temperature_scale + 531.0, y * config.temperature_scale - 212.0]) - height.max(0.0) * 0.5;
+        let moisture = forest_noise.get([x * config.moisture_scale - 97.0, y * config.moisture_scale + 348.0]);
 
+        samples.insert(*coord, HexSample { height, temperature, moisture, forest });
+    }
 
-                let raw = forest_noise.get([x as f64 * 0.074, y as f64 * 0.074]);
-                let forest_value = if forest_worley.get([x as f64 * 0.5, y as f64 * 0.5]) > -0.2 {
-                    raw
-                } else {
-                    -1.0
-                };
+    let water = place_water(config, &coords, &samples, &mut rng);
 
-                let noise = height_noise.get([x as f64 * 0.05, y as f64 * 0.05]);
-                if noise > 0.35 {
-                    terrain_data.kind = Taxon::of(&taxonomy::terrain::Mountains);
+    for coord in coords {
+        let sample = &samples[&coord];
+        let mut vegetation_data : Option<VegetationData> = None;
+        let mut terrain_data = TerrainData::default();
+
+        let forest_value = sample.forest;
+        let noise = sample.height;
+        if let Some(water_kind) = water.get(&coord) {
+            terrain_data.elevation = 0;
+            terrain_data.fertility = 0;
+            terrain_data.harvestables.insert(strf("fish"), (fish_harvest.clone())(world, density(2 + (normal_dist.sample(&mut rng) * 4.0) as i32)));
+            match water_kind {
+                WaterKind::River => {
+                    terrain_data.kind = Taxon::of(&taxonomy::terrain::River);
+                    terrain_data.move_cost = Sext::of(3);
+                    terrain_data.harvestables.insert(strf("clay"), (clay_harvest.clone())(world, density(2 + (normal_dist.sample(&mut rng) * 4.0) as i32)));
+                },
+                WaterKind::Lake => {
+                    terrain_data.kind = Taxon::of(&taxonomy::terrain::Lake);
                     terrain_data.move_cost = Sext::of(3);
-                    terrain_data.elevation = 2;
-                } else if noise > 0.0 {
-                    terrain_data.kind = Taxon::of(&taxonomy::terrain::Hills);
-                    terrain_data.move_cost = Sext::of(2);
-                    terrain_data.elevation = 1;
-                    let stone_amount = 3 + (normal_dist.sample(&mut rng) * 10.0) as i32;
-                    terrain_data.harvestables.insert(strf("quarry stone"), (quarry_stone_harvest.clone())(world, stone_amount));
-                    if forest_value > 0.25 {
-                        vegetation_data = Some(VegetationData {
-                            harvestables : HashMap::new(),
-                            move_cost : Sext::of_parts(0,3),
-                            cover : 2,
-                            kind : Taxon::of(&taxonomy::vegetation::PineForest)
-                        });
-                    }
-                } else {
-                    terrain_data.kind = Taxon::of(&taxonomy::terrain::Plains);
-                    terrain_data.move_cost = Sext::of(1);
-                    terrain_data.elevation = 0;
-                    terrain_data.harvestables.insert(strf("dirt"), (dirt_tile_harvest.clone())(world, 6 + (normal_dist.sample(&mut rng) * 3.0) as i32));
-                    if forest_value > 0.1 {
-                        let mut harvestables = HashMap::new();
-                        harvestables.insert(strf("wood"), EntityBuilder::new().with(Harvestable {
-                            action_name: strf("chop wood"),
-                            amount: Reduceable::new(Sext::of(((normal_dist.sample(&mut rng) * 5 as f64) as i32).max(0))),
-                            ap_per_harvest: 8,
-                            dice_amount_per_harvest: DicePool::of(1,4),
-                            resource: main_resources.wood,
-                            tool_use: ToolUse::DifficultWithout { amount_limit : Some(1), ap_increase : Some(8) },
-                            tool: EntitySelector::is_a(&taxonomy::Axe),
-                            on_depletion: DepletionBehavior::RemoveLayer,
-                            skills_used : vec![Skill::Axe, Skill::ForestSurvival],
-                            .. Default::default()
-                        }).create(world));
-
-                        vegetation_data = Some(VegetationData {
-                            harvestables,
-                            move_cost : Sext::of_parts(0,3),
-                            cover : 2,
-                            kind : Taxon::of(&taxonomy::vegetation::DeciduousForest)
-                        });
-                    } else {
-                        vegetation_data = Some(VegetationData {
-                            harvestables : HashMap::new(),
-                            move_cost : Sext::of(0),
-                            cover : 0,
-                            kind : Taxon::of(&taxonomy::vegetation::Grassland)
-                        });
-                    }
                 }
+            }
+        } else if noise > config.mountain_threshold {
+            terrain_data.kind = Taxon::of(&taxonomy::terrain::Mountains);
+            terrain_data.move_cost = Sext::of(3);
+            terrain_data.elevation = 2;
+            terrain_data.fertility = 0;
+        } else if noise > config.hill_threshold {
+            terrain_data.kind = Taxon::of(&taxonomy::terrain::Hills);
+            terrain_data.move_cost = Sext::of(2);
+            terrain_data.elevation = 1;
+            terrain_data.fertility = 2;
+            let stone_amount = density(3 + (normal_dist.sample(&mut rng) * 10.0) as i32);
+            terrain_data.harvestables.insert(strf("quarry stone"), (quarry_stone_harvest.clone())(world, stone_amount));
+            if forest_value > weighted_threshold(0.25, weights.forest) && sample.moisture > -0.3 {
+                vegetation_data = Some(VegetationData {
+                    harvestables : HashMap::new(),
+                    move_cost : Sext::of_parts(0,3),
+                    cover : 2,
+                    kind : Taxon::of(&taxonomy::vegetation::PineForest)
+                });
+            }
+        } else if sample.temperature < -weighted_threshold(0.35, weights.tundra) {
+            terrain_data.kind = Taxon::of(&taxonomy::terrain::Tundra);
+            terrain_data.move_cost = Sext::of(1);
+            terrain_data.elevation = 0;
+            terrain_data.fertility = 1;
+            terrain_data.harvestables.insert(strf("loose stone"), (stone_tile_harvest.clone())(world, density(2 + (normal_dist.sample(&mut rng) * 4.0) as i32), 1.0));
+        } else if sample.temperature > weighted_threshold(0.35, weights.desert) && sample.moisture < -0.1 {
+            terrain_data.kind = Taxon::of(&taxonomy::terrain::Desert);
+            terrain_data.move_cost = Sext::of_parts(1, 2);
+            terrain_data.elevation = 0;
+            terrain_data.fertility = 0;
+            terrain_data.harvestables.insert(strf("loose stone"), (stone_tile_harvest.clone())(world, density(2 + (normal_dist.sample(&mut rng) * 6.0) as i32), 1.0));
+        } else if sample.moisture > weighted_threshold(0.35, weights.marsh) {
+            terrain_data.kind = Taxon::of(&taxonomy::terrain::Marsh);
+            terrain_data.move_cost = Sext::of(2);
+            terrain_data.elevation = 0;
+            terrain_data.fertility = 3;
+            terrain_data.harvestables.insert(strf("clay"), (clay_harvest.clone())(world, density(4 + (normal_dist.sample(&mut rng) * 6.0) as i32)));
 
+            let mut harvestables = HashMap::new();
+            harvestables.insert(strf("reeds"), (reeds_harvest.clone())(world));
+            vegetation_data = Some(VegetationData {
+                harvestables,
+                move_cost : Sext::of(0),
+                cover : 1,
+                kind : Taxon::of(&taxonomy::vegetation::Reedbed)
+            });
+        } else {
+            terrain_data.kind = Taxon::of(&taxonomy::terrain::Plains);
+            terrain_data.move_cost = Sext::of(1);
+            terrain_data.elevation = 0;
+            terrain_data.fertility = 4;
+            terrain_data.harvestables.insert(strf("dirt"), (dirt_tile_harvest.clone())(world, density(6 + (normal_dist.sample(&mut rng) * 3.0) as i32)));
+            if forest_value > weighted_threshold(0.1, weights.forest) && sample.moisture > -0.3 {
+                let mut harvestables = HashMap::new();
+                harvestables.insert(strf("wood"), EntityBuilder::new().with(Harvestable {
+                    action_name: strf("chop wood"),
+                    amount: Reduceable::new(Sext::of(density((normal_dist.sample(&mut rng) * 5 as f64) as i32).max(0))),
+                    ap_per_harvest: 8,
+                    dice_amount_per_harvest: DicePool::of(1,4),
+                    resource: main_resources.wood,
+                    tool_use: ToolUse::DifficultWithout { amount_limit : Some(1), ap_increase : Some(8) },
+                    tool: EntitySelector::is_a(&taxonomy::Axe),
+                    renew_rate: Some(RenewRate::new(Sext::of_parts(0, 1)).fertility_dependent().in_season(Season::Winter, 0.0)),
+                    on_depletion: DepletionBehavior::RemoveLayer,
+                    skills_used : vec![Skill::Axe, Skill::ForestSurvival],
+                    .. Default::default()
+                }).create(world));
+                harvestables.insert(strf("fruit"), (fruit_harvest.clone())(world));
 
-                let tile_data = TileData {
-                    position: coord,
-                    occupied_by: None,
-                    name_modifiers : Vec::new()
-                };
-
-                let tile = EntityBuilder::new()
-                    .with(tile_data)
-                    .with(terrain_data)
-                    .with_opt(vegetation_data);
-//                    .with(InventoryData::default());
-                ret.push(tile);
+                vegetation_data = Some(VegetationData {
+                    harvestables,
+                    move_cost : Sext::of_parts(0,3),
+                    cover : 2,
+                    kind : Taxon::of(&taxonomy::vegetation::DeciduousForest)
+                });
+            } else {
+                let mut harvestables = HashMap::new();
+                harvestables.insert(strf("grass"), (grass_harvest.clone())(world));
+                vegetation_data = Some(VegetationData {
+                    harvestables,
+                    move_cost : Sext::of(0),
+                    cover : 0,
+                    kind : Taxon::of(&taxonomy::vegetation::Grassland)
+                });
             }
         }
+
+
+        let tile_data = TileData {
+            position: coord,
+            occupied_by: None,
+            name_modifiers : Vec::new()
+        };
+
+        let tile = EntityBuilder::new()
+            .with(tile_data)
+            .with(terrain_data)
+            .with_opt(vegetation_data);
+        ret.push(tile);
     }
 
     ret
-}
\ No newline at end of file
+}
+
+
+/// how far a river may run before it is cut off
+const MAX_RIVER_LENGTH : usize = 80;
+
+struct HexSample {
+    height : f64,
+    temperature : f64,
+    moisture : f64,
+    forest : f64,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+enum WaterKind {
+    River,
+    Lake,
+}
+
+/// shifts a threshold that noise must exceed so that a weight of 1.0 leaves it unchanged, 0.0 pushes it out of the
+/// range of the noise entirely, and larger weights make it easier to reach
+fn weighted_threshold(threshold : f64, weight : f64) -> f64 {
+    1.0 - (1.0 - threshold) * weight
+}
+
+fn height_at(samples : &HashMap<AxialCoord, HexSample>, coord : &AxialCoord) -> f64 {
+    samples.get(coord).map(|s| s.height).unwrap_or(1000.0)
+}
+
+/// fills the basins of the height field in wet lowlands with lakes, then runs rivers downhill from the mountains until
+/// they join other water, leave the map, or reach a basin of their own (which then pools into a lake)
+fn place_water(config : &TerrainGenConfig, coords : &[AxialCoord], samples : &HashMap<AxialCoord, HexSample>, rng : &mut StdRng) -> HashMap<AxialCoord, WaterKind> {
+    let mut water = HashMap::new();
+
+    for coord in coords {
+        let sample = &samples[coord];
+        let is_basin = coord.neighbors_vec().iter().all(|n| samples.get(n).map(|ns| ns.height >= sample.height).unwrap_or(false));
+        if sample.height < config.hill_threshold && sample.moisture > 0.0 && is_basin {
+            let size = 2 + (sample.moisture * 8.0) as usize;
+            fill_lake(config, *coord, size, samples, &mut water);
+        }
+    }
+
+    for coord in coords {
+        let sample = &samples[coord];
+        if sample.height > config.mountain_threshold && sample.moisture > -0.2 && rng.gen_range(0, config.river_rarity.max(1)) == 0 {
+            let mut current = *coord;
+            for _ in 0 .. MAX_RIVER_LENGTH {
+                let neighbors = current.neighbors_vec();
+                if neighbors.iter().any(|n| !samples.contains_key(n)) {
+                    // flows off the edge of the map
+                    break;
+                }
+                let next = *neighbors.iter().min_by(|a, b| height_at(samples, a).partial_cmp(&height_at(samples, b)).unwrap_or(Ordering::Equal)).unwrap();
+                if water.contains_key(&next) {
+                    break;
+                } else if height_at(samples, &next) >= height_at(samples, &current) {
+                    fill_lake(config, current, 3, samples, &mut water);
+                    break;
+                } else {
+                    // rivers cut through the hills, but not the mountain peaks they rise from
+                    if height_at(samples, &next) <= config.mountain_threshold {
+                        water.insert(next, WaterKind::River);
+                    }
+                    current = next;
+                }
+            }
+        }
+    }
+
+    water
+}
+
+/// floods outward from the given hex, always into the lowest adjacent lowland hex, until the lake reaches the given size
+fn fill_lake(config : &TerrainGenConfig, start : AxialCoord, size : usize, samples : &HashMap<AxialCoord, HexSample>, water : &mut HashMap<AxialCoord, WaterKind>) {
+    let mut frontier = vec![start];
+    let mut filled = 0;
+    while filled < size && ! frontier.is_empty() {
+        let lowest = frontier.iter().enumerate()
+            .min_by(|(_, a), (_, b)| height_at(samples, a).partial_cmp(&height_at(samples, b)).unwrap_or(Ordering::Equal))
+            .map(|(i, _)| i).unwrap();
+        let next = frontier.swap_remove(lowest);
+        if water.get(&next) != Some(&WaterKind::Lake) {
+            water.insert(next, WaterKind::Lake);
+            filled += 1;
+            for neighbor in next.neighbors_vec() {
+                if height_at(samples, &neighbor) <= config.hill_threshold && ! water.contains_key(&neighbor) {
+                    frontier.push(neighbor);
+                }
+            }
+        }
+    }
+}
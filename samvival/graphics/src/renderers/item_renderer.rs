@@ -17,6 +17,7 @@ use game::entities::Taxon;
 use graphics::ImageIdentifier;
 use std::collections::VecDeque;
 
+const MAX_PILE_OFFSETS : u32 = 3;
 
 pub struct ItemRenderer {
     items_to_draw: Vec<Entity>,
@@ -40,11 +41,9 @@ impl ItemRenderer {
             // so it is acting as an entity of its own
             if let Some(in_inventory) = item_data.in_inventory_of {
                 if world.has_data::<TileData>(in_inventory) {
-                    println!("Item in tile inventory, will draw");
                     self.items_to_draw.push(*id);
                 }
             } else if world.has_data::<PositionData>(*id) {
-                println!("Pushing item to draw on its own");
                 self.items_to_draw.push(*id);
             }
         }
@@ -108,13 +107,17 @@ impl ItemRenderer {
         }
     }
 
-    pub fn render_items(&mut self, world: &WorldView, resources : &mut GraphicsResources, _bounds : Rect<f32>) -> DrawList {
+    pub fn render_items(&mut self, world: &WorldView, resources : &mut GraphicsResources, player_faction : Entity, _bounds : Rect<f32>) -> DrawList {
         if self.cached_game_time != Some(world.current_time) {
             self.identify_relevant_entities(world);
             self.cached_game_time = Some(world.current_time);
         }
 
         let mut quads : Vec<Quad> = vec![];
+        // items the player has never seen the ground of are not drawn, and multiple items on the same hex are fanned out
+        // slightly so that a pile reads as a pile
+        let visibility = world.world_data::<VisibilityData>().visibility_by_faction.get(&player_faction);
+        let mut items_drawn_at : HashMap<AxialCoord, u32> = HashMap::new();
 
         for ent in &self.items_to_draw {
             let ent = *ent;
@@ -123,10 +126,17 @@ impl ItemRenderer {
             let graphics_data = world.data::<GraphicsData>(ent);
 
             // Main item display
-            if let Some(pos) = ItemRenderer::draw_at(world, ent) {
-                let pos = pos.as_cart_vec();
+            if let Some(hex) = ItemRenderer::draw_at(world, ent) {
+                if ! visibility.map(|v| v.revealed_hexes.contains(&hex)).unwrap_or(false) {
+                    continue;
+                }
+                let drawn_here = items_drawn_at.entry(hex).or_insert(0);
+                let fan_offset = (*drawn_here).min(MAX_PILE_OFFSETS) as f32 * 0.15;
+                *drawn_here += 1;
+
+                let pos = hex.as_cart_vec();
                 let img = ItemRenderer::cached_image_for(world, &mut self.item_images_by_taxon, resources, ident_data);
-                let quad = Quad::new(img, pos.0).centered().color(graphics_data.color);
+                let quad = Quad::new(img, pos.0 + v2(fan_offset, -fan_offset)).centered().color(graphics_data.color);
                 quads.push(quad);
             } else {
                 warn!("Tried to draw item {} but could not identify a position to do so", ent);
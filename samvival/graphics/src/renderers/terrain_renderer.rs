@@ -43,6 +43,13 @@ impl TerrainRenderer {
 
             let accessor = TileAccessor::new(world);
 
+            // darken the visible map as the light fades
+            let light_color = match world.world_data_opt::<TimeData>().map(|t| t.time_of_day()).unwrap_or_default() {
+                TimeOfDay::Daylight => Color::white(),
+                TimeOfDay::Dawn | TimeOfDay::Dusk => Color::new(0.85, 0.75, 0.7, 1.0),
+                TimeOfDay::Night => Color::new(0.55, 0.55, 0.75, 1.0),
+            };
+
 //            for q in map_data.min_tile_bound.q..map_data.max_tile_bound.q + 1 {
 //                for r in map_data.min_tile_bound.r..map_data.max_tile_bound.r + 1 {
             for x in -dist ..= dist {
@@ -54,22 +61,24 @@ impl TerrainRenderer {
                             let visible = visible_hexes.contains(&pos);
 
                             let color = if visible {
-                                Color::white()
+                                light_color
                             } else {
                                 Color::greyscale(0.5)
                             };
                             if let Some(t) = accessor.tile_opt(pos) {
                                 let terrain = accessor.terrain(&t);
                                 let vegetation = accessor.vegetation_opt(&t);
-                                let quad = Quad::new(format!("terrain/{}", terrain.kind.name().to_snake_case()), cartesian_pos.0)
+                                let (texture, tint) = texture_for_kind(&terrain.kind);
+                                let quad = Quad::new(texture, cartesian_pos.0)
                                     .centered()
-                                    .color(color);
+                                    .color(color * tint);
                                 quads.push(quad);
 
                                 if let Some(vegetation) = vegetation {
-                                    let quad = Quad::new(format!("terrain/{}", vegetation.kind.name().to_snake_case()), cartesian_pos.0)
+                                    let (texture, tint) = texture_for_kind(&vegetation.kind);
+                                    let quad = Quad::new(texture, cartesian_pos.0)
                                         .centered()
-                                        .color(color);
+                                        .color(color * tint);
                                     over_quads.push(quad);
                                 }
                             }
@@ -95,4 +104,17 @@ impl TerrainRenderer {
 //
 //        &self.last_draw_list
     }
-}
\ No newline at end of file
+}
+
+/// terrain and vegetation kinds that do not yet have art of their own are drawn with the texture of a similar kind,
+/// tinted to set them apart
+fn texture_for_kind(kind : &Taxon) -> (String, Color) {
+    match kind.name() {
+        "tundra" => (strf("terrain/plains"), Color::new(0.9, 0.95, 1.1, 1.0)),
+        "desert" => (strf("terrain/plains"), Color::new(1.2, 1.05, 0.6, 1.0)),
+        "marsh" => (strf("terrain/plains"), Color::new(0.6, 0.75, 0.55, 1.0)),
+        "river" | "lake" => (strf("terrain/plains"), Color::new(0.35, 0.55, 1.2, 1.0)),
+        "reedbed" => (strf("terrain/grassland"), Color::new(0.8, 0.9, 0.6, 1.0)),
+        name => (format!("terrain/{}", name.to_snake_case()), Color::white()),
+    }
+}
@@ -20,6 +20,8 @@ pub struct UnitRenderer {
 }
 
 const HEALTH_BAR_WIDTH : f32 = 0.2;
+const ACTION_PROGRESS_BAR_WIDTH : f32 = 1.2;
+const ACTION_PROGRESS_BAR_HEIGHT : f32 = 0.15;
 //const STAMINA_WHEEL_WIDTH : f32 = 0.3;
 
 impl UnitRenderer {
@@ -109,6 +111,17 @@ impl UnitRenderer {
                             let img = format!("ui/harvesting_anim{}", alternator);
                             quads.push(Quad::new(img, (pos + CartVec::new(-0.9,0.65)).0).centered());
                         }
+
+                        // Progress toward a multi-turn action
+                        let progress_fract = if active_action.ap.required > 0 {
+                            (active_action.ap.current as f32 / active_action.ap.required as f32).min(1.0)
+                        } else { 1.0 };
+                        let progress_pos = pos - CartVec::new(ACTION_PROGRESS_BAR_WIDTH * 0.5, 0.8);
+                        quads.push(Quad::new(strf("ui/blank"), progress_pos.0 - v2(0.02,0.02)).size(v2(ACTION_PROGRESS_BAR_WIDTH + 0.04, ACTION_PROGRESS_BAR_HEIGHT + 0.04)).color(Color::black()));
+                        quads.push(Quad::new(strf("ui/blank"), progress_pos.0).size(v2(ACTION_PROGRESS_BAR_WIDTH, ACTION_PROGRESS_BAR_HEIGHT)));
+                        quads.push(Quad::new(strf("ui/blank"), progress_pos.0)
+                                       .size(v2(ACTION_PROGRESS_BAR_WIDTH * progress_fract, ACTION_PROGRESS_BAR_HEIGHT))
+                                       .color(Color::new(0.8, 0.6, 0.1, 1.0)));
                     }
 
 
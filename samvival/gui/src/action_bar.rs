@@ -19,6 +19,7 @@ use graphics::FontSize;
 pub enum PlayerActionType {
     MoveAndAttack(MovementTypeRef, AttackRef),
     Harvest,
+    PickUp,
     InteractWithInventory,
     Move(MovementTypeRef),
     Wait,
@@ -34,6 +35,7 @@ impl PlayerActionType {
             PlayerActionType::Move(move_ref) => move_ref.resolve(world).map(|mt| mt.name.capitalized()).unwrap_or_else(|| String::from("Unknown move type")),
             PlayerActionType::Wait => String::from("Wait"),
             PlayerActionType::Harvest => String::from("Harvest"),
+            PlayerActionType::PickUp => String::from("Pick Up"),
             PlayerActionType::Continue { previous } => format!("Continue : {}", previous.name(world, character)),
             PlayerActionType::None => {
                 let active_action_str = world.data::<ActionData>(character).active_action.as_ref().map(|a| a.action_type.name()).unwrap_or("");
@@ -49,6 +51,7 @@ impl PlayerActionType {
             PlayerActionType::Move(move_ref) => format!("{} across terrain to another location", move_ref.resolve(world).map(|m| m.name.as_str()).unwrap_or("move")),
             PlayerActionType::Wait => format!("Do nothing for the moment"),
             PlayerActionType::Harvest => format!("Harvest resources from a nearby hex."),
+            PlayerActionType::PickUp => format!("Pick up everything lying on the ground in a nearby hex, moving there if necessary."),
             PlayerActionType::None => format!("Cancels the active action, losing any progress you may have made"),
             PlayerActionType::Continue { previous } => format!("Continue performing an action that has already been started, but requires more time to finish."),
         }
@@ -61,6 +64,7 @@ impl PlayerActionType {
             PlayerActionType::Move(move_ref) => format!("ui/move_icon"),
             PlayerActionType::Wait => format!("ui/clock_icon"),
             PlayerActionType::Harvest => format!("ui/harvest_icon"),
+            PlayerActionType::PickUp => format!("ui/interact_with_inventory_icon"),
             PlayerActionType::None => format!("ui/cancel"),
             PlayerActionType::Continue { previous } => previous.icon(world, character)
         }
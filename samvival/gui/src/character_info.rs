@@ -24,6 +24,7 @@ pub struct CharacterInfoWidget {
     character_stats_widget: ListWidget<CharacterStatsWidget>,
     tabs: TabWidget,
     skills: ListWidget<SkillWidget>,
+    perks: ListWidget<PerkWidget>,
     attack_descriptions : AttackDescriptionsWidget
 }
 
@@ -69,6 +70,15 @@ impl CharacterInfoWidget {
             CharacterStat::new("Stamina", |view,ent| view.character(ent).stamina.cur_value().as_i32(), |view,ent| view.character(ent).stamina.max_value().as_i32(),
                                "How much endurance this character has left for performing strenuous actions. Attacking, reacting, running and the like all \
                                consume some amount of stamina. Normally recovers by 1 each turn."),
+            CharacterStat::cur_only("Food", |view,ent| need_text(view, ent, Need::Hunger),
+                                    "How well fed this character is. Falls every turn, faster in winter, and is restored by eating. Going hungry \
+                                    slows stamina recovery, then costs AP, and eventually health"),
+            CharacterStat::cur_only("Water", |view,ent| need_text(view, ent, Need::Thirst),
+                                    "How well watered this character is. Falls every turn, faster in a heat wave, and is restored by eating fruit \
+                                    or spending the turn next to a river or lake"),
+            CharacterStat::cur_only("Warmth", |view,ent| need_text(view, ent, Need::Cold),
+                                    "How warm this character is. Falls at night, in winter and in bad weather, and is restored by daylight, \
+                                    staying near a campfire, or sheltering"),
         ];
 
         let character_stats_widget = ListWidget::featherweight()
@@ -76,7 +86,7 @@ impl CharacterInfoWidget {
             .size(Sizing::match_parent(), Sizing::SurroundChildren)
             .position(Positioning::ux(1.0), Positioning::below(&unit_icon_background, 10.px()));
 
-        let tabs = TabWidget::new(vec!["Attacks", "Skills"])
+        let tabs = TabWidget::new(vec!["Attacks", "Skills", "Perks"])
             .parent(&main_widget)
             .size(Sizing::ux(30.0), Sizing::ux(50.0))
             .position(Positioning::ux(1.0), Positioning::ux(30.0))
@@ -89,6 +99,13 @@ impl CharacterInfoWidget {
             .apply(gui);
         skills.item_archetype.set_margin(4.px());
 
+        let mut perks = ListWidget::new()
+            .parent(tabs.tab_named("Perks"))
+            .size(Sizing::match_parent(), Sizing::match_parent())
+            .position(Positioning::origin(), Positioning::origin())
+            .apply(gui);
+        perks.item_archetype.set_margin(4.px());
+
         let attack_descriptions = AttackDescriptionsWidget::new(tabs.tab_named("Attacks"));
 
         CharacterInfoWidget {
@@ -99,6 +116,7 @@ impl CharacterInfoWidget {
             unit_icon,
             unit_icon_background,
             skills,
+            perks,
             tabs,
             attack_descriptions
         }
@@ -150,12 +168,35 @@ impl CharacterInfoWidget {
                 skill_w.xp_bar_full.set_parent(&skill_w.xp_bar_empty).set_width(Sizing::PcntOfParent(xp_pcnt as f32));
             }).reapply_all(gui);
 
+            // perks already earned first, then the ones still to come so there's something to work towards
+            let perks : Vec<(Perk, bool)> = logic::perks::owned_perks(world_view, selected).into_iter().map(|p| (p, true))
+                .chain(logic::perks::upcoming_perks(world_view, selected).into_iter().map(|p| (p, false)))
+                .collect();
+
+            self.perks.update(gui, perks.as_ref(), |perk_w, perk| {
+                let (perk, owned) = *perk;
+                let color = if owned { Color::black() } else { Color::greyscale(0.45) };
+                perk_w.name.modify_widget_type(|wt| wt.set_text(format!("{} ({} {})", perk.name, perk.skill.name(), perk.level)))
+                    .set_color(color);
+                perk_w.description.modify_widget_type(|wt| wt.set_text(perk.description))
+                    .set_color(color)
+                    .set_y(Positioning::below(&perk_w.name, 2.px()));
+            }).reapply_all(gui);
+
             self.attack_descriptions.update(gui, world_view, selected, control);
         }
     }
 }
 
 
+fn need_text(view : &WorldView, character : Entity, need : Need) -> String {
+    match view.data_opt::<NeedsData>(character) {
+        Some(needs) => format!("{} / {}", needs.level(need).cur_value(), needs.level(need).max_value()),
+        None => strf("-")
+    }
+}
+
+
 #[derive(WidgetContainer, Clone)]
 pub struct SkillWidget {
     text: Widget,
@@ -225,6 +266,21 @@ impl Default for CharacterStat {
 }
 
 
+#[derive(WidgetContainer, Clone)]
+pub struct PerkWidget {
+    name: Widget,
+    description: Widget,
+}
+
+impl Default for PerkWidget {
+    fn default() -> Self {
+        PerkWidget {
+            name: Widget::text("", FontSize::Standard),
+            description: Widget::text("", FontSize::Small).width(Sizing::match_parent()),
+        }
+    }
+}
+
 #[derive(Default, WidgetContainer)]
 pub struct CharacterStatsWidget {
     text: Widget
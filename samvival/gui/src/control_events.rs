@@ -19,6 +19,8 @@ pub enum TacticalEvents {
     CounterattackSelected(AttackRef),
     ItemTransferRequested { item : Entity, from : Vec<Entity>, to : Vec<Entity> },
     EquipItemRequested { item : Entity, equip_on : Entity },
+    ConsumeItemRequested { item : Entity, consumer : Entity },
+    BuildRequested { structure : Entity, builder : Entity },
     DisplayMessage(Message),
     SpeechDialogDismissed(Entity, Wid),
     Save,
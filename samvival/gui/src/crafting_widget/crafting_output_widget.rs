@@ -8,6 +8,7 @@ use std::collections::HashMap;
 use itertools::Either;
 use game::logic::crafting;
 use game::prelude::*;
+use game::entities::recipes::{Recipe, RecipeFlavor};
 
 
 #[derive(WidgetContainer)]
@@ -62,10 +63,31 @@ impl CraftingOutputWidget {
     pub fn update(&mut self, world : &mut World, view : &WorldView, gui : &mut GUI, crafter : Entity, base_recipe : Option<Entity>, ingredients : &HashMap<Taxon, Vec<Entity>>) {
         self.body.reapply(gui);
         if let Some(base_recipe) = base_recipe {
+            // repairs don't produce a new item, so rather than showing what would be made, describe what would be mended
+            if let RecipeFlavor::Repair { .. } = view.data::<Recipe>(base_recipe).flavor {
+                match crafting::compute_repair_breakdown(view, crafter, ingredients, base_recipe) {
+                    Ok(breakdown) => {
+                        self.reason_display.show()
+                            .set_text(format!("Restores {} durability to {}", breakdown.durability_restored.total, view.signifier(breakdown.item)))
+                            .reapply(gui);
+                        self.craft_button.set_text("Repair").show().reapply(gui);
+                    },
+                    Err(reason) => {
+                        self.reason_display.show()
+                            .set_text(reason)
+                            .reapply(gui);
+                        self.craft_button.hide().reapply(gui);
+                    },
+                }
+                self.item_display.hide().reapply(gui);
+                self.cancel_button.show().reapply(gui);
+                return;
+            }
+
             match crafting::compute_crafting_breakdown(world, view, crafter, ingredients, base_recipe) {
                 Ok(breakdown) => {
                     self.reason_display.hide().reapply(gui);
-                    self.craft_button.show().reapply(gui);
+                    self.craft_button.set_text("Craft").show().reapply(gui);
                     self.item_display.show().update(view, gui, Either::Left((&breakdown.result_identity, &breakdown.effective_archetype)));
                 },
                 Err(reason) => {
@@ -92,7 +92,6 @@ impl CraftingWidget {
     pub fn toggle(&mut self, view : &WorldView, gui : &mut GUI) -> bool {
         self.body.reapply(gui);
 
-        self.recipe_selector.update(view, gui);
         if self.body.showing {
             self.body.hide().reapply(gui);
             false
@@ -127,6 +126,7 @@ impl CraftingWidget {
                 self.ingredient_assignment_widget.update(view, gui, &recipe_dat.ingredients_by_kind, &self.ingredient_assignments);
             } else {
                 self.recipe_selector.show().reapply(gui);
+                self.recipe_selector.update(view, gui, selected);
                 self.ingredient_assignment_widget.hide().reapply(gui);
             }
 
@@ -155,10 +155,9 @@ impl CraftingWidget {
                     self.ingredient_assignments.clear();
                 } else if let Some(CraftWidgetInternalEvent::Craft) = evt.as_custom_event_no_origin() {
                     if let Some(recipe) = self.selected_base_recipe {
-                        match crafting::craft(world, selected, &self.ingredient_assignments, recipe) {
-                            Ok(crafted_entity) =>  {
-                                item::put_item_in_inventory(world, crafted_entity, selected);
-                            },
+                        match crafting::craft_over_time(world, selected, &self.ingredient_assignments, recipe, None) {
+                            Ok(Some(_)) => (),
+                            Ok(None) => control_context.trigger_event(TacticalEvents::DisplayMessage(Message::new("Crafting will take more than this turn to finish"))),
                             Err(err_str) => control_context.trigger_event(TacticalEvents::DisplayMessage(Message::new(err_str)))
                         }
                     } else { warn!("Craft operation requested without a selected recipe, which is weird") }
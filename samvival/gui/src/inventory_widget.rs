@@ -6,6 +6,9 @@ use game::prelude::*;
 use game::entities::IdentityData;
 use game::entities::EquipmentData;
 use game::entities::StackData;
+use game::entities::Encumbrance;
+use game::entities::FoodInfo;
+use game::entities::StructureData;
 use gui::TabWidget;
 use state::ControlContext;
 use control_events::*;
@@ -37,7 +40,16 @@ pub struct InventoryDisplayData {
     pub equipped_items: HashSet<Entity>,
     pub from_entities: Vec<Entity>,
     pub equippable: bool,
-    pub size: Option<u32>
+    pub size: Option<u32>,
+    pub load: Option<InventoryLoad>,
+}
+
+/// how much weight is being carried in an inventory, for inventories whose owners can be slowed down by it
+#[derive(Clone, PartialEq)]
+pub struct InventoryLoad {
+    pub weight: i32,
+    pub capacity: i32,
+    pub encumbrance: Encumbrance,
 }
 impl InventoryDisplayData {
     pub fn new<S : Into<String>>(items : Vec<Entity>, destacked_items: Vec<Entity>, excluded_items : HashSet<Entity>, equipped_items: HashSet<Entity>, name : S, from_entities : Vec<Entity>, equippable : bool, size : Option<u32>) -> InventoryDisplayData {
@@ -49,9 +61,15 @@ impl InventoryDisplayData {
             name : name.into(),
             from_entities,
             equippable,
-            size
+            size,
+            load : None,
         }
     }
+
+    pub fn with_load(mut self, load : InventoryLoad) -> Self {
+        self.load = Some(load);
+        self
+    }
 }
 
 impl InventoryDisplay {
@@ -106,6 +124,16 @@ impl InventoryDisplay {
                     control.trigger_event(TacticalEvents::EquipItemRequested { item, equip_on : *entity });
                 }
             }
+            if let Some((InventoryItemConsume { item }, _)) = event.as_custom_event::<InventoryItemConsume>() {
+                if let Some(entity) = self.main_inventories.active_inventory_data(gui).from_entities.first() {
+                    control.trigger_event(TacticalEvents::ConsumeItemRequested { item, consumer : *entity });
+                }
+            }
+            if let Some((InventoryItemBuild { item }, _)) = event.as_custom_event::<InventoryItemBuild>() {
+                if let Some(entity) = self.main_inventories.active_inventory_data(gui).from_entities.first() {
+                    control.trigger_event(TacticalEvents::BuildRequested { structure : item, builder : *entity });
+                }
+            }
         }
 
         let mut transfer_to : Option<&InventoryDisplayWidget> = None;
@@ -122,12 +150,15 @@ impl InventoryDisplay {
 
         if let Some(transfer_to) = transfer_to {
             if let Some(selected) = self.selected_item {
-                if transfer_to.inventories.any_match(|idd| idd.items.contains(&selected)) {
+                let to_inventory = transfer_to.active_inventory_data(gui);
+                if to_inventory.items.contains(&selected) {
                     info!("not going to transfer an item from an inventory to itself")
                 } else {
-                    let transfer_from = if transfer_to == &self.main_inventories { &self.other_inventories } else { &self.main_inventories };
-                    let from_entities = transfer_from.inventories.iter().find(|idd| idd.items.contains(&selected)).map(|idd| idd.from_entities.clone()).unwrap_or(Vec::new());
-                    let to_entities = transfer_to.active_inventory_data(gui).from_entities.clone();
+                    // the item can come from either side, so that it can be moved between the tabs of one side as well, from a
+                    // character's inventory into a bag they are carrying, say
+                    let from_entities = self.main_inventories.inventories.iter().chain(self.other_inventories.inventories.iter())
+                        .find(|idd| idd.items.contains(&selected)).map(|idd| idd.from_entities.clone()).unwrap_or(Vec::new());
+                    let to_entities = to_inventory.from_entities.clone();
                     if from_entities.non_empty() && to_entities.non_empty() {
                         control.trigger_event(TacticalEvents::ItemTransferRequested { item : selected, from : from_entities, to: to_entities });
                         self.selected_item = None;
@@ -161,6 +192,7 @@ pub(crate) struct InventoryDisplayWidget {
     show_equip_buttons : bool,
     pub inventory_lists: Vec<ListWidget<ItemNameDisplay>>,
     pub inventories : Vec<InventoryDisplayData>,
+    load_text : Widget,
     placeholder_data : InventoryDisplayData,
     last_selected : Option<Entity>
 }
@@ -178,16 +210,29 @@ impl DelegateToWidget for InventoryDisplayWidget {
 pub struct ItemNameDisplay {
     pub name: Widget,
     pub picked_up_indicator: Widget,
-    pub equip_button: Button
+    pub equip_button: Button,
+    pub eat_button: Button,
+    pub build_button: Button
 }
 
 #[derive(Clone)]
 pub struct InventoryItemSelected { pub inventory_index : usize, pub item_index : usize }
 #[derive(Clone)]
 pub struct InventoryItemToggleEquip { pub item : Entity }
+#[derive(Clone)]
+pub struct InventoryItemConsume { pub item : Entity }
+#[derive(Clone)]
+pub struct InventoryItemBuild { pub item : Entity }
 
 impl Default for ItemNameDisplay {
     fn default() -> Self {
+        let equip_button = Button::new("test").showing(false).y(Positioning::centered()).align_right().height(30.px())
+            .border_width(1).border_sides(BorderSides::one_side(Alignment::Left));
+        let eat_button = Button::new("Eat").showing(false).y(Positioning::centered()).left_of(equip_button.as_widget_immut(), 0.px()).height(30.px())
+            .border_width(1).border_sides(BorderSides::one_side(Alignment::Left));
+        let build_button = Button::new("Build").showing(false).y(Positioning::centered()).left_of(equip_button.as_widget_immut(), 0.px()).height(30.px())
+            .border_width(1).border_sides(BorderSides::one_side(Alignment::Left));
+
         ItemNameDisplay {
             name: Widget::text("Test", FontSize::HeadingMinor).x(32.px()).y(Positioning::centered()),
             picked_up_indicator: Widget::image("ui/hand_icon", Color::white(), 1)
@@ -195,8 +240,9 @@ impl Default for ItemNameDisplay {
                 .y(Positioning::centered())
                 .border_sides(BorderSides::one_side(Alignment::Right))
                 .showing(false),
-            equip_button: Button::new("test").showing(false).y(Positioning::centered()).align_right().height(30.px())
-                .border_width(1).border_sides(BorderSides::one_side(Alignment::Left))
+            equip_button,
+            eat_button,
+            build_button
         }
     }
 }
@@ -218,7 +264,15 @@ impl InventoryDisplayWidget {
             .parent(parent)
             .and_consume(EventConsumption::mouse_events());
 
+        let load_text = Widget::text("", FontSize::Standard)
+            .parent(body.as_widget_immut())
+            .align_bottom()
+            .align_right()
+            .margin(2.px())
+            .showing(false);
+
         InventoryDisplayWidget {
+            load_text,
             body,
             show_equip_buttons,
             inventory_lists : Vec::new(),
@@ -232,6 +286,7 @@ impl InventoryDisplayWidget {
                 from_entities : Vec::new(),
                 equippable: false,
                 size: None,
+                load: None,
             },
             last_selected : None
         }
@@ -275,6 +330,17 @@ impl InventoryDisplayWidget {
             self.body.reapply_all(gui);
         }
 
+        match &self.active_inventory_data(gui).load {
+            Some(load) => {
+                let color = if load.encumbrance == Encumbrance::Unencumbered { Color::black() } else { Color::new(0.6, 0.1, 0.1, 1.0) };
+                let text = format!("Weight {} / {} ({})", load.weight, load.capacity, load.encumbrance.name());
+                self.load_text.set_color(color);
+                self.load_text.set_showing(true).set_text(text);
+            },
+            None => { self.load_text.set_showing(false); }
+        }
+        self.load_text.reapply(gui);
+
         let inv_clone = inventories.clone();
         if self.last_selected != selected_item || self.inventories != inv_clone {
             self.last_selected = selected_item;
@@ -314,6 +380,27 @@ impl InventoryDisplayWidget {
                             widget.name.set_showing(true).set_text("unknown entity");
                         }
                         widget.picked_up_indicator.set_showing(Some(raw_item) == selected_item);
+                        if world.has_data::<FoodInfo>(item) && equippable && show_equip_buttons {
+                            let item_copy = item;
+                            widget.eat_button.set_showing(true).clear_callbacks().add_callback(move |ctxt : &mut WidgetContext, event : &UIEvent| {
+                                if let UIEvent::WidgetEvent { event : WidgetEvent::ButtonClicked(_), .. } = event {
+                                    ctxt.trigger_event(UIEvent::custom_event(InventoryItemConsume { item : item_copy }, self_id));
+                                }
+                            });
+                        } else {
+                            widget.eat_button.set_showing(false);
+                        }
+                        if world.data_opt::<StructureData>(item).map(|s| s.built_at.is_none()).unwrap_or(false) && equippable && show_equip_buttons {
+                            let item_copy = item;
+                            widget.build_button.set_showing(true).clear_callbacks().add_callback(move |ctxt : &mut WidgetContext, event : &UIEvent| {
+                                if let UIEvent::WidgetEvent { event : WidgetEvent::ButtonClicked(_), .. } = event {
+                                    ctxt.trigger_event(UIEvent::custom_event(InventoryItemBuild { item : item_copy }, self_id));
+                                }
+                            });
+                        } else {
+                            widget.build_button.set_showing(false);
+                        }
+
                         if equippable && show_equip_buttons {
                             let text = if all_equipped_items.contains(&item) { "Unequip" } else { "Equip" };
 
@@ -330,6 +417,8 @@ impl InventoryDisplayWidget {
                         widget.name.set_showing(false);
                         widget.picked_up_indicator.set_showing(false);
                         widget.equip_button.set_showing(false);
+                        widget.eat_button.set_showing(false);
+                        widget.build_button.set_showing(false);
                     }
                 });
             }
@@ -45,6 +45,8 @@ pub mod attack_descriptions;
 
 pub mod messages_widget;
 
+pub mod objectives_widget;
+
 pub mod state;
 pub use state::*;
 
@@ -0,0 +1,91 @@
+use gui::*;
+use common::color::Color;
+use common::prelude::*;
+use game::Entity;
+use game::WorldView;
+use game::entities::{ObjectiveData, Objective, ObjectiveCondition, Outcome};
+use graphics::FontSize;
+
+
+/// The player faction's objectives, kept in the corner of the screen so it's always clear what winning and losing
+/// look like in the scenario being played
+#[derive(Default)]
+pub struct ObjectivesDisplay {
+    main_widget : Widget,
+    objectives_list : ListWidget<ObjectiveWidget>,
+}
+impl DelegateToWidget for ObjectivesDisplay {
+    fn as_widget(&mut self) -> &mut Widget { &mut self.main_widget }
+
+    fn as_widget_immut(&self) -> &Widget { &self.main_widget }
+}
+
+#[derive(WidgetContainer)]
+struct ObjectiveWidget {
+    pub text : Widget,
+}
+
+impl Default for ObjectiveWidget {
+    fn default() -> Self {
+        ObjectiveWidget { text : Widget::text("Objective", FontSize::Standard) }
+    }
+}
+
+impl ObjectivesDisplay {
+    pub fn new(gui : &mut GUI, parent : &Widget) -> ObjectivesDisplay {
+        let main_widget = Widget::window(Color::greyscale(0.75), 2)
+            .position(Positioning::constant(1.ux()), Positioning::constant(1.ux()))
+            .size(Sizing::surround_children(), Sizing::surround_children())
+            .margin(1.ux())
+            .named("objectives display")
+            .only_consume(EventConsumption::none())
+            .parent(parent)
+            .apply(gui);
+
+        let heading = Widget::text("Objectives", FontSize::HeadingMajor)
+            .parent(&main_widget)
+            .apply(gui);
+
+        let mut objectives_list = ListWidget::featherweight()
+            .alignment(Alignment::Top, Alignment::Left)
+            .below(&heading, 0.5.ux())
+            .size(Sizing::surround_children(), Sizing::surround_children())
+            .parent(&main_widget)
+            .apply(gui);
+
+        objectives_list.item_archetype.set_size(Sizing::surround_children(), Sizing::surround_children());
+
+        ObjectivesDisplay {
+            main_widget,
+            objectives_list,
+        }
+    }
+
+    pub fn update(&mut self, gui : &mut GUI, view : &WorldView, faction : Entity) {
+        let objectives = view.data_opt::<ObjectiveData>(faction).map(|o| o.objectives.clone()).unwrap_or_else(Vec::new);
+
+        self.objectives_list.update(gui, &objectives, |widget, objective| {
+            let color = if objective.completed {
+                match objective.outcome {
+                    Outcome::Victory => Color::new(0.1, 0.5, 0.1, 1.0),
+                    Outcome::Defeat => Color::new(0.6, 0.1, 0.1, 1.0),
+                }
+            } else {
+                Color::black()
+            };
+            widget.text.set_text(objective_text(objective)).set_color(color);
+        });
+    }
+}
+
+fn objective_text(objective : &Objective) -> String {
+    let prefix = match objective.outcome {
+        Outcome::Victory => if objective.completed { "[done] " } else { "" },
+        Outcome::Defeat => "Avoid: ",
+    };
+    match objective.condition {
+        ObjectiveCondition::GatherResource { amount, .. } if ! objective.completed =>
+            format!("{}{} ({}/{})", prefix, objective.description, objective.progress.min(amount), amount),
+        _ => format!("{}{}", prefix, objective.description)
+    }
+}
@@ -4,6 +4,7 @@ use game::entities::EntitySelector;
 use game::archetype::EntityArchetype;
 use game::entities::item::*;
 use game::prelude::*;
+use game::logic;
 
 use gui::*;
 use gui::compound_widgets::TextDisplayWidget;
@@ -16,7 +17,8 @@ pub struct BaseRecipeSelector {
     body: Widget,
     label : Widget,
     recipe_list : ListWidget<Button>,
-    catalog : Option<RecipeCatalogView>
+    // the character and recipes the list was last built for, so it is only rebuilt when either changes
+    displayed_for : Option<(Entity, Vec<Entity>)>
 }
 impl DelegateToWidget for BaseRecipeSelector {
     fn as_widget(&mut self) -> &mut Widget { &mut self.body }
@@ -40,19 +42,18 @@ impl BaseRecipeSelector {
             label,
             recipe_list,
             body,
-            catalog : None,
+            displayed_for : None,
         }
     }
 
-    pub fn update(&mut self, view : &WorldView, gui : &mut GUI) {
+    /// shows the recipes the given character knows how to make
+    pub fn update(&mut self, view : &WorldView, gui : &mut GUI, character : Entity) {
         self.body.reapply(gui);
-        if self.catalog.is_none() {
-            let catalog = RecipeCatalogView::of(view);
-
+        let known_recipes = logic::recipes::known_root_recipes(view, character);
+        if self.displayed_for.as_ref() != Some(&(character, known_recipes.clone())) {
             self.label.reapply(gui);
 
-            println!("Number of root recipes: {}", catalog.root_recipes().len());
-            self.recipe_list.update(gui, catalog.root_recipes(), |widget, recipe| {
+            self.recipe_list.update(gui, &known_recipes, |widget, recipe| {
                 widget.set_font_size(FontSize::HeadingMajor)
                     .set_width(Sizing::match_parent());
                 match view.data::<Recipe>(*recipe).result {
@@ -69,7 +70,7 @@ impl BaseRecipeSelector {
                 });
             });
 
-            self.catalog = Some(catalog);
+            self.displayed_for = Some((character, known_recipes));
         }
     }
 }
\ No newline at end of file